@@ -18,6 +18,74 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Computes the average of `self` and `rhs` rounded down, without
+    /// overflowing.
+    ///
+    /// Returns $\floor{\frac{\mathtt{self} + \mathtt{rhs}}{2}}$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(3_U256.midpoint(8_U256), 5_U256);
+    /// assert_eq!(U256::MAX.midpoint(U256::MAX - 2_U256), U256::MAX - 1_U256);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // The sum is `2 * (self & rhs) + (self ^ rhs)`.
+        (self & rhs) + ((self ^ rhs) >> 1_usize)
+    }
+
+    /// Calculates `self + rhs + carry` and returns the sum together with the
+    /// carry out.
+    ///
+    /// This allows chaining additions of several [`Uint`]s into a wider
+    /// accumulator. Mirrors the unstable `u64::carrying_add`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// // Add the 512 bit numbers (1, MAX) and (0, 1), least significant first.
+    /// let (low, carry) = U256::MAX.carrying_add(1_U256, false);
+    /// let (high, carry) = 1_U256.carrying_add(0_U256, carry);
+    /// assert_eq!((low, high, carry), (0_U256, 2_U256, false));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        if BITS == 0 {
+            return (Self::ZERO, carry);
+        }
+        let mut carry_in = Self::ZERO;
+        carry_in.limbs[0] = carry as u64;
+        let (sum, overflow_a) = self.overflowing_add(rhs);
+        let (sum, overflow_b) = sum.overflowing_add(carry_in);
+        (sum, overflow_a || overflow_b)
+    }
+
+    /// Calculates `self - rhs - borrow` and returns the difference together
+    /// with the borrow out.
+    ///
+    /// Mirrors the unstable `u64::borrowing_sub`.
+    #[inline]
+    #[must_use]
+    pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        if BITS == 0 {
+            return (Self::ZERO, borrow);
+        }
+        let mut borrow_in = Self::ZERO;
+        borrow_in.limbs[0] = borrow as u64;
+        let (difference, overflow_a) = self.overflowing_sub(rhs);
+        let (difference, overflow_b) = difference.overflowing_sub(borrow_in);
+        (difference, overflow_a || overflow_b)
+    }
+
     /// Computes `self + rhs`, returning [`None`] if overflow occurred.
     #[inline(always)]
     #[must_use]
@@ -162,6 +230,48 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     pub const fn wrapping_sub(self, rhs: Self) -> Self {
         self.overflowing_sub(rhs).0
     }
+
+    /// Computes the sum of `values`, returning [`None`] if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U8::checked_sum(&[100_U8, 150_U8]), Some(250_U8));
+    /// assert_eq!(U8::checked_sum(&[100_U8, 150_U8, 6_U8]), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_sum(values: &[Self]) -> Option<Self> {
+        let mut sum = Self::ZERO;
+        let mut i = 0;
+        while i < values.len() {
+            sum = match sum.overflowing_add(values[i]) {
+                (value, false) => value,
+                _ => return None,
+            };
+            i += 1;
+        }
+        Some(sum)
+    }
+
+    /// Computes the sum of `values`, wrapping around at the boundary of the
+    /// type.
+    ///
+    /// This is the same as [`Iterator::sum`].
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_sum(values: &[Self]) -> Self {
+        let mut sum = Self::ZERO;
+        let mut i = 0;
+        while i < values.len() {
+            sum = sum.wrapping_add(values[i]);
+            i += 1;
+        }
+        sum
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Neg for Uint<BITS, LIMBS> {
@@ -182,6 +292,8 @@ impl<const BITS: usize, const LIMBS: usize> Neg for &Uint<BITS, LIMBS> {
     }
 }
 
+/// Sums wrapping around at the boundary of the type, see
+/// [`Uint::checked_sum`] to detect overflow.
 impl<const BITS: usize, const LIMBS: usize> Sum<Self> for Uint<BITS, LIMBS> {
     #[inline]
     fn sum<I>(iter: I) -> Self
@@ -192,6 +304,8 @@ impl<const BITS: usize, const LIMBS: usize> Sum<Self> for Uint<BITS, LIMBS> {
     }
 }
 
+/// Sums wrapping around at the boundary of the type, see
+/// [`Uint::checked_sum`] to detect overflow.
 impl<'a, const BITS: usize, const LIMBS: usize> Sum<&'a Self> for Uint<BITS, LIMBS> {
     #[inline]
     fn sum<I>(iter: I) -> Self
@@ -267,4 +381,55 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_sum() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(values: Vec<U>)| {
+                let wide: Uint<4160, 65> = values.iter().map(|&x| Uint::from(x)).sum();
+                let sum = U::wrapping_sum(&values);
+                assert_eq!(sum, wide.wrapping_to::<U>());
+                assert_eq!(values.iter().sum::<U>(), sum);
+                assert_eq!(values.iter().copied().sum::<U>(), sum);
+                assert_eq!(U::checked_sum(&values), (wide <= Uint::from(U::MAX)).then_some(sum));
+            });
+        });
+    }
+
+    #[test]
+    fn test_carrying() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, carry: bool)| {
+                let wide = |x: U| Uint::<4097, 65>::from(x);
+                let (sum, carry_out) = a.carrying_add(b, carry);
+                assert_eq!(
+                    wide(sum) + (Uint::from(carry_out) << BITS),
+                    wide(a) + wide(b) + Uint::from(carry)
+                );
+                let (difference, borrow_out) = a.borrowing_sub(b, carry);
+                assert_eq!(
+                    wide(difference) + wide(b) + Uint::from(carry),
+                    wide(a) + (Uint::from(borrow_out) << BITS)
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn test_abs_diff_midpoint() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let wide = |x: U| Uint::<4097, 65>::from(x);
+                assert_eq!(wide(a.abs_diff(b)), wide(a.max(b)) - wide(a.min(b)));
+                assert_eq!(wide(a.midpoint(b)), (wide(a) + wide(b)) >> 1_usize);
+                assert_eq!(a.midpoint(b), b.midpoint(a));
+            });
+        });
+    }
 }
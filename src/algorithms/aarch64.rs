@@ -0,0 +1,354 @@
+//! AArch64 kernels using `MUL`, `UMULH` and `ADCS` carry chains.
+//!
+//! The portable code keeps the limbs in loops over slices, which does not
+//! unroll for small operands. The kernels here keep all limbs in registers.
+//! AArch64 has no flag-free multiply, so each row of a schoolbook product adds
+//! the low halves of the partial products in one carry chain and the high
+//! halves in a second one.
+//!
+//! The kernels cover four limb operands, i.e. `U256` products and the four
+//! limb additions and subtractions in [`adc_n`] and
+//! [`sbb_n`](crate::algorithms::sbb_n). Other sizes use the portable code, as
+//! does `Uint` addition, which is a `const fn` and can not use assembly.
+
+use crate::algorithms::{adc_n, add_nx1};
+use core::arch::asm;
+
+/// ⚠️ Computes the full product `result = a * b` of four limb operands.
+///
+/// **Warning.** This function is not part of the stable API.
+#[inline]
+#[must_use]
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+pub fn mul_4x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 8] {
+    let [a0, a1, a2, a3] = *a;
+    let [b0, b1, b2, b3] = *b;
+    let (r0, r1, r2, r3, r4, r5, r6, r7): (u64, u64, u64, u64, u64, u64, u64, u64);
+    // SAFETY: Only registers are used.
+    unsafe {
+        asm!(
+            // Row 0 initializes r0–r4.
+            "mul {r0}, {a0}, {b0}",
+            "umulh {r1}, {a0}, {b0}",
+            "mul {t0}, {a0}, {b1}",
+            "umulh {r2}, {a0}, {b1}",
+            "mul {t1}, {a0}, {b2}",
+            "umulh {r3}, {a0}, {b2}",
+            "mul {t2}, {a0}, {b3}",
+            "umulh {r4}, {a0}, {b3}",
+            "adds {r1}, {r1}, {t0}",
+            "adcs {r2}, {r2}, {t1}",
+            "adcs {r3}, {r3}, {t2}",
+            "adc {r4}, {r4}, xzr",
+            // Row 1 adds to r1–r4 and initializes r5.
+            "mul {t0}, {a1}, {b0}",
+            "mul {t1}, {a1}, {b1}",
+            "mul {t2}, {a1}, {b2}",
+            "mul {t3}, {a1}, {b3}",
+            "adds {r1}, {r1}, {t0}",
+            "adcs {r2}, {r2}, {t1}",
+            "adcs {r3}, {r3}, {t2}",
+            "adcs {r4}, {r4}, {t3}",
+            "cset {r5}, cs",
+            "umulh {t0}, {a1}, {b0}",
+            "umulh {t1}, {a1}, {b1}",
+            "umulh {t2}, {a1}, {b2}",
+            "umulh {t3}, {a1}, {b3}",
+            "adds {r2}, {r2}, {t0}",
+            "adcs {r3}, {r3}, {t1}",
+            "adcs {r4}, {r4}, {t2}",
+            "adc {r5}, {r5}, {t3}",
+            // Row 2 adds to r2–r5 and initializes r6.
+            "mul {t0}, {a2}, {b0}",
+            "mul {t1}, {a2}, {b1}",
+            "mul {t2}, {a2}, {b2}",
+            "mul {t3}, {a2}, {b3}",
+            "adds {r2}, {r2}, {t0}",
+            "adcs {r3}, {r3}, {t1}",
+            "adcs {r4}, {r4}, {t2}",
+            "adcs {r5}, {r5}, {t3}",
+            "cset {r6}, cs",
+            "umulh {t0}, {a2}, {b0}",
+            "umulh {t1}, {a2}, {b1}",
+            "umulh {t2}, {a2}, {b2}",
+            "umulh {t3}, {a2}, {b3}",
+            "adds {r3}, {r3}, {t0}",
+            "adcs {r4}, {r4}, {t1}",
+            "adcs {r5}, {r5}, {t2}",
+            "adc {r6}, {r6}, {t3}",
+            // Row 3 adds to r3–r6 and initializes r7.
+            "mul {t0}, {a3}, {b0}",
+            "mul {t1}, {a3}, {b1}",
+            "mul {t2}, {a3}, {b2}",
+            "mul {t3}, {a3}, {b3}",
+            "adds {r3}, {r3}, {t0}",
+            "adcs {r4}, {r4}, {t1}",
+            "adcs {r5}, {r5}, {t2}",
+            "adcs {r6}, {r6}, {t3}",
+            "cset {r7}, cs",
+            "umulh {t0}, {a3}, {b0}",
+            "umulh {t1}, {a3}, {b1}",
+            "umulh {t2}, {a3}, {b2}",
+            "umulh {t3}, {a3}, {b3}",
+            "adds {r4}, {r4}, {t0}",
+            "adcs {r5}, {r5}, {t1}",
+            "adcs {r6}, {r6}, {t2}",
+            "adc {r7}, {r7}, {t3}",
+            a0 = in(reg) a0,
+            a1 = in(reg) a1,
+            a2 = in(reg) a2,
+            a3 = in(reg) a3,
+            b0 = in(reg) b0,
+            b1 = in(reg) b1,
+            b2 = in(reg) b2,
+            b3 = in(reg) b3,
+            r0 = out(reg) r0,
+            r1 = out(reg) r1,
+            r2 = out(reg) r2,
+            r3 = out(reg) r3,
+            r4 = out(reg) r4,
+            r5 = out(reg) r5,
+            r6 = out(reg) r6,
+            r7 = out(reg) r7,
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg) _,
+            t3 = out(reg) _,
+            options(pure, nomem, nostack),
+        );
+    }
+    [r0, r1, r2, r3, r4, r5, r6, r7]
+}
+
+/// ⚠️ Computes the truncated product `a * b mod 2^256` of four limb operands.
+///
+/// **Warning.** This function is not part of the stable API.
+#[inline]
+#[must_use]
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+pub fn mul_4x4_low(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let [a0, a1, a2, a3] = *a;
+    let [b0, b1, b2, b3] = *b;
+    let (r0, r1, r2, r3): (u64, u64, u64, u64);
+    // SAFETY: Only registers are used.
+    unsafe {
+        asm!(
+            // Row 0 initializes r0–r3.
+            "mul {r0}, {a0}, {b0}",
+            "umulh {r1}, {a0}, {b0}",
+            "mul {t0}, {a0}, {b1}",
+            "umulh {r2}, {a0}, {b1}",
+            "mul {t1}, {a0}, {b2}",
+            "umulh {r3}, {a0}, {b2}",
+            "mul {t2}, {a0}, {b3}",
+            "adds {r1}, {r1}, {t0}",
+            "adcs {r2}, {r2}, {t1}",
+            "adc {r3}, {r3}, {t2}",
+            // Row 1 adds to r1–r3.
+            "mul {t0}, {a1}, {b0}",
+            "mul {t1}, {a1}, {b1}",
+            "mul {t2}, {a1}, {b2}",
+            "adds {r1}, {r1}, {t0}",
+            "adcs {r2}, {r2}, {t1}",
+            "adc {r3}, {r3}, {t2}",
+            "umulh {t0}, {a1}, {b0}",
+            "umulh {t1}, {a1}, {b1}",
+            "adds {r2}, {r2}, {t0}",
+            "adc {r3}, {r3}, {t1}",
+            // Row 2 adds to r2–r3.
+            "mul {t0}, {a2}, {b0}",
+            "mul {t1}, {a2}, {b1}",
+            "umulh {t2}, {a2}, {b0}",
+            "adds {r2}, {r2}, {t0}",
+            "adc {r3}, {r3}, {t1}",
+            "add {r3}, {r3}, {t2}",
+            // Row 3 adds to r3.
+            "madd {r3}, {a3}, {b0}, {r3}",
+            a0 = in(reg) a0,
+            a1 = in(reg) a1,
+            a2 = in(reg) a2,
+            a3 = in(reg) a3,
+            b0 = in(reg) b0,
+            b1 = in(reg) b1,
+            b2 = in(reg) b2,
+            b3 = in(reg) b3,
+            r0 = out(reg) r0,
+            r1 = out(reg) r1,
+            r2 = out(reg) r2,
+            r3 = out(reg) r3,
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg) _,
+            options(pure, nomem, nostack),
+        );
+    }
+    [r0, r1, r2, r3]
+}
+
+/// ⚠️ Computes `lhs += a * b` for four limb operands and checks for overflow.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Limbs of the product past the end of `lhs` count as overflow, like in
+/// [`addmul`](crate::algorithms::addmul).
+#[inline]
+pub fn addmul_4x4(lhs: &mut [u64], a: &[u64; 4], b: &[u64; 4]) -> bool {
+    let product = mul_4x4(a, b);
+    if lhs.len() >= 8 {
+        let (low, high) = lhs.split_at_mut(8);
+        let carry = adc_n(low, &product, 0);
+        add_nx1(high, carry) != 0
+    } else {
+        let (low, high) = product.split_at(lhs.len());
+        let carry = adc_n(lhs, low, 0);
+        carry != 0 || high.iter().any(|&limb| limb != 0)
+    }
+}
+
+/// ⚠️ Computes wrapping `lhs += a * b` for four limbs.
+///
+/// **Warning.** This function is not part of the stable API.
+#[inline]
+pub fn addmul_4x4_low(lhs: &mut [u64; 4], a: &[u64; 4], b: &[u64; 4]) {
+    adc_4(lhs, &mul_4x4_low(a, b), 0);
+}
+
+/// ⚠️ Computes `lhs += rhs + carry` for four limbs and returns the carry.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Requires `carry <= 1`.
+#[inline]
+pub fn adc_4(lhs: &mut [u64; 4], rhs: &[u64; 4], carry: u64) -> u64 {
+    debug_assert!(carry <= 1);
+    let [mut l0, mut l1, mut l2, mut l3] = *lhs;
+    let carry_out: u64;
+    // SAFETY: Only registers are used.
+    unsafe {
+        asm!(
+            // Sets the carry flag if `carry` is one.
+            "cmp {carry}, #1",
+            "adcs {l0}, {l0}, {r0}",
+            "adcs {l1}, {l1}, {r1}",
+            "adcs {l2}, {l2}, {r2}",
+            "adcs {l3}, {l3}, {r3}",
+            "cset {carry}, cs",
+            l0 = inout(reg) l0,
+            l1 = inout(reg) l1,
+            l2 = inout(reg) l2,
+            l3 = inout(reg) l3,
+            r0 = in(reg) rhs[0],
+            r1 = in(reg) rhs[1],
+            r2 = in(reg) rhs[2],
+            r3 = in(reg) rhs[3],
+            carry = inout(reg) carry => carry_out,
+            options(pure, nomem, nostack),
+        );
+    }
+    *lhs = [l0, l1, l2, l3];
+    carry_out
+}
+
+/// ⚠️ Computes `lhs -= rhs + borrow` for four limbs and returns the borrow.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Requires `borrow <= 1`.
+#[inline]
+pub fn sbb_4(lhs: &mut [u64; 4], rhs: &[u64; 4], borrow: u64) -> u64 {
+    debug_assert!(borrow <= 1);
+    let [mut l0, mut l1, mut l2, mut l3] = *lhs;
+    let borrow_out: u64;
+    // SAFETY: Only registers are used.
+    unsafe {
+        asm!(
+            // The carry flag is the inverted borrow, so it is set if `borrow`
+            // is zero.
+            "cmp xzr, {borrow}",
+            "sbcs {l0}, {l0}, {r0}",
+            "sbcs {l1}, {l1}, {r1}",
+            "sbcs {l2}, {l2}, {r2}",
+            "sbcs {l3}, {l3}, {r3}",
+            "cset {borrow}, cc",
+            l0 = inout(reg) l0,
+            l1 = inout(reg) l1,
+            l2 = inout(reg) l2,
+            l3 = inout(reg) l3,
+            r0 = in(reg) rhs[0],
+            r1 = in(reg) rhs[1],
+            r2 = in(reg) rhs[2],
+            r3 = in(reg) rhs[3],
+            borrow = inout(reg) borrow => borrow_out,
+            options(pure, nomem, nostack),
+        );
+    }
+    *lhs = [l0, l1, l2, l3];
+    borrow_out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        add::{adc_n_scalar, sbb_n_scalar},
+        addmul_ref,
+    };
+    use proptest::{array::uniform4, collection, num::u64, prop_oneof, proptest, strategy::Just};
+
+    #[test]
+    fn test_mul_4x4() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(a in uniform4(limb.clone()), b in uniform4(limb))| {
+            let mut expected = [0; 8];
+            addmul_ref(&mut expected, &a, &b);
+            assert_eq!(mul_4x4(&a, &b), expected);
+        });
+    }
+
+    #[test]
+    fn test_mul_4x4_low() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(a in uniform4(limb.clone()), b in uniform4(limb))| {
+            let mut expected = [0; 4];
+            addmul_ref(&mut expected, &a, &b);
+            assert_eq!(mul_4x4_low(&a, &b), expected);
+        });
+    }
+
+    #[test]
+    fn test_addmul_4x4() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(
+            a in uniform4(limb.clone()),
+            b in uniform4(limb.clone()),
+            mut lhs in collection::vec(limb, 0..12),
+        )| {
+            let mut expected = lhs.clone();
+            let overflow = addmul_ref(&mut expected, &a, &b);
+            assert_eq!(addmul_4x4(&mut lhs, &a, &b), overflow);
+            assert_eq!(lhs, expected);
+        });
+    }
+
+    #[test]
+    fn test_adc_sbb_4() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(
+            lhs in uniform4(limb.clone()),
+            rhs in uniform4(limb),
+            carry in 0_u64..=1,
+        )| {
+            let mut expected = lhs;
+            let expected_carry = adc_n_scalar(&mut expected, &rhs, carry);
+            let mut sum = lhs;
+            assert_eq!(adc_4(&mut sum, &rhs, carry), expected_carry);
+            assert_eq!(sum, expected);
+
+            let mut expected = lhs;
+            let expected_borrow = sbb_n_scalar(&mut expected, &rhs, carry);
+            let mut difference = lhs;
+            assert_eq!(sbb_4(&mut difference, &rhs, carry), expected_borrow);
+            assert_eq!(difference, expected);
+        });
+    }
+}
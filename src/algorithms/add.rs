@@ -2,7 +2,21 @@ use super::ops::{adc, sbb};
 
 /// `lhs += rhs + carry`
 #[inline(always)]
-pub fn adc_n(lhs: &mut [u64], rhs: &[u64], mut carry: u64) -> u64 {
+pub fn adc_n(lhs: &mut [u64], rhs: &[u64], carry: u64) -> u64 {
+    #[cfg(all(feature = "asm", target_arch = "aarch64"))]
+    if let (Ok(lhs), Ok(rhs)) = ((&mut *lhs).try_into(), rhs.try_into()) {
+        return super::aarch64::adc_4(lhs, rhs, carry);
+    }
+
+    #[cfg(feature = "portable-simd")]
+    return super::simd::adc_n(lhs, rhs, carry);
+
+    #[cfg(not(feature = "portable-simd"))]
+    return adc_n_scalar(lhs, rhs, carry);
+}
+
+#[inline(always)]
+pub(crate) fn adc_n_scalar(lhs: &mut [u64], rhs: &[u64], mut carry: u64) -> u64 {
     for i in 0..lhs.len() {
         (lhs[i], carry) = adc(lhs[i], rhs[i], carry);
     }
@@ -11,7 +25,17 @@ pub fn adc_n(lhs: &mut [u64], rhs: &[u64], mut carry: u64) -> u64 {
 
 /// `lhs -= rhs - borrow`
 #[inline(always)]
-pub fn sbb_n(lhs: &mut [u64], rhs: &[u64], mut borrow: u64) -> u64 {
+pub fn sbb_n(lhs: &mut [u64], rhs: &[u64], borrow: u64) -> u64 {
+    #[cfg(all(feature = "asm", target_arch = "aarch64"))]
+    if let (Ok(lhs), Ok(rhs)) = ((&mut *lhs).try_into(), rhs.try_into()) {
+        return super::aarch64::sbb_4(lhs, rhs, borrow);
+    }
+
+    sbb_n_scalar(lhs, rhs, borrow)
+}
+
+#[inline(always)]
+pub(crate) fn sbb_n_scalar(lhs: &mut [u64], rhs: &[u64], mut borrow: u64) -> u64 {
     for i in 0..lhs.len() {
         (lhs[i], borrow) = sbb(lhs[i], rhs[i], borrow);
     }
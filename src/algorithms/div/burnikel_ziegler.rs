@@ -0,0 +1,212 @@
+//! Burnikel–Ziegler recursive division.
+//!
+//! This is Algorithm 1.8 (`RecursiveDivRem`) from [BZ10], which splits the
+//! quotient in halves and reduces a division to two divisions of half the
+//! size and two multiplications. It is only faster than [`div_nxm`] for large
+//! operands, see [`THRESHOLD`].
+//!
+//! [BZ10]: https://members.loria.fr/PZimmermann/mca/mca-cup-0.5.9.pdf
+
+use super::knuth::{div_nxm, div_nxm_normalized};
+use crate::algorithms::{adc_n, addmul, cmp, sbb_n, shift_left_small, shift_right_small};
+use alloc::vec;
+use core::cmp::Ordering;
+
+/// Number of divisor and quotient limbs below which [`div_nxm_normalized`]
+/// is used.
+// Below 24 limbs the recursion does not pay off over [`div_nxm`].
+pub const THRESHOLD: usize = 24;
+
+/// ⚠️ In-place recursive normalized division.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Stores the quotient in `numerator[divisor.len()..]` and the remainder in
+/// `numerator[..divisor.len()]`, like [`div_nxm_normalized`].
+///
+/// Requires
+/// * the highest bit of the divisor to be set,
+/// * the `divisor` to be at least two limbs, and
+/// * the highest `divisor.len()` limbs of `numerator` to be less than
+///   `divisor`, i.e. the quotient fits the remaining limbs.
+///
+/// # Panics
+///
+/// May panic if the above requirements are not met.
+#[inline]
+pub fn div_nxm_recursive_normalized(numerator: &mut [u64], divisor: &[u64]) {
+    debug_assert!(divisor.len() >= 2);
+    debug_assert!(numerator.len() >= divisor.len());
+    debug_assert!(*divisor.last().unwrap() >= (1 << 63));
+    let n = divisor.len();
+    let m = numerator.len() - n;
+    debug_assert_eq!(cmp(&numerator[m..], divisor), Ordering::Less);
+
+    // Divide blocks of at most `n` quotient limbs from the top. The remainder
+    // of each block stays in place and becomes the top of the next block.
+    let mut scratch = vec![0; n];
+    let mut end = numerator.len();
+    while end > n {
+        let start = end.saturating_sub(2 * n);
+        div_balanced(&mut numerator[start..end], divisor, &mut scratch);
+        end = start + n;
+    }
+}
+
+/// ⚠️ In-place recursive division with implicit normalization.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Has the same interface as [`div_nxm`]: the quotient is stored in
+/// `numerator` and the remainder in `divisor`. Operands smaller than
+/// [`THRESHOLD`] limbs are delegated to [`div_nxm`].
+///
+/// Requires
+/// * the highest limb of the divisor to be non-zero,
+/// * the `divisor` to be at least three limbs, and
+/// * `numerator` is at least as long as `divisor`.
+///
+/// # Panics
+///
+/// May panic if the above requirements are not met.
+#[inline]
+pub fn div_nxm_recursive(numerator: &mut [u64], divisor: &mut [u64]) {
+    debug_assert!(divisor.len() >= 3);
+    debug_assert!(numerator.len() >= divisor.len());
+    debug_assert!(*divisor.last().unwrap() >= 1);
+    let n = divisor.len();
+    if n < THRESHOLD || numerator.len() - n < THRESHOLD {
+        div_nxm(numerator, divisor);
+        return;
+    }
+
+    // Normalize copies of the operands, adding a limb to the numerator so its
+    // top `n` limbs are less than the divisor.
+    let shift = divisor[n - 1].leading_zeros() as usize;
+    let mut normalized = divisor.to_vec();
+    let mut buffer = vec![0; numerator.len() + 1];
+    buffer[..numerator.len()].copy_from_slice(numerator);
+    if shift > 0 {
+        shift_left_small(&mut normalized, shift);
+        shift_left_small(&mut buffer, shift);
+    }
+
+    div_nxm_recursive_normalized(&mut buffer, &normalized);
+
+    // The quotient has `numerator.len() + 1 - n` limbs.
+    let (remainder, quotient) = buffer.split_at_mut(n);
+    numerator[..quotient.len()].copy_from_slice(quotient);
+    numerator[quotient.len()..].fill(0);
+    if shift > 0 {
+        shift_right_small(remainder, shift);
+    }
+    divisor.copy_from_slice(remainder);
+}
+
+/// Division with at most as many quotient limbs as divisor limbs.
+///
+/// The `scratch` space must be at least `divisor.len()` limbs.
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+fn div_balanced(a: &mut [u64], b: &[u64], scratch: &mut [u64]) {
+    let n = b.len();
+    let m = a.len() - n;
+    debug_assert!(m <= n);
+    if m == 0 {
+        return;
+    }
+    if m < THRESHOLD {
+        div_nxm_normalized(a, b);
+        return;
+    }
+
+    // Split the divisor `b = b1 ⋅ β^k + b0` and the quotient in a high part of
+    // `m - k` limbs and a low part of `k` limbs.
+    let k = m / 2;
+    let (b0, b1) = b.split_at(k);
+
+    // Compute the high part of the quotient from `a / β^{2k}` and `b1`. This
+    // requires the top limbs of `a` to be less than `b1`. Since they are at
+    // most `b1`, the rare case of equality is delegated to Knuth division.
+    if cmp(&a[m + k..], b1) != Ordering::Less {
+        div_nxm_normalized(a, b);
+        return;
+    }
+    div_balanced(&mut a[2 * k..], b1, scratch);
+    let (low, q1) = a.split_at_mut(n + k);
+    sub_mul(&mut low[k..], q1, b0, b, scratch);
+
+    // Compute the low part of the quotient from `a / β^k` and `b1`.
+    let a = &mut a[..n + k];
+    if cmp(&a[2 * k..], b1) != Ordering::Less {
+        div_nxm_normalized(a, b);
+        return;
+    }
+    div_balanced(&mut a[k..], b1, scratch);
+    let (low, q0) = a.split_at_mut(n);
+    sub_mul(low, q0, b0, b, scratch);
+}
+
+/// Computes `window -= q ⋅ b0` and corrects the estimate `q` until the result
+/// is non-negative by adding `b` to `window`.
+fn sub_mul(window: &mut [u64], q: &mut [u64], b0: &[u64], b: &[u64], scratch: &mut [u64]) {
+    debug_assert_eq!(window.len(), b.len());
+    let product = &mut scratch[..window.len()];
+    product.fill(0);
+    let overflow = addmul(product, q, b0);
+    debug_assert!(!overflow);
+    let mut borrow = sbb_n(window, product, 0);
+
+    // The estimate is at most two too large.
+    while borrow != 0 {
+        for limb in q.iter_mut() {
+            let (value, underflow) = limb.overflowing_sub(1);
+            *limb = value;
+            if !underflow {
+                break;
+            }
+        }
+        borrow -= adc_n(window, b, 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{
+        collection,
+        num::u64,
+        prop_oneof, proptest,
+        strategy::{Just, Strategy},
+    };
+
+    #[test]
+    fn test_div_nxm_recursive() {
+        // Extreme limb values make the rare correction paths more likely.
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        let limbs = |range| collection::vec(limb.clone(), range);
+        proptest!(|(
+            mut numerator in limbs(THRESHOLD..5 * THRESHOLD),
+            shift in 0_usize..64,
+            divisor in limbs(THRESHOLD..3 * THRESHOLD).prop_map(|mut d| {
+                // Make the top limb non-zero.
+                *d.last_mut().unwrap() |= 1;
+                d
+            }),
+        )| {
+            if numerator.len() < divisor.len() {
+                numerator.resize(divisor.len(), 0);
+            }
+            // Vary the normalization shift.
+            let mut divisor = divisor;
+            *divisor.last_mut().unwrap() >>= shift;
+            *divisor.last_mut().unwrap() |= 1;
+
+            let (mut q, mut r) = (numerator.clone(), divisor.clone());
+            div_nxm(&mut q, &mut r);
+            let mut remainder = divisor.clone();
+            div_nxm_recursive(&mut numerator, &mut remainder);
+            assert_eq!(numerator, q);
+            assert_eq!(remainder, r);
+        });
+    }
+}
@@ -0,0 +1,90 @@
+//! Exact division using Hensel (2-adic) division.
+//!
+//! See T. Jebelean (1993). An algorithm for exact division. Journal of
+//! Symbolic Computation 15.
+
+use crate::algorithms::{sbb, submul_nx1};
+
+/// Computes the inverse of an odd `d` modulo $2^{64}$.
+///
+/// Uses Newton iteration starting from a 5-bit accurate value.
+#[inline]
+#[must_use]
+pub const fn inv_limb(d: u64) -> u64 {
+    debug_assert!(d & 1 == 1);
+    let mut x = d.wrapping_mul(3) ^ 2;
+    // Each iteration doubles the number of correct bits: 5, 10, 20, 40, 80.
+    let mut i = 0;
+    while i < 4 {
+        x = x.wrapping_mul(2_u64.wrapping_sub(d.wrapping_mul(x)));
+        i += 1;
+    }
+    x
+}
+
+/// ⚠️ Exact division.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Computes `numerator / divisor` modulo $2^{64⋅N}$ where `N` is the length of
+/// `numerator` and stores the result in `numerator`. If `divisor` divides
+/// `numerator` this is the exact quotient, otherwise the result is
+/// meaningless.
+///
+/// # Algorithm
+///
+/// Limbs of the quotient are computed from least to most significant by
+/// multiplying with the inverse of the lowest divisor limb and subtracting the
+/// corresponding multiple of the divisor, following Jebelean. Unlike
+/// [`div`](super::div) this requires no normalization or quotient estimation.
+///
+/// # Panics
+///
+/// May panic if `divisor` is empty or even.
+#[inline]
+pub fn div_exact(numerator: &mut [u64], divisor: &[u64]) {
+    debug_assert!(divisor.first().map_or(false, |d| d & 1 == 1));
+    let inv = inv_limb(divisor[0]);
+    let n = numerator.len();
+    for i in 0..n {
+        let q = numerator[i].wrapping_mul(inv);
+        let m = divisor.len().min(n - i);
+        let (window, rest) = numerator[i..].split_at_mut(m);
+        let mut borrow = submul_nx1(window, &divisor[..m], q);
+        for limb in rest {
+            if borrow == 0 {
+                break;
+            }
+            (*limb, borrow) = sbb(*limb, 0, borrow);
+        }
+        debug_assert_eq!(numerator[i], 0);
+        numerator[i] = q;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::addmul;
+    use proptest::{collection, num::u64, proptest};
+
+    #[test]
+    fn test_inv_limb() {
+        proptest!(|(d: u64)| {
+            let d = d | 1;
+            assert_eq!(d.wrapping_mul(inv_limb(d)), 1);
+        });
+    }
+
+    #[test]
+    fn test_div_exact() {
+        let any_vec = collection::vec(u64::ANY, 1..6);
+        proptest!(|(quotient in &any_vec, mut divisor in &any_vec)| {
+            divisor[0] |= 1;
+            let mut numerator = vec![0; quotient.len()];
+            addmul(&mut numerator, &quotient, &divisor);
+            div_exact(&mut numerator, &divisor);
+            assert_eq!(numerator, quotient);
+        });
+    }
+}
@@ -20,15 +20,25 @@ use crate::{
 #[allow(clippy::many_single_char_names)]
 pub fn div_nxm_normalized(numerator: &mut [u64], divisor: &[u64]) {
     debug_assert!(divisor.len() >= 2);
+
+    // Compute the divisor double limb and reciprocal
+    let n = divisor.len();
+    let v = reciprocal_2(u128::join(divisor[n - 1], divisor[n - 2]));
+    div_nxm_normalized_with_reciprocal(numerator, divisor, v);
+}
+
+/// Same as [`div_nxm_normalized`] with a precomputed `v = reciprocal_2(d)`
+/// where `d` are the two most significant limbs of the divisor.
+#[inline]
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn div_nxm_normalized_with_reciprocal(numerator: &mut [u64], divisor: &[u64], v: u64) {
+    debug_assert!(divisor.len() >= 2);
     debug_assert!(numerator.len() >= divisor.len());
     debug_assert!(*divisor.last().unwrap() >= (1 << 63));
 
     let n = divisor.len();
     let m = numerator.len() - n - 1;
-
-    // Compute the divisor double limb and reciprocal
     let d = u128::join(divisor[n - 1], divisor[n - 2]);
-    let v = reciprocal_2(d);
 
     // Compute the quotient one limb at a time.
     for j in (0..=m).rev() {
@@ -14,11 +14,20 @@
 
 #![allow(clippy::similar_names)] // TODO
 
+#[cfg(feature = "alloc")]
+mod burnikel_ziegler;
+mod exact;
 mod knuth;
 mod reciprocal;
 mod small;
 
+#[cfg(feature = "alloc")]
+pub use self::burnikel_ziegler::{
+    div_nxm_recursive, div_nxm_recursive_normalized, THRESHOLD as RECURSIVE_THRESHOLD,
+};
+pub(crate) use self::knuth::div_nxm_normalized_with_reciprocal;
 pub use self::{
+    exact::{div_exact, inv_limb},
     knuth::{div_nxm, div_nxm_normalized},
     reciprocal::{reciprocal, reciprocal_2, reciprocal_2_mg10, reciprocal_mg10, reciprocal_ref},
     small::{
@@ -39,7 +48,8 @@ use crate::algorithms::DoubleWord;
 ///
 /// It trims zeros from the numerator and divisor then solves the trivial cases
 /// directly, or dispatches to the [`div_nx1`], [`div_nx2`] or [`div_nxm`]
-/// functions.
+/// functions. With the `alloc` feature large divisions use the recursive
+/// `div_nxm_recursive` instead.
 ///
 /// # Panics
 ///
@@ -94,6 +104,9 @@ pub fn div(numerator: &mut [u64], divisor: &mut [u64]) {
             divisor[1] = remainder.high();
         }
     } else {
+        #[cfg(feature = "alloc")]
+        div_nxm_recursive(numerator, divisor);
+        #[cfg(not(feature = "alloc"))]
         div_nxm(numerator, divisor);
     }
 }
@@ -12,6 +12,9 @@ use core::mem::swap;
 /// **Warning.** This struct is not part of the stable API.
 ///
 /// See [`gcd_extended`] for documentation.
+///
+/// Once the operands fit in a `u128` the remaining steps are done using
+/// [`gcd_u128`], which is faster than Lehmer steps at that size.
 #[inline]
 #[must_use]
 pub fn gcd<const BITS: usize, const LIMBS: usize>(
@@ -23,6 +26,13 @@ pub fn gcd<const BITS: usize, const LIMBS: usize>(
     }
     while b != Uint::ZERO {
         debug_assert!(a >= b);
+        if a.bit_len() <= 128 {
+            let limbs = |x: Uint<BITS, LIMBS>| {
+                let limbs = x.as_limbs();
+                u128::from(limbs[0]) | u128::from(limbs.get(1).copied().unwrap_or(0)) << 64
+            };
+            return Uint::from(gcd_u128(limbs(a), limbs(b)));
+        }
         let m = LehmerMatrix::from(a, b);
         if m == LehmerMatrix::IDENTITY {
             // Lehmer step failed to find a factor, which happens when
@@ -37,6 +47,36 @@ pub fn gcd<const BITS: usize, const LIMBS: usize>(
     a
 }
 
+/// ⚠️ Binary GCD of two `u128`s.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// See Stein's algorithm <https://en.wikipedia.org/wiki/Binary_GCD_algorithm>.
+#[inline]
+#[must_use]
+pub const fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            let t = a;
+            a = b;
+            b = t;
+        }
+        b -= a;
+        if b == 0 {
+            break a << shift;
+        }
+    }
+}
+
 /// ⚠️ Lehmer's extended GCD.
 ///
 /// **Warning.** This struct is not part of the stable API.
@@ -232,6 +272,26 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_gcd_u128() {
+        proptest!(|(a: u128, b: u128)| {
+            let (a, b) = (Uint::<128, 2>::from(a), Uint::<128, 2>::from(b));
+            assert_eq!(Uint::from(gcd_u128(a.to(), b.to())), gcd_ref(a, b));
+        });
+    }
+
+    #[test]
+    fn test_gcd_mixed() {
+        const_for!(BITS in NON_ZERO if (BITS >= 64) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: u64)| {
+                let b = U::from(b);
+                assert_eq!(gcd(a, b), gcd_ref(a, b));
+            });
+        });
+    }
+
     #[test]
     #[allow(clippy::absurd_extreme_comparisons)] // Generated code
     fn test_gcd_extended() {
@@ -0,0 +1,208 @@
+//! Batched Montgomery multiplication using AVX-512 IFMA.
+//!
+//! `VPMADD52LUQ` and `VPMADD52HUQ` add the low and high 52 bits of eight
+//! $52 × 52$-bit products to eight 64-bit accumulators. Operands are held in
+//! radix $2^{52}$, which leaves 12 bits of headroom in every accumulator, so
+//! carries only need to be propagated once at the end. Each of the eight
+//! lanes computes an independent product, which suits workloads with many
+//! multiplications by the same modulus.
+//!
+//! Five limbs of 52 bits represent numbers up to $2^{260}$, so the reduction
+//! divides by $2^{260}$ instead of $R = 2^{256}$. One operand is multiplied
+//! by $2^4$ on conversion to compensate, and the results are the same as
+//! those of [`mul_redc`](crate::algorithms::mul_redc).
+//!
+//! The `ifma` feature requires Rust 1.89 or later, where the AVX-512
+//! intrinsics are stable, above the crate's minimum supported Rust version.
+
+use core::arch::x86_64::{
+    __m512i, _mm512_add_epi64, _mm512_and_si512, _mm512_cmplt_epi64_mask, _mm512_loadu_si512,
+    _mm512_madd52hi_epu64, _mm512_madd52lo_epu64, _mm512_mask_blend_epi64, _mm512_set1_epi64,
+    _mm512_setzero_si512, _mm512_srai_epi64, _mm512_srli_epi64, _mm512_storeu_si512,
+    _mm512_sub_epi64,
+};
+
+const MASK: u64 = (1 << 52) - 1;
+
+/// Whether the AVX-512 IFMA extension is available.
+///
+/// This is known at compile time if the target enables it, and is detected at
+/// runtime with the `std` feature. Otherwise it is `false`.
+#[inline(always)]
+#[must_use]
+pub fn has_ifma() -> bool {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512ifma"))]
+    return true;
+
+    #[cfg(all(
+        not(all(target_feature = "avx512f", target_feature = "avx512ifma")),
+        feature = "std"
+    ))]
+    return std::is_x86_feature_detected!("avx512f") && std::is_x86_feature_detected!("avx512ifma");
+
+    #[cfg(all(
+        not(all(target_feature = "avx512f", target_feature = "avx512ifma")),
+        not(feature = "std")
+    ))]
+    return false;
+}
+
+/// ⚠️ Computes eight Montgomery products `a[i] * b[i] / 2^256 mod m` of four
+/// limb operands.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Requires `m` to be odd, `inv = -m^{-1} mod 2^64` and all operands to be
+/// less than `m`. The results are less than `m`.
+///
+/// # Safety
+///
+/// Requires the AVX-512F and AVX-512 IFMA extensions, see [`has_ifma`].
+#[inline]
+#[must_use]
+#[target_feature(enable = "avx512f,avx512ifma")]
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+pub unsafe fn mul_redc_4x8(
+    a: &[[u64; 4]; 8],
+    b: &[[u64; 4]; 8],
+    m: &[u64; 4],
+    inv: u64,
+) -> [[u64; 4]; 8] {
+    debug_assert_eq!(inv.wrapping_mul(m[0]), u64::MAX);
+    let a = transpose(&a.map(|a| to_radix_52(&a)));
+    let b = transpose(&b.map(|b| times_16(to_radix_52(&b))));
+    let a = a.map(|a| unsafe { load(&a) });
+    let b = b.map(|b| unsafe { load(&b) });
+    let m = to_radix_52(m).map(|m| splat(m));
+    let inv = splat(inv & MASK);
+    let mask = splat(MASK);
+    let zero = _mm512_setzero_si512();
+
+    // Interleaved multiplication and reduction, one 52-bit limb of `a` at a
+    // time. The accumulator limbs stay below $2^{57}$.
+    let mut t = [zero; 6];
+    for a in a {
+        for j in 0..5 {
+            t[j] = _mm512_madd52lo_epu64(t[j], a, b[j]);
+            t[j + 1] = _mm512_madd52hi_epu64(t[j + 1], a, b[j]);
+        }
+        let q = _mm512_madd52lo_epu64(zero, t[0], inv);
+        for j in 0..5 {
+            t[j] = _mm512_madd52lo_epu64(t[j], q, m[j]);
+            t[j + 1] = _mm512_madd52hi_epu64(t[j + 1], q, m[j]);
+        }
+        // The low 52 bits of `t[0]` are now zero.
+        t[1] = _mm512_add_epi64(t[1], _mm512_srli_epi64(t[0], 52));
+        t = [t[1], t[2], t[3], t[4], t[5], zero];
+    }
+
+    // Propagate the carries. The result is less than `2 * m`.
+    for j in 0..4 {
+        t[j + 1] = _mm512_add_epi64(t[j + 1], _mm512_srli_epi64(t[j], 52));
+        t[j] = _mm512_and_si512(t[j], mask);
+    }
+
+    // Subtract `m` where that does not borrow.
+    let mut d = [zero; 5];
+    let mut borrow = zero;
+    for j in 0..5 {
+        let difference = _mm512_add_epi64(_mm512_sub_epi64(t[j], m[j]), borrow);
+        borrow = _mm512_srai_epi64(difference, 52);
+        d[j] = _mm512_and_si512(difference, mask);
+    }
+    let keep = _mm512_cmplt_epi64_mask(borrow, zero);
+    let result = [0, 1, 2, 3, 4].map(|j| {
+        let mut lanes = [0; 8];
+        unsafe { store(&mut lanes, _mm512_mask_blend_epi64(keep, d[j], t[j])) };
+        lanes
+    });
+    let mut transposed = [[0; 5]; 8];
+    for (i, limbs) in transposed.iter_mut().enumerate() {
+        *limbs = result.map(|lanes| lanes[i]);
+    }
+    transposed.map(|limbs| from_radix_52(&limbs))
+}
+
+/// Splits four 64-bit limbs in five 52-bit limbs.
+const fn to_radix_52(a: &[u64; 4]) -> [u64; 5] {
+    [
+        a[0] & MASK,
+        (a[0] >> 52 | a[1] << 12) & MASK,
+        (a[1] >> 40 | a[2] << 24) & MASK,
+        (a[2] >> 28 | a[3] << 36) & MASK,
+        a[3] >> 16,
+    ]
+}
+
+/// Joins five 52-bit limbs of a number less than $2^{256}$.
+const fn from_radix_52(a: &[u64; 5]) -> [u64; 4] {
+    [
+        a[0] | a[1] << 52,
+        a[1] >> 12 | a[2] << 40,
+        a[2] >> 24 | a[3] << 28,
+        a[3] >> 36 | a[4] << 16,
+    ]
+}
+
+/// Multiplies a number less than $2^{256}$ by $2^4$.
+const fn times_16(a: [u64; 5]) -> [u64; 5] {
+    [
+        a[0] << 4 & MASK,
+        (a[1] << 4 | a[0] >> 48) & MASK,
+        (a[2] << 4 | a[1] >> 48) & MASK,
+        (a[3] << 4 | a[2] >> 48) & MASK,
+        a[4] << 4 | a[3] >> 48,
+    ]
+}
+
+/// Collects limb `j` of the eight operands in lane vector `j`.
+fn transpose(a: &[[u64; 5]; 8]) -> [[u64; 8]; 5] {
+    [0, 1, 2, 3, 4].map(|j| a.map(|limbs| limbs[j]))
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn load(lanes: &[u64; 8]) -> __m512i {
+    unsafe { _mm512_loadu_si512(lanes.as_ptr().cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn store(lanes: &mut [u64; 8], value: __m512i) {
+    unsafe { _mm512_storeu_si512(lanes.as_mut_ptr().cast(), value) }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[allow(clippy::cast_possible_wrap)] // Intentional
+fn splat(value: u64) -> __m512i {
+    _mm512_set1_epi64(value as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, Uint};
+    use proptest::{arbitrary::any, array::uniform8, proptest};
+
+    #[test]
+    fn test_mul_redc_4x8() {
+        if !has_ifma() {
+            return;
+        }
+        let values = || uniform8(any::<U256>());
+        proptest!(|(a in values(), b in values(), m: U256)| {
+            let m = m | U256::from(1);
+            let inv = Uint::<64, 1>::from(m.as_limbs()[0].wrapping_neg()).inv_ring().unwrap();
+            let inv = inv.as_limbs()[0];
+            let a = a.map(|a| a.reduce_mod(m));
+            let b = b.map(|b| b.reduce_mod(m));
+            let result = unsafe {
+                mul_redc_4x8(&a.map(|a| *a.as_limbs()), &b.map(|b| *b.as_limbs()), m.as_limbs(), inv)
+            };
+            for i in 0..8 {
+                assert_eq!(result[i], *a[i].mul_redc(b[i], m, inv).as_limbs());
+            }
+        });
+    }
+}
@@ -0,0 +1,188 @@
+//! Karatsuba multiplication.
+//!
+//! Splits both operands in a high and a low half and computes the product
+//! from three half-size products instead of four, see section 1.3.2 of
+//! [BZ10]. This reduces the cost from $O(n^2)$ to $O(n^{\log_2 3})$, but has
+//! more overhead than schoolbook multiplication for small operands, see
+//! [`THRESHOLD`].
+//!
+//! [BZ10]: https://members.loria.fr/PZimmermann/mca/mca-cup-0.5.9.pdf
+
+#[cfg(feature = "ntt")]
+use super::ntt::{mul_ntt, THRESHOLD as NTT_THRESHOLD};
+use super::toom3::{mul_toom3, THRESHOLD as TOOM3_THRESHOLD};
+use crate::algorithms::{adc_n, add_nx1, addmul, sbb, sbb_n};
+use alloc::{vec, vec::Vec};
+
+/// Number of limbs of the shorter operand below which schoolbook
+/// multiplication is used.
+// Below 32 limbs the allocations outweigh the saved limb products.
+pub const THRESHOLD: usize = 32;
+
+/// ⚠️ Computes `lhs += a * b` with Karatsuba multiplication and checks for
+/// overflow.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Has the same interface as [`addmul`], which calls this for large operands.
+#[inline]
+pub fn addmul_karatsuba(lhs: &mut [u64], a: &[u64], b: &[u64]) -> bool {
+    let mut product = vec![0; a.len() + b.len()];
+    mul_karatsuba(&mut product, a, b);
+
+    // Add the product and check the excess limbs for overflow.
+    let len = lhs.len().min(product.len());
+    let (low, high) = product.split_at(len);
+    let carry = adc_n(&mut lhs[..len], low, 0);
+    let carry = add_nx1(&mut lhs[len..], carry);
+    carry != 0 || high.iter().any(|&limb| limb != 0)
+}
+
+/// ⚠️ Computes `result = a * b` with Karatsuba multiplication.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Requires `result.len() == a.len() + b.len()`. Operands shorter than
+/// [`THRESHOLD`] limbs are multiplied with schoolbook multiplication, and
+/// balanced operands of at least
+/// [`TOOM3_THRESHOLD`](crate::algorithms::TOOM3_THRESHOLD) limbs with
+/// [`mul_toom3`](crate::algorithms::mul_toom3). With the `ntt` feature,
+/// operands of at least `NTT_THRESHOLD` limbs use `mul_ntt`.
+///
+/// # Panics
+///
+/// May panic if the above requirement is not met.
+#[inline]
+pub fn mul_karatsuba(result: &mut [u64], a: &[u64], b: &[u64]) {
+    debug_assert_eq!(result.len(), a.len() + b.len());
+    let (a, b) = if b.len() > a.len() { (b, a) } else { (a, b) };
+    result.fill(0);
+    if b.len() < THRESHOLD {
+        let overflow = addmul(result, a, b);
+        debug_assert!(!overflow);
+        return;
+    }
+
+    #[cfg(feature = "ntt")]
+    if b.len() >= NTT_THRESHOLD {
+        mul_ntt(result, a, b);
+        return;
+    }
+
+    // Unbalanced operands are multiplied in blocks of `b.len()` limbs of `a`.
+    if a.len() >= 2 * b.len() {
+        let mut product = vec![0; 2 * b.len()];
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            let product = &mut product[..chunk.len() + b.len()];
+            mul_karatsuba(product, chunk, b);
+            add_assign(&mut result[i * b.len()..], product);
+        }
+        return;
+    }
+
+    // Large operands that are balanced enough for three-way splitting use
+    // Toom-3.
+    if b.len() >= TOOM3_THRESHOLD && b.len() > 2 * ((a.len() + 2) / 3) {
+        mul_toom3(result, a, b);
+        return;
+    }
+
+    // Split `a = a1 ⋅ β^k + a0` and `b = b1 ⋅ β^k + b0`. Since `b` is more
+    // than half the length of `a`, `b1` is not empty.
+    let k = a.len() / 2;
+    let (a0, a1) = a.split_at(k);
+    let (b0, b1) = b.split_at(k);
+
+    // The low and high products `a0 ⋅ b0` and `a1 ⋅ b1` fill `result`.
+    let (low, high) = result.split_at_mut(2 * k);
+    mul_karatsuba(low, a0, b0);
+    mul_karatsuba(high, a1, b1);
+
+    // The middle product `(a0 + a1) ⋅ (b0 + b1) - a0 ⋅ b0 - a1 ⋅ b1`.
+    let sa = sum(a1, a0);
+    let sb = if b1.len() >= b0.len() {
+        sum(b1, b0)
+    } else {
+        sum(b0, b1)
+    };
+    let mut middle = vec![0; sa.len() + sb.len()];
+    mul_karatsuba(&mut middle, &sa, &sb);
+    sub_assign(&mut middle, &result[..2 * k]);
+    sub_assign(&mut middle, &result[2 * k..]);
+    add_assign(&mut result[k..], &middle);
+}
+
+/// Computes `a + b` with one extra limb. Requires `a.len() >= b.len()`.
+fn sum(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = vec![0; a.len() + 1];
+    result[..a.len()].copy_from_slice(a);
+    add_assign(&mut result, b);
+    result
+}
+
+/// Computes `lhs += rhs` where the result must fit `lhs`.
+pub(super) fn add_assign(lhs: &mut [u64], rhs: &[u64]) {
+    let rhs = trim(rhs);
+    let (low, high) = lhs.split_at_mut(rhs.len());
+    let carry = adc_n(low, rhs, 0);
+    let carry = add_nx1(high, carry);
+    debug_assert_eq!(carry, 0);
+}
+
+/// Computes `lhs -= rhs` where the result must be non-negative.
+fn sub_assign(lhs: &mut [u64], rhs: &[u64]) {
+    let rhs = trim(rhs);
+    let (low, high) = lhs.split_at_mut(rhs.len());
+    let mut borrow = sbb_n(low, rhs, 0);
+    for limb in high {
+        if borrow == 0 {
+            break;
+        }
+        (*limb, borrow) = sbb(*limb, 0, borrow);
+    }
+    debug_assert_eq!(borrow, 0);
+}
+
+/// Removes the most significant zero limbs.
+fn trim(mut a: &[u64]) -> &[u64] {
+    while let [rest @ .., 0] = a {
+        a = rest;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::addmul_ref;
+    use proptest::{collection, num::u64, prop_oneof, proptest, strategy::Just};
+
+    #[test]
+    fn test_mul_karatsuba() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        let limbs = |range| collection::vec(limb.clone(), range);
+        proptest!(|(a in limbs(0..5 * THRESHOLD), b in limbs(0..5 * THRESHOLD))| {
+            let mut expected = vec![0; a.len() + b.len()];
+            addmul_ref(&mut expected, &a, &b);
+            let mut result = vec![u64::MAX; a.len() + b.len()];
+            mul_karatsuba(&mut result, &a, &b);
+            assert_eq!(result, expected);
+        });
+    }
+
+    #[test]
+    fn test_addmul_karatsuba() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        let limbs = |range| collection::vec(limb.clone(), range);
+        proptest!(|(
+            mut lhs in limbs(0..10 * THRESHOLD),
+            a in limbs(THRESHOLD..5 * THRESHOLD),
+            b in limbs(THRESHOLD..5 * THRESHOLD),
+        )| {
+            let mut expected = lhs.clone();
+            let overflow = addmul_ref(&mut expected, &a, &b);
+            assert_eq!(addmul_karatsuba(&mut lhs, &a, &b), overflow);
+            assert_eq!(lhs, expected);
+        });
+    }
+}
@@ -7,25 +7,50 @@
 
 use core::cmp::Ordering;
 
+#[cfg(all(feature = "asm", target_arch = "aarch64"))]
+pub mod aarch64;
 mod add;
 pub mod div;
 mod gcd;
+#[cfg(all(feature = "ifma", target_arch = "x86_64"))]
+#[clippy::msrv = "1.89"]
+pub mod ifma;
+#[cfg(feature = "alloc")]
+mod karatsuba;
 mod mul;
 #[cfg(feature = "alloc")] // TODO: Make mul_redc alloc-free
 mod mul_redc;
+#[cfg(feature = "ntt")]
+mod ntt;
 mod ops;
+mod primaility;
 mod shift;
+#[cfg(feature = "portable-simd")]
+pub mod simd;
+#[cfg(feature = "alloc")]
+mod toom3;
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+pub mod x86_64;
 
 pub use self::{
     add::{adc_n, sbb_n},
     div::div,
-    gcd::{gcd, gcd_extended, inv_mod, LehmerMatrix},
-    mul::{add_nx1, addmul, addmul_n, addmul_nx1, addmul_ref, mul_nx1, submul_nx1},
+    gcd::{gcd, gcd_extended, gcd_u128, inv_mod, LehmerMatrix},
+    mul::{add_nx1, addmul, addmul_n, addmul_nx1, addmul_ref, mul_nx1, sqr, submul_nx1},
     ops::{adc, sbb},
+    primaility::{is_prime, miller_rabin},
     shift::{shift_left_small, shift_right_small},
 };
 #[cfg(feature = "alloc")]
+pub use karatsuba::{addmul_karatsuba, mul_karatsuba, THRESHOLD as KARATSUBA_THRESHOLD};
+#[cfg(feature = "alloc")]
 pub use mul_redc::mul_redc;
+#[cfg(feature = "ntt")]
+pub use ntt::{mul_ntt, THRESHOLD as NTT_THRESHOLD};
+#[cfg(feature = "alloc")]
+pub(crate) use primaility::SMALL_PRIMES;
+#[cfg(feature = "alloc")]
+pub use toom3::{mul_toom3, THRESHOLD as TOOM3_THRESHOLD};
 
 trait DoubleWord<T>: Sized + Copy {
     fn join(high: T, low: T) -> Self;
@@ -54,21 +79,29 @@ impl DoubleWord<u64> for u128 {
     /// Computes `a * b` as a 128-bit value.
     #[inline(always)]
     fn mul(a: u64, b: u64) -> Self {
-        Self::from(a) * Self::from(b)
+        #[cfg(target_pointer_width = "32")]
+        return mul_32(a, b);
+
+        #[cfg(not(target_pointer_width = "32"))]
+        return Self::from(a) * Self::from(b);
     }
 
     /// Computes `a * b + c` as a 128-bit value. Note that this can not
     /// overflow.
     #[inline(always)]
     fn muladd(a: u64, b: u64, c: u64) -> Self {
-        Self::from(a) * Self::from(b) + Self::from(c)
+        Self::muladd2(a, b, c, 0)
     }
 
     /// Computes `a * b + c + d` as a 128-bit value. Note that this can not
     /// overflow.
     #[inline(always)]
     fn muladd2(a: u64, b: u64, c: u64, d: u64) -> Self {
-        Self::from(a) * Self::from(b) + Self::from(c) + Self::from(d)
+        #[cfg(target_family = "wasm")]
+        return muladd2_split(a, b, c, d);
+
+        #[cfg(not(target_family = "wasm"))]
+        return Self::mul(a, b) + Self::from(c) + Self::from(d);
     }
 
     #[inline(always)]
@@ -88,10 +121,52 @@ impl DoubleWord<u64> for u128 {
     }
 }
 
+/// Computes `a * b` from four 32-bit products.
+///
+/// Without a native 128-bit product the compiler multiplies the full 128-bit
+/// values, ignoring that the upper halves are zero. On Cortex-M0 and RV32 this
+/// takes six 64-bit multiplications instead of four, and on wasm32 it is a call
+/// to `__multi3`. This is not a `u32` limb option: the limbs stay `u64`, as
+/// they are part of the API.
+#[cfg(any(test, target_pointer_width = "32"))]
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)] // Intentional
+const fn mul_32(a: u64, b: u64) -> u128 {
+    let (a0, a1) = (a as u32 as u64, a >> 32);
+    let (b0, b1) = (b as u32 as u64, b >> 32);
+    let low = a0 * b0;
+    let (cross_0, cross_1) = (a0 * b1, a1 * b0);
+    let middle = (low >> 32) + (cross_0 & 0xffff_ffff) + (cross_1 & 0xffff_ffff);
+    let high = a1 * b1 + (cross_0 >> 32) + (cross_1 >> 32) + (middle >> 32);
+    (high as u128) << 64 | (low & 0xffff_ffff | middle << 32) as u128
+}
+
+/// Computes `a * b + c + d` with the additions on the low half.
+///
+/// Wasm has no carry flag, and a 128-bit addition compares both halves.
+/// Adding `c` and `d` to the low half and counting the carries is cheaper.
+#[cfg(any(test, target_family = "wasm"))]
+#[inline(always)]
+fn muladd2_split(a: u64, b: u64, c: u64, d: u64) -> u128 {
+    let (low, high) = u128::mul(a, b).split();
+    let (low, carry_c) = low.overflowing_add(c);
+    let (low, carry_d) = low.overflowing_add(d);
+    u128::join(high + u64::from(carry_c) + u64::from(carry_d), low)
+}
+
 /// Compare two `u64` slices in reverse order.
 #[inline(always)]
 #[must_use]
 pub fn cmp(left: &[u64], right: &[u64]) -> Ordering {
+    #[cfg(feature = "portable-simd")]
+    return simd::cmp(left, right);
+
+    #[cfg(not(feature = "portable-simd"))]
+    return cmp_scalar(left, right);
+}
+
+#[inline(always)]
+pub(crate) fn cmp_scalar(left: &[u64], right: &[u64]) -> Ordering {
     let l = core::cmp::min(left.len(), right.len());
 
     // Slice to the loop iteration range to enable bound check
@@ -116,3 +191,26 @@ pub fn cmp(left: &[u64], right: &[u64]) -> Ordering {
 
     left.len().cmp(&right.len())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{num::u64, prop_oneof, proptest, strategy::Just};
+
+    #[test]
+    fn test_mul_32() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(a in limb.clone(), b in limb)| {
+            assert_eq!(mul_32(a, b), u128::from(a) * u128::from(b));
+        });
+    }
+
+    #[test]
+    fn test_muladd2_split() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(a in limb.clone(), b in limb.clone(), c in limb.clone(), d in limb)| {
+            let expected = u128::from(a) * u128::from(b) + u128::from(c) + u128::from(d);
+            assert_eq!(muladd2_split(a, b, c, d), expected);
+        });
+    }
+}
@@ -1,6 +1,8 @@
 #![allow(clippy::module_name_repetitions)]
 
-use crate::algorithms::{ops::sbb, DoubleWord};
+#[cfg(feature = "alloc")]
+use crate::algorithms::karatsuba::{addmul_karatsuba, THRESHOLD as KARATSUBA_THRESHOLD};
+use crate::algorithms::{ops::sbb, shift_left_small, DoubleWord};
 
 #[inline]
 #[allow(clippy::cast_possible_truncation)] // Intentional truncation.
@@ -51,7 +53,9 @@ pub fn addmul_ref(result: &mut [u64], a: &[u64], b: &[u64]) -> bool {
 /// # Algorithm
 ///
 /// Trims zeros from inputs, then uses the schoolbook multiplication algorithm.
-/// It takes the shortest input as the outer loop.
+/// It takes the shortest input as the outer loop. With the `alloc` feature,
+/// full products of inputs with at least `KARATSUBA_THRESHOLD` limbs use
+/// Karatsuba multiplication instead.
 ///
 /// # Examples
 ///
@@ -64,6 +68,18 @@ pub fn addmul_ref(result: &mut [u64], a: &[u64], b: &[u64]) -> bool {
 /// ```
 #[inline(always)]
 pub fn addmul(mut lhs: &mut [u64], mut a: &[u64], mut b: &[u64]) -> bool {
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    if let (Ok(a), Ok(b)) = (a.try_into(), b.try_into()) {
+        if super::x86_64::has_adx() {
+            // SAFETY: The required extensions are available.
+            return unsafe { super::x86_64::addmul_4x4(lhs, a, b) };
+        }
+    }
+    #[cfg(all(feature = "asm", target_arch = "aarch64"))]
+    if let (Ok(a), Ok(b)) = (a.try_into(), b.try_into()) {
+        return super::aarch64::addmul_4x4(lhs, a, b);
+    }
+
     // Trim zeros from `a`
     while let [0, rest @ ..] = a {
         a = rest;
@@ -95,6 +111,11 @@ pub fn addmul(mut lhs: &mut [u64], mut a: &[u64], mut b: &[u64]) -> bool {
 
     let (a, b) = if b.len() > a.len() { (b, a) } else { (a, b) };
 
+    #[cfg(feature = "alloc")]
+    if b.len() >= KARATSUBA_THRESHOLD && lhs.len() >= a.len() + b.len() {
+        return addmul_karatsuba(lhs, a, b);
+    }
+
     // Iterate over limbs of `b` and add partial products to `lhs`.
     let mut overflow = false;
     for &b in b {
@@ -115,6 +136,76 @@ pub fn addmul(mut lhs: &mut [u64], mut a: &[u64], mut b: &[u64]) -> bool {
     overflow
 }
 
+/// ⚠️ Computes `result = a * a` modulo $2^{64⋅N}$ where `N` is the length of
+/// `result`.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Arrays are in little-endian order. All arrays can be arbitrary sized.
+///
+/// # Algorithm
+///
+/// Each product $a_i ⋅ a_j$ with $i ≠ j$ occurs twice in the square, so the
+/// products with $i < j$ are computed once and doubled with a shift. The
+/// squares $a_i^2$ are added last. This takes about half the multiplications
+/// of [`addmul`]. With the `alloc` feature, inputs of at least
+/// `KARATSUBA_THRESHOLD` limbs use Karatsuba multiplication instead.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::algorithms::sqr;
+/// let mut result = [0; 2];
+/// sqr(&mut result, &[u64::MAX]);
+/// assert_eq!(result, [1, u64::MAX - 1]);
+/// ```
+#[inline(always)]
+pub fn sqr(result: &mut [u64], mut a: &[u64]) {
+    while let [rest @ .., 0] = a {
+        a = rest;
+    }
+    result.fill(0);
+    let n = result.len();
+
+    #[cfg(feature = "alloc")]
+    if a.len() >= KARATSUBA_THRESHOLD && n >= 2 * a.len() {
+        crate::algorithms::mul_karatsuba(&mut result[..2 * a.len()], a, a);
+        return;
+    }
+
+    // Products $a_i ⋅ a_j$ with $i < j$, at offset $i + j$.
+    for (i, &limb) in a.iter().enumerate() {
+        let start = 2 * i + 1;
+        if start >= n {
+            break;
+        }
+        let row = &a[i + 1..];
+        let len = row.len().min(n - start);
+        let (target, rest) = result[start..].split_at_mut(len);
+        let carry = addmul_nx1(target, &row[..len], limb);
+        add_nx1(rest, carry);
+    }
+    shift_left_small(result, 1);
+
+    // Squares $a_i^2$, at offset $2 i$.
+    let mut carry = 0;
+    for (i, &limb) in a.iter().enumerate() {
+        if 2 * i >= n {
+            return;
+        }
+        let square = u128::mul(limb, limb);
+        let sum = u128::from(result[2 * i]) + u128::from(square.low()) + u128::from(carry);
+        result[2 * i] = sum.low();
+        if 2 * i + 1 >= n {
+            return;
+        }
+        let sum = u128::from(result[2 * i + 1]) + u128::from(square.high()) + (sum >> 64);
+        result[2 * i + 1] = sum.low();
+        carry = sum.high();
+    }
+    add_nx1(&mut result[(2 * a.len()).min(n)..], carry);
+}
+
 /// Computes `lhs += a` and returns the carry.
 #[inline(always)]
 pub fn add_nx1(lhs: &mut [u64], mut a: u64) -> u64 {
@@ -200,6 +291,11 @@ fn addmul_4(lhs: &mut [u64], a: &[u64], b: &[u64]) {
     assert_eq!(a.len(), 4);
     assert_eq!(b.len(), 4);
 
+    #[cfg(all(feature = "asm", target_arch = "aarch64"))]
+    if let (Ok(lhs), Ok(a), Ok(b)) = ((&mut *lhs).try_into(), a.try_into(), b.try_into()) {
+        return super::aarch64::addmul_4x4_low(lhs, a, b);
+    }
+
     let carry = mac(&mut lhs[0], a[0], b[0], 0);
     let carry = mac(&mut lhs[1], a[0], b[1], carry);
     let carry = mac(&mut lhs[2], a[0], b[2], carry);
@@ -247,6 +343,13 @@ pub fn mul_nx1(lhs: &mut [u64], a: u64) -> u64 {
 #[inline(always)]
 pub fn addmul_nx1(lhs: &mut [u64], a: &[u64], b: u64) -> u64 {
     debug_assert_eq!(lhs.len(), a.len());
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    if super::x86_64::has_adx() {
+        // SAFETY: The required extensions are available.
+        if let Some(carry) = unsafe { super::x86_64::addmul_nx1(lhs, a, b) } {
+            return carry;
+        }
+    }
     let mut carry = 0;
     for (lhs, a) in lhs.iter_mut().zip(a.iter().copied()) {
         let product = u128::muladd2(a, b, carry, *lhs);
@@ -291,7 +394,7 @@ pub fn submul_nx1(lhs: &mut [u64], a: &[u64], b: u64) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use proptest::{collection, num::u64, proptest};
+    use proptest::{collection, num::u64, prop_oneof, proptest, strategy::Just};
 
     #[test]
     fn test_addmul() {
@@ -315,6 +418,19 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_sqr() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        let limbs = |range| collection::vec(limb.clone(), range);
+        proptest!(|(a in limbs(0..80), len in 0_usize..160)| {
+            let mut expected = vec![0; len];
+            addmul_ref(&mut expected, &a, &a);
+            let mut result = vec![u64::MAX; len];
+            sqr(&mut result, &a);
+            assert_eq!(result, expected);
+        });
+    }
+
     #[test]
     fn test_empty() {
         test_vals(&[], &[], &[], false);
@@ -1,4 +1,4 @@
-use super::addmul;
+use super::{add_nx1, addmul, addmul_nx1};
 use core::iter::zip;
 
 /// See Handbook of Applied Cryptography, Algorithm 14.32, p. 601.
@@ -19,21 +19,9 @@ pub fn mul_redc(a: &[u64], b: &[u64], result: &mut [u64], m: &[u64], inv: u64) {
     // Reduce temp.
     for i in 0..m.len() {
         let u = temp[i].wrapping_mul(inv);
-
-        // REFACTOR: Create add_mul1 routine.
-        let mut carry = 0;
-        #[allow(clippy::cast_possible_truncation)] // Intentional
-        for j in 0..m.len() {
-            carry += u128::from(temp[i + j]) + u128::from(m[j]) * u128::from(u);
-            temp[i + j] = carry as u64;
-            carry >>= 64;
-        }
-        #[allow(clippy::cast_possible_truncation)] // Intentional
-        for j in m.len()..(temp.len() - i) {
-            carry += u128::from(temp[i + j]);
-            temp[i + j] = carry as u64;
-            carry >>= 64;
-        }
+        let (row, rest) = temp[i..].split_at_mut(m.len());
+        let carry = addmul_nx1(row, m, u);
+        let carry = add_nx1(rest, carry);
         debug_assert!(carry == 0);
     }
     debug_assert!(temp[temp.len() - 1] <= 1); // Basically a carry flag.
@@ -0,0 +1,248 @@
+//! Multiplication using number theoretic transforms.
+//!
+//! The limbs of the operands are the coefficients of two polynomials. Their
+//! product is computed with a number theoretic transform (NTT) modulo three
+//! primes of the form $c ⋅ 2^k + 1$, which makes the cost $O(n \log n)$. The
+//! coefficients of the product are less than $n ⋅ β^2$ and recovered from
+//! their residues with the Chinese remainder theorem, see section 2.7 of
+//! [BZ10].
+//!
+//! [BZ10]: https://members.loria.fr/PZimmermann/mca/mca-cup-0.5.9.pdf
+
+use crate::algorithms::adc_n;
+use alloc::{vec, vec::Vec};
+
+/// Number of limbs of the shorter operand below which Karatsuba or Toom-3
+/// multiplication is used.
+// The transforms and the carry propagation have a large constant cost, which
+// Toom-3 multiplication beats below about 2048 limbs.
+pub const THRESHOLD: usize = 2048;
+
+/// The primes $197 ⋅ 2^{55} + 1$, $87 ⋅ 2^{56} + 1$ and $131 ⋅ 2^{55} + 1$
+/// with their smallest primitive roots. They are less than $2^{63}$ and their
+/// product exceeds $2^{187}$, so transforms of up to $2^{55}$ limbs can be
+/// computed.
+const PRIMES: [Field; 3] = [
+    Field::new(7_097_673_012_735_901_697, 3),
+    Field::new(6_269_010_681_299_730_433, 5),
+    Field::new(4_719_772_409_484_279_809, 3),
+];
+
+/// ⚠️ Computes `result = a * b` with number theoretic transforms.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Requires `result.len() == a.len() + b.len()`.
+///
+/// # Panics
+///
+/// May panic if the above requirement is not met.
+#[inline]
+#[allow(clippy::cast_possible_truncation)] // Intentional
+pub fn mul_ntt(result: &mut [u64], a: &[u64], b: &[u64]) {
+    debug_assert_eq!(result.len(), a.len() + b.len());
+    result.fill(0);
+    if a.is_empty() || b.is_empty() {
+        return;
+    }
+    let size = (a.len() + b.len() - 1).next_power_of_two();
+    let residues = PRIMES.map(|field| field.convolution(a, b, size));
+
+    // Reconstruct each coefficient with Garner's algorithm and add it to the
+    // result. The carry into the next limb is less than $2^{128}$.
+    let [f1, f2, f3] = PRIMES;
+    let (p1, p2) = (f1.modulus, f2.modulus);
+    let inv_12 = f2.inverse(f2.to_montgomery(p1));
+    let inv_13 = f3.inverse(f3.to_montgomery(p1));
+    let inv_23 = f3.inverse(f3.to_montgomery(p2));
+    let p12 = u128::from(p1) * u128::from(p2);
+    let mut carry = [0_u64; 3];
+    for (i, limb) in result.iter_mut().enumerate() {
+        // The convolution has one coefficient less than the result.
+        let residue = |j: usize| residues[j].get(i).copied().unwrap_or_default();
+        let (r1, r2, r3) = (residue(0), residue(1), residue(2));
+        let v2 = f2.mul(f2.sub(r2, r1 % p2), inv_12);
+        let v3 = f3.mul(
+            f3.sub(f3.mul(f3.sub(r3, r1 % f3.modulus), inv_13), v2 % f3.modulus),
+            inv_23,
+        );
+
+        // Add $r_1 + v_2 ⋅ p_1 + v_3 ⋅ p_1 ⋅ p_2$ to the carry.
+        let low = u128::from(r1) + u128::from(v2) * u128::from(p1);
+        let middle = u128::from(v3) * u128::from(p12 as u64);
+        let high = u128::from(v3) * (p12 >> 64);
+        adc_n(&mut carry, &[low as u64, (low >> 64) as u64, 0], 0);
+        adc_n(&mut carry, &[middle as u64, (middle >> 64) as u64, 0], 0);
+        adc_n(&mut carry, &[0, high as u64, (high >> 64) as u64], 0);
+        *limb = carry[0];
+        carry = [carry[1], carry[2], 0];
+    }
+    debug_assert_eq!(carry, [0; 3]);
+}
+
+/// Arithmetic modulo a prime below $2^{63}$ using Montgomery multiplication
+/// with $R = 2^{64}$.
+#[derive(Clone, Copy)]
+struct Field {
+    modulus:   u64,
+    generator: u64,
+    /// $-\mathtt{modulus}^{-1} \bmod 2^{64}$.
+    neg_inv:   u64,
+    /// $R^2 \bmod \mathtt{modulus}$.
+    r2:        u64,
+}
+
+impl Field {
+    #[allow(clippy::cast_possible_truncation)] // Intentional
+    const fn new(modulus: u64, generator: u64) -> Self {
+        Self {
+            modulus,
+            generator,
+            neg_inv: super::div::inv_limb(modulus).wrapping_neg(),
+            r2: ((u128::MAX % modulus as u128 + 1) % modulus as u128) as u64,
+        }
+    }
+
+    /// Computes $a ⋅ b ⋅ R^{-1}$.
+    #[allow(clippy::cast_possible_truncation)] // Intentional
+    fn mul(self, a: u64, b: u64) -> u64 {
+        let product = u128::from(a) * u128::from(b);
+        let m = (product as u64).wrapping_mul(self.neg_inv);
+        let result = ((product + u128::from(m) * u128::from(self.modulus)) >> 64) as u64;
+        reduce(result, self.modulus)
+    }
+
+    const fn add(self, a: u64, b: u64) -> u64 {
+        reduce(a + b, self.modulus)
+    }
+
+    const fn sub(self, a: u64, b: u64) -> u64 {
+        let difference = a.wrapping_sub(b);
+        min(difference, difference.wrapping_add(self.modulus))
+    }
+
+    /// Computes $a ⋅ R$.
+    fn to_montgomery(self, a: u64) -> u64 {
+        self.mul(a % self.modulus, self.r2)
+    }
+
+    /// Computes $a^e$ in Montgomery form.
+    fn pow(self, mut a: u64, mut e: u64) -> u64 {
+        let mut result = self.to_montgomery(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = self.mul(result, a);
+            }
+            a = self.mul(a, a);
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Computes $a^{-1}$ in Montgomery form.
+    fn inverse(self, a: u64) -> u64 {
+        self.pow(a, self.modulus - 2)
+    }
+
+    /// Computes the cyclic convolution of `a` and `b` modulo the prime,
+    /// padded to `size` coefficients.
+    fn convolution(self, a: &[u64], b: &[u64], size: usize) -> Vec<u64> {
+        let mut fa = vec![0; size];
+        let mut fb = vec![0; size];
+        for (f, &limb) in fa.iter_mut().zip(a) {
+            *f = limb % self.modulus;
+        }
+        for (f, &limb) in fb.iter_mut().zip(b) {
+            *f = limb % self.modulus;
+        }
+        let root = self.pow(
+            self.to_montgomery(self.generator),
+            (self.modulus - 1) / size as u64,
+        );
+        self.transform(&mut fa, root);
+        self.transform(&mut fb, root);
+
+        // The pointwise products carry a factor $R^{-1}$ which is removed
+        // together with the factor `size` of the inverse transform.
+        for (a, b) in fa.iter_mut().zip(&fb) {
+            *a = self.mul(*a, *b);
+        }
+        self.transform(&mut fa, self.inverse(root));
+        let scale = self.to_montgomery(self.inverse(self.to_montgomery(size as u64)));
+        for a in &mut fa {
+            *a = self.mul(*a, scale);
+        }
+        fa
+    }
+
+    /// In-place iterative radix-2 transform with a `root` of unity of order
+    /// `values.len()` in Montgomery form. The values are not in Montgomery
+    /// form, which is preserved as the transform is linear.
+    fn transform(self, values: &mut [u64], root: u64) {
+        let n = values.len();
+        let bits = n.trailing_zeros();
+        if bits == 0 {
+            return;
+        }
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+        let mut twiddles = vec![0; n / 2];
+        let mut len = 2;
+        while len <= n {
+            // Powers of a root of unity of order `len`.
+            let step = self.pow(root, (n / len) as u64);
+            twiddles[0] = self.to_montgomery(1);
+            for i in 1..len / 2 {
+                twiddles[i] = self.mul(twiddles[i - 1], step);
+            }
+            for block in values.chunks_exact_mut(len) {
+                let (low, high) = block.split_at_mut(len / 2);
+                for ((u, v), &w) in low.iter_mut().zip(high).zip(&twiddles) {
+                    let t = self.mul(*v, w);
+                    *v = self.sub(*u, t);
+                    *u = self.add(*u, t);
+                }
+            }
+            len *= 2;
+        }
+    }
+}
+
+/// Reduces `a < 2 ⋅ modulus` without a branch, as the comparisons on random
+/// residues are unpredictable. If `a < modulus` the subtraction wraps around
+/// to a larger value.
+const fn reduce(a: u64, modulus: u64) -> u64 {
+    min(a, a.wrapping_sub(modulus))
+}
+
+const fn min(a: u64, b: u64) -> u64 {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::addmul_ref;
+    use proptest::{collection, num::u64, prop_oneof, proptest, strategy::Just};
+
+    #[test]
+    fn test_mul_ntt() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        let limbs = |range| collection::vec(limb.clone(), range);
+        proptest!(|(a in limbs(0..300), b in limbs(0..300))| {
+            let mut expected = vec![0; a.len() + b.len()];
+            addmul_ref(&mut expected, &a, &b);
+            let mut result = vec![u64::MAX; a.len() + b.len()];
+            mul_ntt(&mut result, &a, &b);
+            assert_eq!(result, expected);
+        });
+    }
+}
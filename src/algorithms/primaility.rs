@@ -1,25 +1,118 @@
+use super::gcd_u128;
+
 // Product of primes up to and including 47.
-const SMALL_PRIMES: u64 = 614889782588491410;
+pub(crate) const SMALL_PRIMES: u64 = 614889782588491410;
+
+// Bit `i` is set iff `i` is a prime less than 64.
+const SMALL_PRIMES_MASK: u64 = 0x2820_8a20_a08a_28ac;
 
 /// Miller-Rabin primality test
 ///
+/// Returns `true` if `n` is a strong probable prime to `base`. Bases that are
+/// a multiple of `n` are considered witnesses of primality.
+///
+/// `n` must be odd and larger than two.
+///
 /// See <https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test>
-pub fn miller_rabin(n: u64, base: u64) -> bool {
-    todo!{}
+#[inline]
+#[must_use]
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+pub const fn miller_rabin(n: u64, base: u64) -> bool {
+    debug_assert!(n > 2 && n % 2 == 1);
+
+    // Write n - 1 = d ⋅ 2^s with d odd.
+    let s = (n - 1).trailing_zeros();
+    let mut d = (n - 1) >> s;
+
+    // x = base^d mod n
+    let mut x = 1;
+    let mut power = base % n;
+    if power == 0 {
+        return true;
+    }
+    while d > 0 {
+        if d & 1 == 1 {
+            x = mul_mod(x, power, n);
+        }
+        power = mul_mod(power, power, n);
+        d >>= 1;
+    }
+
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    let mut i = 1;
+    while i < s {
+        x = mul_mod(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+        if x == 1 {
+            return false;
+        }
+        i += 1;
+    }
+    false
+}
+
+#[allow(clippy::cast_possible_truncation)] // Result is less than `modulus`.
+const fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
 }
 
 /// Exact 64 bit primality test
-pub fn is_prime(n: u64) -> bool {
+#[inline]
+#[must_use]
+pub const fn is_prime(n: u64) -> bool {
+    if n < 64 {
+        return (SMALL_PRIMES_MASK >> n) & 1 == 1;
+    }
+    if gcd_u128(n as u128, SMALL_PRIMES as u128) != 1 {
+        return false;
+    }
     // Sufficient set of bases for `u64`
     // See <https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test#Testing_against_small_sets_of_bases>
     // See <https://miller-rabin.appspot.com/>
     // OPT: This method <https://www.techneon.com/> ?
     // OPT: Combined basis srp
-    miller_rabin(n, 2) &&
-    miller_rabin(n, 325) &&
-    miller_rabin(n, 9375) &&
-    miller_rabin(n, 28178) &&
-    miller_rabin(n, 450775) &&
-    miller_rabin(n, 9780504) &&
-    miller_rabin(n, 1795265022)
+    miller_rabin(n, 2)
+        && miller_rabin(n, 325)
+        && miller_rabin(n, 9375)
+        && miller_rabin(n, 28178)
+        && miller_rabin(n, 450775)
+        && miller_rabin(n, 9780504)
+        && miller_rabin(n, 1795265022)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+
+    fn is_prime_ref(n: u64) -> bool {
+        n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)
+    }
+
+    #[test]
+    fn test_small() {
+        for n in 0..10_000 {
+            assert_eq!(is_prime(n), is_prime_ref(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_is_prime() {
+        proptest!(|(n in 0_u64..1 << 32)| {
+            assert_eq!(is_prime(n), is_prime_ref(n));
+        });
+        // Strong pseudoprimes to base 2.
+        for n in [2047, 3_215_031_751] {
+            assert!(miller_rabin(n, 2));
+            assert!(!is_prime(n));
+        }
+        assert!(!is_prime(4_294_967_291 * 4_294_967_279));
+        for n in [4_294_967_291, 18_446_744_073_709_551_557, (1 << 61) - 1] {
+            assert!(is_prime(n));
+        }
+    }
 }
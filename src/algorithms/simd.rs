@@ -0,0 +1,153 @@
+//! Limb operations using portable SIMD.
+//!
+//! Comparisons and bitwise operations process [`LANES`] limbs at a time.
+//! Addition computes the lane sums in parallel and resolves the carries
+//! between lanes with bitmasks: a lane generates a carry if its sum wraps and
+//! propagates an incoming carry if its sum is `u64::MAX`, which is the same
+//! problem as adding two `LANES` bit numbers.
+//!
+//! On x86-64 this pays off with AVX2, e.g. with `-C target-cpu=native`.
+//! Without it the 64-bit lane comparisons are emulated and addition is slower
+//! than the scalar carry chain.
+//!
+//! Requires a nightly compiler for [`core::simd`].
+
+use core::{
+    cmp::Ordering,
+    simd::{
+        cmp::{SimdPartialEq, SimdPartialOrd},
+        u64x4,
+    },
+};
+
+/// Number of limbs per vector.
+pub const LANES: usize = 4;
+
+/// ⚠️ Compare two `u64` slices in reverse order.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Has the same interface as [`cmp`](crate::algorithms::cmp).
+#[inline]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)] // Intentional
+pub fn cmp(left: &[u64], right: &[u64]) -> Ordering {
+    let len = left.len().min(right.len());
+    let mut lhs = left[..len].rchunks_exact(LANES);
+    let mut rhs = right[..len].rchunks_exact(LANES);
+    for (a, b) in (&mut lhs).zip(&mut rhs) {
+        let (a, b) = (u64x4::from_slice(a), u64x4::from_slice(b));
+        let differ = a.simd_ne(b).to_bitmask();
+        if differ != 0 {
+            // The most significant lane that differs decides.
+            let i = (63 - differ.leading_zeros()) as usize;
+            return a[i].cmp(&b[i]);
+        }
+    }
+    match super::cmp_scalar(lhs.remainder(), rhs.remainder()) {
+        Ordering::Equal => left.len().cmp(&right.len()),
+        ordering => ordering,
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($fn:ident, $op:tt) => {
+        /// ⚠️ Computes `lhs
+        #[doc = stringify!($op)]
+        /// rhs` limb by limb.
+        ///
+        /// **Warning.** This function is not part of the stable API.
+        ///
+        /// Requires `lhs.len() <= rhs.len()`.
+        #[inline]
+        pub fn $fn(lhs: &mut [u64], rhs: &[u64]) {
+            let mut rhs = rhs[..lhs.len()].chunks_exact(LANES);
+            let mut lhs = lhs.chunks_exact_mut(LANES);
+            for (a, b) in (&mut lhs).zip(&mut rhs) {
+                let mut result = u64x4::from_slice(a);
+                result $op u64x4::from_slice(b);
+                result.copy_to_slice(a);
+            }
+            for (a, b) in lhs.into_remainder().iter_mut().zip(rhs.remainder()) {
+                *a $op *b;
+            }
+        }
+    };
+}
+
+impl_bit_op!(bitand_assign, &=);
+impl_bit_op!(bitor_assign, |=);
+impl_bit_op!(bitxor_assign, ^=);
+
+/// ⚠️ `lhs += rhs + carry`
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Has the same interface as [`adc_n`](crate::algorithms::adc_n).
+#[inline]
+pub fn adc_n(lhs: &mut [u64], rhs: &[u64], mut carry: u64) -> u64 {
+    debug_assert!(carry <= 1);
+    let mut rhs = rhs[..lhs.len()].chunks_exact(LANES);
+    let mut lhs = lhs.chunks_exact_mut(LANES);
+    for (a, b) in (&mut lhs).zip(&mut rhs) {
+        let sum = u64x4::from_slice(a) + u64x4::from_slice(b);
+        let generate = sum.simd_lt(u64x4::from_slice(a)).to_bitmask();
+        let propagate = sum.simd_eq(u64x4::splat(u64::MAX)).to_bitmask();
+
+        // A carry enters each lane that follows a generating lane, or a run
+        // of propagating lanes that follows one.
+        let incoming = (generate << 1 | carry) + propagate;
+        let carries = incoming ^ propagate;
+        let shifts = u64x4::from_array([0, 1, 2, 3]);
+        let result = sum + (u64x4::splat(carries) >> shifts & u64x4::splat(1));
+        result.copy_to_slice(a);
+        carry = carries >> LANES & 1;
+    }
+    super::add::adc_n_scalar(lhs.into_remainder(), rhs.remainder(), carry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{add::adc_n_scalar, cmp_scalar};
+    use proptest::{collection, num::u64, prop_oneof, proptest, strategy::Just};
+
+    #[test]
+    fn test_cmp() {
+        let limb = prop_oneof![Just(0), Just(1), Just(u64::MAX), u64::ANY];
+        proptest!(|(a in collection::vec(limb.clone(), 0..20), mut b in collection::vec(limb, 0..20))| {
+            assert_eq!(cmp(&a, &b), cmp_scalar(&a, &b));
+            let len = a.len().min(b.len());
+            b[..len].copy_from_slice(&a[..len]);
+            assert_eq!(cmp(&a, &b), a.len().cmp(&b.len()));
+        });
+    }
+
+    #[test]
+    fn test_bit_ops() {
+        proptest!(|(a in collection::vec(u64::ANY, 0..20), b in collection::vec(u64::ANY, 20))| {
+            let mut and = a.clone();
+            bitand_assign(&mut and, &b);
+            let mut or = a.clone();
+            bitor_assign(&mut or, &b);
+            let mut xor = a.clone();
+            bitxor_assign(&mut xor, &b);
+            for i in 0..a.len() {
+                assert_eq!(and[i], a[i] & b[i]);
+                assert_eq!(or[i], a[i] | b[i]);
+                assert_eq!(xor[i], a[i] ^ b[i]);
+            }
+        });
+    }
+
+    #[test]
+    fn test_adc_n() {
+        let limb = prop_oneof![Just(0), Just(1), Just(u64::MAX), u64::ANY];
+        proptest!(|(mut a in collection::vec(limb.clone(), 0..20), b in collection::vec(limb, 20), carry in 0..=1_u64)| {
+            let mut expected = a.clone();
+            let expected_carry = adc_n_scalar(&mut expected, &b, carry);
+            assert_eq!(adc_n(&mut a, &b, carry), expected_carry);
+            assert_eq!(a, expected);
+        });
+    }
+}
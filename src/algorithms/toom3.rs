@@ -0,0 +1,179 @@
+//! Toom–Cook 3-way multiplication.
+//!
+//! Splits both operands in three parts, evaluates them as polynomials at the
+//! points $0, 1, -1, 2, ∞$ and interpolates the product from five products of
+//! a third of the size, see section 1.3.3 of [BZ10]. This reduces the cost to
+//! $O(n^{\log_3 5})$, but it only beats [`mul_karatsuba`] for large operands,
+//! see [`THRESHOLD`].
+//!
+//! [BZ10]: https://members.loria.fr/PZimmermann/mca/mca-cup-0.5.9.pdf
+
+use super::{
+    div::div_exact,
+    karatsuba::{add_assign, mul_karatsuba},
+};
+use crate::algorithms::{adc_n, add_nx1, cmp, sbb_n, shift_left_small, shift_right_small};
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+/// Number of limbs of the shorter operand below which [`mul_karatsuba`] is
+/// used.
+// The evaluation and interpolation cost more than Karatsuba multiplication
+// saves below 128 limbs.
+pub const THRESHOLD: usize = 128;
+
+/// ⚠️ Computes `result = a * b` with Toom–Cook 3-way multiplication.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Requires `result.len() == a.len() + b.len()` and the operands to be
+/// balanced, i.e. after splitting the longer operand in three parts of
+/// $k = \ceil{\mathtt{len} / 3}$ limbs the shorter operand must have more
+/// than $2k$ limbs. Other operands are delegated to [`mul_karatsuba`].
+///
+/// # Panics
+///
+/// May panic if the above requirements are not met.
+#[inline]
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+pub fn mul_toom3(result: &mut [u64], a: &[u64], b: &[u64]) {
+    debug_assert_eq!(result.len(), a.len() + b.len());
+    let (a, b) = if b.len() > a.len() { (b, a) } else { (a, b) };
+    let k = (a.len() + 2) / 3;
+    if b.len() <= 2 * k {
+        mul_karatsuba(result, a, b);
+        return;
+    }
+
+    // Evaluate at $-1$, $1$ and $2$. The values are less than $7 ⋅ β^k$, so
+    // `k + 1` limbs suffice and the products fit `len` limbs.
+    let len = 2 * k + 2;
+    let (a0, a1, a2) = (&a[..k], &a[k..2 * k], &a[2 * k..]);
+    let (b0, b1, b2) = (&b[..k], &b[k..2 * k], &b[2 * k..]);
+    let (am1, a_negative, a1_) = evaluate(a0, a1, a2);
+    let (bm1, b_negative, b1_) = evaluate(b0, b1, b2);
+    let a2_ = evaluate_2(a0, a1, a2);
+    let b2_ = evaluate_2(b0, b1, b2);
+
+    // The products at $0$ and $∞$ are the lowest and highest limbs of the
+    // result.
+    let (v0, high) = result.split_at_mut(2 * k);
+    let (middle, vinf) = high.split_at_mut(2 * k);
+    middle.fill(0);
+    mul_karatsuba(v0, a0, b0);
+    mul_karatsuba(vinf, a2, b2);
+    let v1 = product(&a1_, &b1_);
+    let v2 = product(&a2_, &b2_);
+    let mut vm1 = product(&am1, &bm1);
+    if a_negative != b_negative {
+        negate(&mut vm1);
+    }
+
+    // Interpolate, following Bodrato's sequence from section 1.3.3 of [BZ10].
+    // All final values are non-negative and fit `len` limbs, so the
+    // intermediate values can be computed modulo $β^{len}$.
+    let v0 = padded(&result[..2 * k], len);
+    let vinf = padded(&result[4 * k..], len);
+
+    // $t_1 = (3 v_0 + 2 v_{-1} + v_2) / 6 - 2 v_∞$
+    let mut t1 = v0.clone();
+    shift_left_small(&mut t1, 1);
+    adc_n(&mut t1, &v0, 0);
+    let mut twice = vm1.clone();
+    shift_left_small(&mut twice, 1);
+    adc_n(&mut t1, &twice, 0);
+    adc_n(&mut t1, &v2, 0);
+    shift_right_small(&mut t1, 1);
+    div_exact(&mut t1, &[3]);
+    let mut twice = vinf.clone();
+    shift_left_small(&mut twice, 1);
+    sbb_n(&mut t1, &twice, 0);
+
+    // $t_2 = (v_1 + v_{-1}) / 2$
+    let mut t2 = v1.clone();
+    adc_n(&mut t2, &vm1, 0);
+    shift_right_small(&mut t2, 1);
+
+    // $c_1 = v_1 - t_1$, $c_2 = t_2 - v_0 - v_∞$ and $c_3 = t_1 - t_2$.
+    let mut c1 = v1;
+    sbb_n(&mut c1, &t1, 0);
+    let mut c2 = t2.clone();
+    sbb_n(&mut c2, &v0, 0);
+    sbb_n(&mut c2, &vinf, 0);
+    let mut c3 = t1;
+    sbb_n(&mut c3, &t2, 0);
+
+    add_assign(&mut result[k..], &c1);
+    add_assign(&mut result[2 * k..], &c2);
+    add_assign(&mut result[3 * k..], &c3);
+}
+
+/// Evaluates `p(x) = p2 ⋅ x^2 + p1 ⋅ x + p0` at $-1$ and $1$ in `k + 1`
+/// limbs. Returns $|p(-1)|$, whether $p(-1)$ is negative, and $p(1)$.
+fn evaluate(p0: &[u64], p1: &[u64], p2: &[u64]) -> (Vec<u64>, bool, Vec<u64>) {
+    let mut even = padded(p0, p0.len() + 1);
+    add_assign(&mut even, p2);
+    let odd = padded(p1, p0.len() + 1);
+    let mut one = even.clone();
+    add_assign(&mut one, &odd);
+    if cmp(&even, &odd) == Ordering::Less {
+        let mut minus_one = odd;
+        sbb_n(&mut minus_one, &even, 0);
+        (minus_one, true, one)
+    } else {
+        sbb_n(&mut even, &odd, 0);
+        (even, false, one)
+    }
+}
+
+/// Evaluates `p(x) = p2 ⋅ x^2 + p1 ⋅ x + p0` at $2$ in `k + 1` limbs.
+fn evaluate_2(p0: &[u64], p1: &[u64], p2: &[u64]) -> Vec<u64> {
+    let mut result = padded(p2, p0.len() + 1);
+    shift_left_small(&mut result, 1);
+    add_assign(&mut result, p1);
+    shift_left_small(&mut result, 1);
+    add_assign(&mut result, p0);
+    result
+}
+
+/// Computes the product of two operands of equal length.
+fn product(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = vec![0; a.len() + b.len()];
+    mul_karatsuba(&mut result, a, b);
+    result
+}
+
+/// Computes the two's complement of `a`.
+fn negate(a: &mut [u64]) {
+    for limb in &mut *a {
+        *limb = !*limb;
+    }
+    add_nx1(a, 1);
+}
+
+/// Copies `a` to a vector of `len` limbs.
+fn padded(a: &[u64], len: usize) -> Vec<u64> {
+    let mut result = vec![0; len];
+    result[..a.len()].copy_from_slice(a);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::addmul_ref;
+    use proptest::{collection, num::u64, prop_oneof, proptest, strategy::Just};
+
+    #[test]
+    fn test_mul_toom3() {
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        let limbs = |range| collection::vec(limb.clone(), range);
+        proptest!(|(a in limbs(0..4 * THRESHOLD), b in limbs(0..4 * THRESHOLD))| {
+            let mut expected = vec![0; a.len() + b.len()];
+            addmul_ref(&mut expected, &a, &b);
+            let mut result = vec![u64::MAX; a.len() + b.len()];
+            mul_toom3(&mut result, &a, &b);
+            assert_eq!(result, expected);
+        });
+    }
+}
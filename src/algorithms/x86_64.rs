@@ -0,0 +1,361 @@
+//! x86-64 kernels using the ADX and BMI2 extensions.
+//!
+//! `MULX` multiplies without touching the flags, and `ADCX` and `ADOX` add with
+//! carry through the carry and overflow flag respectively. Each row of a
+//! schoolbook product then runs two independent carry chains, one for the low
+//! and one for the high halves of the partial products.
+//!
+//! Four limb products, i.e. `U256` products, keep the whole accumulator in
+//! registers. Other products up to eight limbs wide, and the reduction step of
+//! `mul_redc`, add one row at a time with [`addmul_nx1`]. Truncated `U256`
+//! products and plain `ADC` and `SBB` chains for additions and subtractions
+//! measured slower than the compiler's output and use the portable code.
+
+use crate::algorithms::add_nx1;
+use core::arch::asm;
+
+/// Whether the ADX and BMI2 extensions are available.
+///
+/// This is known at compile time if the target enables them, and is detected
+/// at runtime with the `std` feature. Otherwise it is `false`.
+#[inline(always)]
+#[must_use]
+#[allow(clippy::missing_const_for_fn)] // Runtime detection is not const.
+pub fn has_adx() -> bool {
+    #[cfg(all(target_feature = "adx", target_feature = "bmi2"))]
+    return true;
+
+    #[cfg(all(
+        not(all(target_feature = "adx", target_feature = "bmi2")),
+        feature = "std"
+    ))]
+    {
+        use core::sync::atomic::{AtomicU8, Ordering};
+
+        // The kernels check this once per row, so the result is cached in a
+        // single byte: zero if unknown, one if unavailable and two if
+        // available.
+        static DETECTED: AtomicU8 = AtomicU8::new(0);
+        match DETECTED.load(Ordering::Relaxed) {
+            0 => {
+                let detected =
+                    std::is_x86_feature_detected!("adx") && std::is_x86_feature_detected!("bmi2");
+                DETECTED.store(1 + u8::from(detected), Ordering::Relaxed);
+                detected
+            }
+            state => state == 2,
+        }
+    }
+
+    #[cfg(all(
+        not(all(target_feature = "adx", target_feature = "bmi2")),
+        not(feature = "std")
+    ))]
+    return false;
+}
+
+/// ⚠️ Computes the full product `result = a * b` of four limb operands.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// # Safety
+///
+/// Requires the ADX and BMI2 extensions, see [`has_adx`].
+#[inline]
+pub unsafe fn mul_4x4(result: &mut [u64; 8], a: &[u64; 4], b: &[u64; 4]) {
+    // The accumulator rotates through `r8`–`r12`. After each row its lowest
+    // limb is final and its register receives the top of the next row.
+    unsafe {
+        asm!(
+            // Row 0 has a single carry chain.
+            "mov rdx, [{a}]",
+            "mulx r9, r8, [{b}]",
+            "mulx r10, rax, [{b} + 8]",
+            "add r9, rax",
+            "mulx r11, rax, [{b} + 16]",
+            "adc r10, rax",
+            "mulx r12, rax, [{b} + 24]",
+            "adc r11, rax",
+            "adc r12, 0",
+            "mov [{result}], r8",
+            // Row 1, accumulator r9, r10, r11, r12, r8.
+            "mov rdx, [{a} + 8]",
+            "xor r8d, r8d",
+            "mulx rcx, rax, [{b}]",
+            "adcx r9, rax",
+            "adox r10, rcx",
+            "mulx rcx, rax, [{b} + 8]",
+            "adcx r10, rax",
+            "adox r11, rcx",
+            "mulx rcx, rax, [{b} + 16]",
+            "adcx r11, rax",
+            "adox r12, rcx",
+            "mulx rcx, rax, [{b} + 24]",
+            "adcx r12, rax",
+            "adox r8, rcx",
+            "mov eax, 0",
+            "adcx r8, rax",
+            "mov [{result} + 8], r9",
+            // Row 2, accumulator r10, r11, r12, r8, r9.
+            "mov rdx, [{a} + 16]",
+            "xor r9d, r9d",
+            "mulx rcx, rax, [{b}]",
+            "adcx r10, rax",
+            "adox r11, rcx",
+            "mulx rcx, rax, [{b} + 8]",
+            "adcx r11, rax",
+            "adox r12, rcx",
+            "mulx rcx, rax, [{b} + 16]",
+            "adcx r12, rax",
+            "adox r8, rcx",
+            "mulx rcx, rax, [{b} + 24]",
+            "adcx r8, rax",
+            "adox r9, rcx",
+            "mov eax, 0",
+            "adcx r9, rax",
+            "mov [{result} + 16], r10",
+            // Row 3, accumulator r11, r12, r8, r9, r10.
+            "mov rdx, [{a} + 24]",
+            "xor r10d, r10d",
+            "mulx rcx, rax, [{b}]",
+            "adcx r11, rax",
+            "adox r12, rcx",
+            "mulx rcx, rax, [{b} + 8]",
+            "adcx r12, rax",
+            "adox r8, rcx",
+            "mulx rcx, rax, [{b} + 16]",
+            "adcx r8, rax",
+            "adox r9, rcx",
+            "mulx rcx, rax, [{b} + 24]",
+            "adcx r9, rax",
+            "adox r10, rcx",
+            "mov eax, 0",
+            "adcx r10, rax",
+            "mov [{result} + 24], r11",
+            "mov [{result} + 32], r12",
+            "mov [{result} + 40], r8",
+            "mov [{result} + 48], r9",
+            "mov [{result} + 56], r10",
+            result = in(reg) result.as_mut_ptr(),
+            a = in(reg) a.as_ptr(),
+            b = in(reg) b.as_ptr(),
+            out("rax") _,
+            out("rcx") _,
+            out("rdx") _,
+            out("r8") _,
+            out("r9") _,
+            out("r10") _,
+            out("r11") _,
+            out("r12") _,
+            options(nostack),
+        );
+    }
+}
+
+/// ⚠️ Computes `lhs += a * b` for four limb operands and checks for overflow.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Limbs of the product past the end of `lhs` count as overflow, like in
+/// [`addmul`](crate::algorithms::addmul).
+///
+/// # Safety
+///
+/// Requires the ADX and BMI2 extensions, see [`has_adx`].
+#[inline]
+pub unsafe fn addmul_4x4(lhs: &mut [u64], a: &[u64; 4], b: &[u64; 4]) -> bool {
+    let mut product = [0; 8];
+    unsafe { mul_4x4(&mut product, a, b) };
+    if lhs.len() >= 8 {
+        let (low, high) = lhs.split_at_mut(8);
+        let carry = super::adc_n(low, &product, 0);
+        add_nx1(high, carry) != 0
+    } else {
+        let (low, high) = product.split_at(lhs.len());
+        let carry = super::adc_n(lhs, low, 0);
+        carry != 0 || high.iter().any(|&limb| limb != 0)
+    }
+}
+
+/// Defines a kernel computing `lhs += a * b` for a single limb `b`, which
+/// returns the carry limb.
+///
+/// `$high` is the register holding the high half of the last partial product,
+/// and each following limb lists its offset, the register for its high half and
+/// the one holding the high half of the previous limb.
+macro_rules! addmul_nx1 {
+    ($name:ident, $n:literal, $high:literal, [$(($offset:literal, $high_j:literal, $previous:literal)),*]) => {
+        #[doc = concat!("⚠️ Computes `lhs += a * b` for ", $n, " limbs and returns the carry.")]
+        ///
+        /// **Warning.** This function is not part of the stable API.
+        ///
+        /// # Safety
+        ///
+        /// Requires the ADX and BMI2 extensions, see [`has_adx`].
+        #[inline]
+        pub unsafe fn $name(lhs: &mut [u64; $n], a: &[u64; $n], b: u64) -> u64 {
+            let carry: u64;
+            // `ADCX` adds the low halves to `lhs` and `ADOX` the high halves of
+            // the previous limb. `rcx` stays zero to add the final flags.
+            unsafe {
+                asm!(
+                    "xor ecx, ecx",
+                    "mulx r8, rax, [{a}]",
+                    "adcx rax, [{lhs}]",
+                    "mov [{lhs}], rax",
+                    $(
+                        concat!("mulx ", $high_j, ", rax, [{a} + ", $offset, "]"),
+                        concat!("adcx rax, [{lhs} + ", $offset, "]"),
+                        concat!("adox rax, ", $previous),
+                        concat!("mov [{lhs} + ", $offset, "], rax"),
+                    )*
+                    concat!("adcx ", $high, ", rcx"),
+                    concat!("adox ", $high, ", rcx"),
+                    concat!("mov {carry}, ", $high),
+                    lhs = in(reg) lhs.as_mut_ptr(),
+                    a = in(reg) a.as_ptr(),
+                    carry = out(reg) carry,
+                    in("rdx") b,
+                    out("rax") _,
+                    out("rcx") _,
+                    out("r8") _,
+                    out("r9") _,
+                    options(nostack),
+                );
+            }
+            carry
+        }
+    };
+}
+
+addmul_nx1!(addmul_1x1, 1, "r8", []);
+addmul_nx1!(addmul_2x1, 2, "r9", [(8, "r9", "r8")]);
+addmul_nx1!(addmul_3x1, 3, "r8", [(8, "r9", "r8"), (16, "r8", "r9")]);
+addmul_nx1!(addmul_4x1, 4, "r9", [
+    (8, "r9", "r8"),
+    (16, "r8", "r9"),
+    (24, "r9", "r8")
+]);
+addmul_nx1!(addmul_5x1, 5, "r8", [
+    (8, "r9", "r8"),
+    (16, "r8", "r9"),
+    (24, "r9", "r8"),
+    (32, "r8", "r9")
+]);
+addmul_nx1!(addmul_6x1, 6, "r9", [
+    (8, "r9", "r8"),
+    (16, "r8", "r9"),
+    (24, "r9", "r8"),
+    (32, "r8", "r9"),
+    (40, "r9", "r8")
+]);
+addmul_nx1!(addmul_7x1, 7, "r8", [
+    (8, "r9", "r8"),
+    (16, "r8", "r9"),
+    (24, "r9", "r8"),
+    (32, "r8", "r9"),
+    (40, "r9", "r8"),
+    (48, "r8", "r9")
+]);
+addmul_nx1!(addmul_8x1, 8, "r9", [
+    (8, "r9", "r8"),
+    (16, "r8", "r9"),
+    (24, "r9", "r8"),
+    (32, "r8", "r9"),
+    (40, "r9", "r8"),
+    (48, "r8", "r9"),
+    (56, "r9", "r8")
+]);
+
+/// ⚠️ Computes `lhs += a * b` for up to eight limbs and returns the carry.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Returns `None` if the lengths differ or exceed eight limbs.
+///
+/// # Safety
+///
+/// Requires the ADX and BMI2 extensions, see [`has_adx`].
+#[inline]
+pub unsafe fn addmul_nx1(lhs: &mut [u64], a: &[u64], b: u64) -> Option<u64> {
+    if lhs.len() != a.len() {
+        return None;
+    }
+    // SAFETY: Forwarded to the caller.
+    unsafe {
+        Some(match lhs.len() {
+            1 => addmul_1x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            2 => addmul_2x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            3 => addmul_3x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            4 => addmul_4x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            5 => addmul_5x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            6 => addmul_6x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            7 => addmul_7x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            8 => addmul_8x1(lhs.try_into().ok()?, a.try_into().ok()?, b),
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::addmul_ref;
+    use proptest::{array::uniform4, collection, num::u64, prop_oneof, proptest, strategy::Just};
+
+    #[test]
+    fn test_mul_4x4() {
+        if !has_adx() {
+            return;
+        }
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(a in uniform4(limb.clone()), b in uniform4(limb))| {
+            let mut expected = [0; 8];
+            addmul_ref(&mut expected, &a, &b);
+            let mut result = [0; 8];
+            unsafe { mul_4x4(&mut result, &a, &b) };
+            assert_eq!(result, expected);
+        });
+    }
+
+    #[test]
+    fn test_addmul_4x4() {
+        if !has_adx() {
+            return;
+        }
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(
+            a in uniform4(limb.clone()),
+            b in uniform4(limb.clone()),
+            mut lhs in collection::vec(limb, 0..12),
+        )| {
+            let mut expected = lhs.clone();
+            let overflow = addmul_ref(&mut expected, &a, &b);
+            assert_eq!(unsafe { addmul_4x4(&mut lhs, &a, &b) }, overflow);
+            assert_eq!(lhs, expected);
+        });
+    }
+
+    #[test]
+    fn test_addmul_nx1() {
+        if !has_adx() {
+            return;
+        }
+        let limb = prop_oneof![Just(0), Just(u64::MAX), u64::ANY];
+        proptest!(|(
+            mut lhs in collection::vec(limb.clone(), 1..=8),
+            a in collection::vec(limb.clone(), 8),
+            b in limb,
+        )| {
+            let a = &a[..lhs.len()];
+            let mut expected = lhs.clone();
+            expected.push(0);
+            addmul_ref(&mut expected, a, &[b]);
+            let carry = unsafe { addmul_nx1(&mut lhs, a, b) };
+            lhs.push(carry.unwrap());
+            assert_eq!(lhs, expected);
+        });
+        assert_eq!(unsafe { addmul_nx1(&mut [0; 9], &[0; 9], 1) }, None);
+        assert_eq!(unsafe { addmul_nx1(&mut [0; 4], &[0; 5], 1) }, None);
+    }
+}
@@ -0,0 +1,199 @@
+#![cfg(feature = "alloc")] // see comments on `mul_mod`
+
+use crate::{algorithms, Uint};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Number of modulus limbs for which the scratch space of a reduction fits a
+/// stack buffer, i.e. up to the common `U256` case.
+const STACK_LIMBS: usize = 4;
+
+/// Number of scratch limbs [`BarrettReducer::reduce_limbs`] needs for a
+/// modulus of `k` limbs: `2k` for the value and `2k + 3` and `k + 1` for the
+/// products in [`BarrettReducer::reduce_step`].
+const fn scratch_len(k: usize) -> usize {
+    5 * k + 4
+}
+
+/// Returns the first `len` limbs of `stack` if they fit, otherwise of `heap`
+/// grown to `len` zeros.
+fn scratch<'a>(stack: &'a mut [u64], heap: &'a mut Vec<u64>, len: usize) -> &'a mut [u64] {
+    if len <= stack.len() {
+        &mut stack[..len]
+    } else {
+        heap.resize(len, 0);
+        &mut heap[..]
+    }
+}
+
+/// Precomputed context for Barrett reduction modulo a fixed `modulus`.
+///
+/// Unlike [`Montgomery`](crate::Montgomery) this works for any non-zero
+/// modulus, including even ones, and the values stay in their normal
+/// representation. Reduction only requires multiplications by the
+/// precomputed reciprocal, which makes it faster than [`Uint::reduce_mod`]
+/// and [`Uint::mul_mod`] when many values are reduced by the same modulus.
+///
+/// ```
+/// # use ruint::{uint, BarrettReducer, aliases::U256};
+/// # uint!{
+/// let modulus =
+///     0x8000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_U256;
+/// let ctx = BarrettReducer::new(modulus - 2_U256).unwrap();
+/// assert_eq!(ctx.reduce(modulus), 2_U256);
+/// assert_eq!(ctx.mul_mod(modulus, modulus), 4_U256);
+/// assert_eq!(ctx.add_mod(modulus, modulus), 4_U256);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BarrettReducer<const BITS: usize, const LIMBS: usize> {
+    modulus: Uint<BITS, LIMBS>,
+    /// $\floor{b^{2k} / \mathtt{modulus}}$ where $b = 2^{64}$ and $k$ is the
+    /// number of non-zero limbs in the modulus. Stored in $k + 2$ limbs.
+    mu:      Vec<u64>,
+}
+
+impl<const BITS: usize, const LIMBS: usize> BarrettReducer<BITS, LIMBS> {
+    /// Precompute the reciprocal of `modulus`.
+    ///
+    /// Returns `None` if `modulus` is zero.
+    #[inline]
+    #[must_use]
+    pub fn new(modulus: Uint<BITS, LIMBS>) -> Option<Self> {
+        if modulus == Uint::ZERO {
+            return None;
+        }
+        let k = (modulus.bit_len() + 63) / 64;
+
+        // Compute mu = floor(b^2k / modulus). The quotient is stored in the
+        // numerator.
+        let mut mu = vec![0; 2 * k + 1];
+        mu[2 * k] = 1;
+        let mut divisor = modulus;
+        algorithms::div(&mut mu, &mut divisor.limbs[..k]);
+        mu.truncate(k + 2);
+        Some(Self { modulus, mu })
+    }
+
+    /// The modulus of this context.
+    #[inline]
+    #[must_use]
+    pub const fn modulus(&self) -> Uint<BITS, LIMBS> {
+        self.modulus
+    }
+
+    /// Compute $\mod{\mathtt{value}}_{\mathtt{modulus}}$.
+    #[inline]
+    #[must_use]
+    pub fn reduce(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.reduce_limbs(value.as_limbs())
+    }
+
+    /// Compute $\mod{\mathtt{lhs} + \mathtt{rhs}}_{\mathtt{modulus}}$.
+    #[inline]
+    #[must_use]
+    pub fn add_mod(&self, lhs: Uint<BITS, LIMBS>, rhs: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.reduce(lhs).add_mod(self.reduce(rhs), self.modulus)
+    }
+
+    /// Compute $\mod{\mathtt{lhs} ⋅ \mathtt{rhs}}_{\mathtt{modulus}}$.
+    #[inline]
+    #[must_use]
+    pub fn mul_mod(&self, lhs: Uint<BITS, LIMBS>, rhs: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        let mut stack = [0; 2 * STACK_LIMBS];
+        let mut heap = Vec::new();
+        let product = scratch(&mut stack, &mut heap, 2 * LIMBS);
+        let overflow = algorithms::addmul(product, lhs.as_limbs(), rhs.as_limbs());
+        debug_assert!(!overflow);
+        self.reduce_limbs(product)
+    }
+
+    /// Reduce a little-endian limb slice of arbitrary length.
+    fn reduce_limbs(&self, limbs: &[u64]) -> Uint<BITS, LIMBS> {
+        let k = self.mu.len() - 2;
+
+        // Reduce the most significant (up to) `2k` limbs first, then fold in
+        // the remaining limbs `k` at a time.
+        let rest = if limbs.len() > 2 * k {
+            (limbs.len() - k - 1) / k * k
+        } else {
+            0
+        };
+        let (rest, top) = limbs.split_at(rest);
+        let mut stack = [0; scratch_len(STACK_LIMBS)];
+        let mut heap = Vec::new();
+        let (buffer, products) = scratch(&mut stack, &mut heap, scratch_len(k)).split_at_mut(2 * k);
+        buffer[..top.len()].copy_from_slice(top);
+        self.reduce_step(buffer, products);
+        for chunk in rest.chunks(k).rev() {
+            buffer.copy_within(..k, k);
+            buffer[..k].copy_from_slice(chunk);
+            self.reduce_step(buffer, products);
+        }
+
+        let mut result = Uint::ZERO;
+        result.limbs[..k].copy_from_slice(&buffer[..k]);
+        debug_assert!(result < self.modulus);
+        result
+    }
+
+    /// Reduce the `2k` limbs in `x` in place. The result is stored in the
+    /// lower `k` limbs, the upper `k` limbs are cleared. The `3k + 4` limbs in
+    /// `products` are scratch space.
+    ///
+    /// See Handbook of Applied Cryptography, Algorithm 14.42, p. 604.
+    fn reduce_step(&self, x: &mut [u64], products: &mut [u64]) {
+        let k = self.mu.len() - 2;
+        debug_assert_eq!(x.len(), 2 * k);
+        debug_assert_eq!(products.len(), scratch_len(k) - 2 * k);
+        products.fill(0);
+        let (q, qm) = products.split_at_mut(2 * k + 3);
+
+        // Estimate the quotient q3 = floor(floor(x / b^(k-1)) * mu / b^(k+1)).
+        algorithms::addmul(q, &x[k - 1..], &self.mu);
+        let q = &q[k + 1..];
+
+        // Compute r = (x - q3 * modulus) mod b^(k+1).
+        let modulus = &self.modulus.as_limbs()[..k];
+        algorithms::addmul(qm, q, modulus);
+        let r = &mut x[..=k];
+        algorithms::sbb_n(r, qm, 0);
+
+        // The estimate is off by at most two.
+        while r[k] != 0 || algorithms::cmp(&r[..k], modulus) != Ordering::Less {
+            r[k] -= algorithms::sbb_n(&mut r[..k], modulus, 0);
+        }
+        x[k..].fill(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_zero() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            assert!(BarrettReducer::new(Uint::<BITS, LIMBS>::ZERO).is_none());
+        });
+    }
+
+    #[test]
+    fn test_reduce() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, m: U, shift: usize)| {
+                // Also test small moduli relative to the bit size.
+                let m = (m >> (shift % (64 * LIMBS))).max(U::from(1));
+                let ctx = BarrettReducer::new(m).unwrap();
+                assert_eq!(ctx.reduce(a), a.reduce_mod(m));
+                assert_eq!(ctx.add_mod(a, b), a.add_mod(b, m));
+                assert_eq!(ctx.mul_mod(a, b), a.mul_mod(b, m));
+            });
+        });
+    }
+}
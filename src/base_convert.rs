@@ -4,6 +4,13 @@ use crate::{
 };
 use core::fmt;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Values larger than this are converted by splitting them in halves.
+#[cfg(feature = "alloc")]
+const SPLIT_THRESHOLD_BITS: usize = 1024;
+
 /// Error for [`from_base_le`][Uint::from_base_le] and
 /// [`from_base_be`][Uint::from_base_be].
 #[allow(clippy::module_name_repetitions)]
@@ -89,14 +96,58 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Returns an iterator over the base `base` digits of the number in
+    /// big-endian order, without allocating.
+    ///
+    /// Digits are extracted in chunks by dividing by the largest power of
+    /// `base` that fits a limb, so there is no need to pick a large base for
+    /// performance. The number zero has no digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// let x = uint!(1234567_U64);
+    /// assert!(x.to_digits(1000).eq([1, 234, 567]));
+    /// assert_eq!(U64::from_digits(1000, x.to_digits(1000)), Ok(x));
+    /// ```
+    #[inline]
+    pub fn to_digits(&self, base: u64) -> impl Iterator<Item = u64> {
+        assert!(base > 1);
+        let (power, _) = chunk_power(base);
+        let mut limbs = self.limbs;
+        let mut chunks = [[0; 2]; LIMBS];
+        let mut len = 0;
+        while limbs.iter().any(|&limb| limb != 0) {
+            chunks[len / 2][len % 2] = div_rem_small(&mut limbs, power);
+            len += 1;
+        }
+        let mut scale = power / base;
+        if let Some(top) = len.checked_sub(1) {
+            while scale > chunks[top / 2][top % 2] {
+                scale /= base;
+            }
+        }
+        SpigotBig {
+            base,
+            scale,
+            full_scale: power / base,
+            chunks,
+            len,
+        }
+    }
+
     /// Constructs the [`Uint`] from digits in the base `base` in little-endian.
     ///
     /// # Errors
     ///
     /// * [`BaseConvertError::InvalidBase`] if the base is less than 2.
     /// * [`BaseConvertError::InvalidDigit`] if a digit is out of range.
-    /// * [`BaseConvertError::Overflow`] if the number is too large to
-    /// fit.
+    /// * [`BaseConvertError::Overflow`] if the number is too large to fit.
     #[inline]
     pub fn from_base_le<I>(base: u64, digits: I) -> Result<Self, BaseConvertError>
     where
@@ -155,41 +206,208 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     ///
     /// * [`BaseConvertError::InvalidBase`] if the base is less than 2.
     /// * [`BaseConvertError::InvalidDigit`] if a digit is out of range.
-    /// * [`BaseConvertError::Overflow`] if the number is too large to
-    /// fit.
+    /// * [`BaseConvertError::Overflow`] if the number is too large to fit.
     #[inline]
     pub fn from_base_be<I: IntoIterator<Item = u64>>(
         base: u64,
         digits: I,
     ) -> Result<Self, BaseConvertError> {
-        // OPT: Special handling of bases that divide 2^64, and bases that are
-        // powers of 2.
-        // OPT: Same trick as with `to_base_le`, find the largest power of base
-        // that fits `u64` and accumulate there first.
         if base < 2 {
             return Err(BaseConvertError::InvalidBase(base));
         }
 
+        // Accumulate digits in a limb and multiply in one chunk at a time.
+        let (power, chunk_digits) = chunk_power(base);
         let mut result = Self::ZERO;
+        let mut chunk = 0;
+        let mut count = 0;
         for digit in digits {
             if digit >= base {
                 return Err(BaseConvertError::InvalidDigit(digit, base));
             }
-            // Multiply by base.
-            // OPT: keep track of non-zero limbs and mul the minimum.
-            let mut carry: u128 = u128::from(digit);
-            #[allow(clippy::cast_possible_truncation)]
-            for limb in &mut result.limbs {
-                carry += u128::from(*limb) * u128::from(base);
-                *limb = carry as u64;
-                carry >>= 64;
+            chunk = chunk * base + digit;
+            count += 1;
+            if count == chunk_digits {
+                result.mul_add_small(power, chunk)?;
+                chunk = 0;
+                count = 0;
             }
-            if carry > 0 || (LIMBS != 0 && result.limbs[LIMBS - 1] > Self::MASK) {
-                return Err(BaseConvertError::Overflow);
+        }
+        if count > 0 {
+            #[allow(clippy::cast_possible_truncation)] // count < chunk_digits <= 64
+            result.mul_add_small(base.pow(count as u32), chunk)?;
+        }
+        Ok(result)
+    }
+
+    /// Constructs the [`Uint`] from digits in the base `base` in big-endian,
+    /// the inverse of [`to_digits`](Self::to_digits).
+    ///
+    /// This is the same as [`from_base_be`](Self::from_base_be).
+    ///
+    /// # Errors
+    ///
+    /// * [`BaseConvertError::InvalidBase`] if the base is less than 2.
+    /// * [`BaseConvertError::InvalidDigit`] if a digit is out of range.
+    /// * [`BaseConvertError::Overflow`] if the number is too large to fit.
+    #[inline]
+    pub fn from_digits<I: IntoIterator<Item = u64>>(
+        base: u64,
+        digits: I,
+    ) -> Result<Self, BaseConvertError> {
+        Self::from_base_be(base, digits)
+    }
+
+    /// Sets `self` to `self * factor + addend`.
+    #[inline]
+    pub(crate) fn mul_add_small(
+        &mut self,
+        factor: u64,
+        addend: u64,
+    ) -> Result<(), BaseConvertError> {
+        // OPT: keep track of non-zero limbs and mul the minimum.
+        let mut carry: u128 = u128::from(addend);
+        #[allow(clippy::cast_possible_truncation)]
+        for limb in &mut self.limbs {
+            carry += u128::from(*limb) * u128::from(factor);
+            *limb = carry as u64;
+            carry >>= 64;
+        }
+        if carry > 0 || (LIMBS != 0 && self.limbs[LIMBS - 1] > Self::MASK) {
+            return Err(BaseConvertError::Overflow);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns the big-endian base `base` digits of the number, like
+    /// [`to_base_be`](Self::to_base_be).
+    ///
+    /// Large values are split in halves by dividing by a power of `base`,
+    /// which is much faster than extracting the digits one chunk at a time.
+    pub(crate) fn to_base_be_split(self, base: u64) -> Vec<u64> {
+        assert!(base > 1);
+        let mut digits = Vec::new();
+        self.push_digits(base, &Self::split_powers(base), 0, &mut digits);
+        digits
+    }
+
+    /// Constructs the [`Uint`] from big-endian digits, like
+    /// [`from_base_be`](Self::from_base_be), splitting large inputs in halves.
+    pub(crate) fn from_base_be_split(base: u64, digits: &[u64]) -> Result<Self, BaseConvertError> {
+        if base < 2 {
+            return Err(BaseConvertError::InvalidBase(base));
+        }
+        Self::from_digit_slice(base, digits, &Self::split_powers(base))
+    }
+
+    /// Returns the powers `base^n` used to split values, with `n` a chunk of
+    /// digits times a power of two, ignoring those below the threshold.
+    fn split_powers(base: u64) -> Vec<(Self, usize)> {
+        let (power, digits) = chunk_power(base);
+        let mut powers = Vec::new();
+        let mut next = Self::try_from(power).ok().map(|power| (power, digits));
+        while let Some((power, digits)) = next {
+            if power.bit_len() > SPLIT_THRESHOLD_BITS / 2 {
+                powers.push((power, digits));
             }
+            next = power.checked_mul(power).map(|square| (square, digits * 2));
         }
+        powers
+    }
 
-        Ok(result)
+    /// Appends the digits of `self` to `digits`, with leading zeros up to
+    /// `width` digits.
+    fn push_digits(self, base: u64, powers: &[(Self, usize)], width: usize, digits: &mut Vec<u64>) {
+        // `self` is less than the square of the largest power below it, so
+        // both halves are less than that power.
+        let Some(index) = powers.iter().rposition(|(power, _)| *power <= self) else {
+            let start = digits.len();
+            digits.extend(self.to_digits(base));
+            let padding = width.saturating_sub(digits.len() - start);
+            digits.splice(start..start, core::iter::repeat(0).take(padding));
+            return;
+        };
+        let (power, n) = powers[index];
+        let (high, low) = self.div_rem(power);
+        high.push_digits(base, &powers[..index], width.saturating_sub(n), digits);
+        low.push_digits(base, &powers[..index], n, digits);
+    }
+
+    fn from_digit_slice(
+        base: u64,
+        digits: &[u64],
+        powers: &[(Self, usize)],
+    ) -> Result<Self, BaseConvertError> {
+        let Some(index) = powers.iter().rposition(|&(_, n)| n < digits.len()) else {
+            return Self::from_base_be(base, digits.iter().copied());
+        };
+        let (power, n) = powers[index];
+        let (high, low) = digits.split_at(digits.len() - n);
+        let high = Self::from_digit_slice(base, high, &powers[..=index])?;
+        let low = Self::from_digit_slice(base, low, &powers[..index])?;
+        high.checked_mul(power)
+            .and_then(|high| high.checked_add(low))
+            .ok_or(BaseConvertError::Overflow)
+    }
+}
+
+/// Returns the largest power of `base` that fits a limb and its exponent.
+#[inline]
+const fn chunk_power(base: u64) -> (u64, usize) {
+    let mut power = base;
+    let mut digits = 1;
+    while let Some(next) = power.checked_mul(base) {
+        power = next;
+        digits += 1;
+    }
+    (power, digits)
+}
+
+/// Divides `limbs` in place by `divisor` and returns the remainder.
+#[inline]
+#[allow(clippy::cast_possible_truncation)] // Doesn't truncate
+fn div_rem_small<const LIMBS: usize>(limbs: &mut [u64; LIMBS], divisor: u64) -> u64 {
+    let mut remainder = 0_u128;
+    for limb in limbs.iter_mut().rev() {
+        remainder = (remainder << 64) | u128::from(*limb);
+        *limb = (remainder / u128::from(divisor)) as u64;
+        remainder %= u128::from(divisor);
+    }
+    remainder as u64
+}
+
+/// Big-endian digits from chunks of digits, with the most significant chunk at
+/// `len - 1`. Chunks are at least 32 bits, so there are at most `2 * LIMBS` of
+/// them.
+struct SpigotBig<const LIMBS: usize> {
+    base:       u64,
+    /// Place value of the next digit in the current chunk.
+    scale:      u64,
+    /// Place value of the leading digit of a full chunk.
+    full_scale: u64,
+    chunks:     [[u64; 2]; LIMBS],
+    len:        usize,
+}
+
+impl<const LIMBS: usize> Iterator for SpigotBig<LIMBS> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let top = self.len.checked_sub(1)?;
+        let chunk = &mut self.chunks[top / 2][top % 2];
+        let digit = *chunk / self.scale;
+        *chunk %= self.scale;
+        if self.scale == 1 {
+            self.len = top;
+            self.scale = self.full_scale;
+        } else {
+            self.scale /= self.base;
+        }
+        Some(digit)
     }
 }
 
@@ -227,6 +445,8 @@ impl<const LIMBS: usize> Iterator for SpigotLittle<LIMBS> {
 #[allow(clippy::zero_prefixed_literal)]
 mod tests {
     use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
 
     // 90630363884335538722706632492458228784305343302099024356772372330524102404852
     const N: Uint<256, 4> = Uint::from_limbs([
@@ -314,6 +534,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_digits() {
+        assert_eq!(
+            N.to_digits(58).collect::<Vec<_>>(),
+            N.to_base_be(58).collect::<Vec<_>>()
+        );
+        assert_eq!(Uint::<64, 1>::ZERO.to_digits(10).count(), 0);
+        assert_eq!(Uint::<0, 0>::ZERO.to_digits(10).count(), 0);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, base in 2_u64..)| {
+                for base in [2, 10, 58, 1000, base] {
+                    let digits: Vec<_> = value.to_digits(base).collect();
+                    assert_eq!(digits, value.to_base_be(base).collect::<Vec<_>>());
+                    assert_eq!(U::from_digits(base, digits), Ok(value));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_split() {
+        const_for!(BITS in [0, 64, 1023, 1024, 1025, 2048, 4096] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, base in 2_u64..)| {
+                for base in [2, 10, 36, 58, base] {
+                    let digits = value.to_base_be_split(base);
+                    assert_eq!(digits, value.to_base_be(base).collect::<Vec<_>>());
+                    assert_eq!(U::from_base_be_split(base, &digits), Ok(value));
+                }
+            });
+        });
+        // Padding inside the split and leading zeros in the input.
+        let value = Uint::<4096, 64>::from(10).pow(Uint::from(1000)) + Uint::from(7);
+        let digits = value.to_base_be_split(10);
+        assert_eq!(digits.len(), 1001);
+        assert_eq!(digits.iter().filter(|&&digit| digit != 0).count(), 2);
+        let mut padded = vec![0; 2000];
+        padded.extend_from_slice(&digits);
+        assert_eq!(Uint::<4096, 64>::from_base_be_split(10, &padded), Ok(value));
+        assert_eq!(
+            Uint::<1024, 16>::from_base_be_split(10, &digits),
+            Err(BaseConvertError::Overflow)
+        );
+    }
+
     #[test]
     fn test_from_base_be_overflow() {
         assert_eq!(
@@ -0,0 +1,143 @@
+//! Parallel element-wise operations on slices using [`rayon`].
+//!
+//! The operations are the same as calling the scalar methods in a loop, but
+//! the work is split over the rayon thread pool. This pays off for slices of
+//! thousands of elements and more.
+
+use crate::Uint;
+use rayon::prelude::*;
+
+/// Computes `result[i] = lhs[i].add_mod(rhs[i], modulus)` in parallel.
+///
+/// See [`Uint::add_mod`].
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn batch_add_mod<const BITS: usize, const LIMBS: usize>(
+    lhs: &[Uint<BITS, LIMBS>],
+    rhs: &[Uint<BITS, LIMBS>],
+    modulus: Uint<BITS, LIMBS>,
+    result: &mut [Uint<BITS, LIMBS>],
+) {
+    zip_with(lhs, rhs, result, |a, b| a.add_mod(b, modulus));
+}
+
+/// Computes `result[i] = lhs[i].mul_mod(rhs[i], modulus)` in parallel.
+///
+/// See [`Uint::mul_mod`].
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn batch_mul_mod<const BITS: usize, const LIMBS: usize>(
+    lhs: &[Uint<BITS, LIMBS>],
+    rhs: &[Uint<BITS, LIMBS>],
+    modulus: Uint<BITS, LIMBS>,
+    result: &mut [Uint<BITS, LIMBS>],
+) {
+    zip_with(lhs, rhs, result, |a, b| a.mul_mod(b, modulus));
+}
+
+/// Computes `result[i] = bases[i].pow_mod(exps[i], modulus)` in parallel.
+///
+/// See [`Uint::pow_mod`].
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn batch_pow_mod<const BITS: usize, const LIMBS: usize>(
+    bases: &[Uint<BITS, LIMBS>],
+    exps: &[Uint<BITS, LIMBS>],
+    modulus: Uint<BITS, LIMBS>,
+    result: &mut [Uint<BITS, LIMBS>],
+) {
+    zip_with(bases, exps, result, |b, e| b.pow_mod(e, modulus));
+}
+
+/// Sums `values` in parallel, returning `None` on overflow.
+#[inline]
+#[must_use]
+pub fn checked_sum<const BITS: usize, const LIMBS: usize>(
+    values: &[Uint<BITS, LIMBS>],
+) -> Option<Uint<BITS, LIMBS>> {
+    values
+        .par_iter()
+        .try_fold(|| Uint::ZERO, |sum, &value| sum.checked_add(value))
+        .try_reduce(|| Uint::ZERO, Uint::checked_add)
+}
+
+fn zip_with<const BITS: usize, const LIMBS: usize>(
+    lhs: &[Uint<BITS, LIMBS>],
+    rhs: &[Uint<BITS, LIMBS>],
+    result: &mut [Uint<BITS, LIMBS>],
+    f: impl Fn(Uint<BITS, LIMBS>, Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> + Sync,
+) {
+    assert_eq!(lhs.len(), rhs.len());
+    assert_eq!(lhs.len(), result.len());
+    result
+        .par_iter_mut()
+        .zip(lhs.par_iter().zip(rhs))
+        .for_each(|(result, (&a, &b))| *result = f(a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U64, const_for, nlimbs};
+    use proptest::{proptest, test_runner::Config};
+
+    #[test]
+    fn test_batch_mod() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(values: Vec<(U, U)>, m: U)| {
+                let (lhs, rhs): (Vec<U>, Vec<U>) = values.into_iter().unzip();
+                let mut result = vec![U::ZERO; lhs.len()];
+                batch_add_mod(&lhs, &rhs, m, &mut result);
+                for i in 0..lhs.len() {
+                    assert_eq!(result[i], lhs[i].add_mod(rhs[i], m));
+                }
+                batch_mul_mod(&lhs, &rhs, m, &mut result);
+                for i in 0..lhs.len() {
+                    assert_eq!(result[i], lhs[i].mul_mod(rhs[i], m));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_batch_pow_mod() {
+        const_for!(BITS in [64, 256] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let config = Config { cases: 20, ..Config::default() };
+            proptest!(config, |(values: Vec<(U, U)>, m: U)| {
+                let (bases, exps): (Vec<U>, Vec<U>) = values.into_iter().unzip();
+                let mut result = vec![U::ZERO; bases.len()];
+                batch_pow_mod(&bases, &exps, m, &mut result);
+                for i in 0..bases.len() {
+                    assert_eq!(result[i], bases[i].pow_mod(exps[i], m));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(values: Vec<U>)| {
+                let expected = values.iter().try_fold(U::ZERO, |sum, &value| sum.checked_add(value));
+                assert_eq!(checked_sum(&values), expected);
+            });
+        });
+        assert_eq!(checked_sum(&[U64::from(1); 1000]), Some(U64::from(1000)));
+        assert_eq!(checked_sum(&[U64::MAX, U64::from(1)]), None);
+    }
+}
@@ -15,6 +15,7 @@ use alloc::{borrow::Cow, vec::Vec};
 /// relevant for bit arrays.
 #[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "alloc", derive(Debug))]
+#[repr(transparent)]
 pub struct Bits<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
 
 impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Bits<BITS, LIMBS> {
@@ -211,6 +212,7 @@ macro_rules! forward {
 impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
     forward! {
         fn reverse_bits(self) -> Self;
+        fn swap_bytes(self) -> Self;
     }
     #[cfg(feature = "alloc")]
     forward! {
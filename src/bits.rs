@@ -1,7 +1,7 @@
 use crate::Uint;
 use core::ops::{
-    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
-    ShrAssign,
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Shl,
+    ShlAssign, Shr, ShrAssign,
 };
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
@@ -32,6 +32,118 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Returns an iterator over the bits, starting with the least significant
+    /// bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// let x = uint!(0b1101_U4);
+    /// assert!(x.iter_bits_le().eq([true, false, true, true]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn iter_bits_le(self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
+        (0..BITS).map(move |i| self.bit(i))
+    }
+
+    /// Returns an iterator over the bits, starting with the most significant
+    /// bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// let x = uint!(0b1101_U4);
+    /// assert!(x.iter_bits_be().eq([true, true, false, true]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn iter_bits_be(self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
+        self.iter_bits_le().rev()
+    }
+
+    /// Creates a new integer from an iterator over bits, starting with the
+    /// least significant bit. The iterator can be any length, trailing
+    /// `false` bits are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is larger than fits the [`Uint`].
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub fn from_bits_iter_le<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        Self::try_from_bits_iter_le(bits).expect("Value too large for Uint")
+    }
+
+    /// Creates a new integer from an iterator over bits, starting with the
+    /// least significant bit. The iterator can be any length, trailing
+    /// `false` bits are ignored.
+    ///
+    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    #[must_use]
+    #[inline]
+    pub fn try_from_bits_iter_le<I: IntoIterator<Item = bool>>(bits: I) -> Option<Self> {
+        let mut result = Self::ZERO;
+        for (i, bit) in bits.into_iter().enumerate() {
+            if bit {
+                if i >= BITS {
+                    return None;
+                }
+                result.set_bit(i, true);
+            }
+        }
+        Some(result)
+    }
+
+    /// Creates a new integer from an iterator over bits, starting with the
+    /// most significant bit. The iterator can be any length, leading `false`
+    /// bits are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is larger than fits the [`Uint`].
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub fn from_bits_iter_be<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        Self::try_from_bits_iter_be(bits).expect("Value too large for Uint")
+    }
+
+    /// Creates a new integer from an iterator over bits, starting with the
+    /// most significant bit. The iterator can be any length, leading `false`
+    /// bits are ignored.
+    ///
+    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint};
+    /// let x = uint!(0b1101_U4);
+    /// assert_eq!(
+    ///     Uint::<4, 1>::try_from_bits_iter_be(x.iter_bits_be()),
+    ///     Some(x)
+    /// );
+    /// assert_eq!(Uint::<4, 1>::try_from_bits_iter_be([true; 5]), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn try_from_bits_iter_be<I: IntoIterator<Item = bool>>(bits: I) -> Option<Self> {
+        let mut result = Self::ZERO;
+        for bit in bits {
+            let (shifted, overflow) = result.overflowing_shl(1);
+            if overflow || (bit && BITS == 0) {
+                return None;
+            }
+            result = shifted;
+            result.set_bit(0, bit);
+        }
+        Some(result)
+    }
+
     /// Returns a specific byte. The byte at index `0` is the least significant
     /// byte (little endian).
     ///
@@ -96,6 +208,100 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self
     }
 
+    /// Reverses the byte order of the integer over its full width of
+    /// [`Self::BYTES`] bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is not a multiple of eight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// # uint!{
+    /// assert_eq!(0x123456_U24.swap_bytes(), 0x563412_U24);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn swap_bytes(mut self) -> Self {
+        assert!(BITS % 8 == 0, "BITS must be a multiple of 8");
+        self.limbs.reverse();
+        for limb in &mut self.limbs {
+            *limb = limb.swap_bytes();
+        }
+        if BITS % 64 != 0 {
+            self >>= 64 - BITS % 64;
+        }
+        self
+    }
+
+    /// Converts `x` from big endian to the target's endianness, i.e. reverses
+    /// the bytes on little endian targets. See
+    /// [`swap_bytes`](Self::swap_bytes).
+    ///
+    /// # Panics
+    ///
+    /// Panics on little endian targets if `BITS` is not a multiple of eight.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn from_be(x: Self) -> Self {
+        x.to_be()
+    }
+
+    /// Converts `x` from little endian to the target's endianness, i.e.
+    /// reverses the bytes on big endian targets. See
+    /// [`swap_bytes`](Self::swap_bytes).
+    ///
+    /// # Panics
+    ///
+    /// Panics on big endian targets if `BITS` is not a multiple of eight.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn from_le(x: Self) -> Self {
+        x.to_le()
+    }
+
+    /// Converts `self` to big endian from the target's endianness, i.e.
+    /// reverses the bytes on little endian targets. See
+    /// [`swap_bytes`](Self::swap_bytes).
+    ///
+    /// # Panics
+    ///
+    /// Panics on little endian targets if `BITS` is not a multiple of eight.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn to_be(self) -> Self {
+        if cfg!(target_endian = "big") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    /// Converts `self` to little endian from the target's endianness, i.e.
+    /// reverses the bytes on big endian targets. See
+    /// [`swap_bytes`](Self::swap_bytes).
+    ///
+    /// # Panics
+    ///
+    /// Panics on big endian targets if `BITS` is not a multiple of eight.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn to_le(self) -> Self {
+        if cfg!(target_endian = "little") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
     /// Returns the number of leading zeros in the binary representation of
     /// `self`.
     #[inline]
@@ -437,6 +643,135 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         let rhs = rhs % BITS;
         self.rotate_left(BITS - rhs)
     }
+
+    /// Returns the bits in `range` as a number, i.e. $\left\lfloor
+    /// \frac{\mathtt{self}}{2^{\mathtt{start}}} \right\rfloor \bmod
+    /// 2^{\mathtt{end} - \mathtt{start}}$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or ends past `BITS`. See
+    /// [`checked_bits`](Self::checked_bits) and
+    /// [`wrapping_bits`](Self::wrapping_bits) for non-panicking versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// let x = 0x1234_5678_U64;
+    /// assert_eq!(x.bits(8..16), 0x56_U64);
+    /// assert_eq!(x.bits(..12), 0x678_U64);
+    /// assert_eq!(x.bits(28..), 0x1_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn bits<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        self.checked_bits(range).expect("bit range out of bounds")
+    }
+
+    /// Returns the bits in `range` as a number, or [`None`] if the range is
+    /// decreasing or ends past `BITS`.
+    #[inline]
+    #[must_use]
+    pub fn checked_bits<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let (start, end) = Self::bit_range(&range);
+        if start > end || end > BITS {
+            return None;
+        }
+        Some(self.wrapping_bits(start..end))
+    }
+
+    /// Returns the bits in `range` as a number, where bits past `BITS` read as
+    /// zero and a decreasing range is empty.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_bits<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let (start, end) = Self::bit_range(&range);
+        let end = end.min(BITS);
+        if start >= end {
+            return Self::ZERO;
+        }
+        (*self >> start) & Self::low_mask(end - start)
+    }
+
+    /// Overwrites the bits in `range` with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or ends past `BITS`, or if `value`
+    /// does not fit in the range. See
+    /// [`checked_set_bits`](Self::checked_set_bits) and
+    /// [`wrapping_set_bits`](Self::wrapping_set_bits) for non-panicking
+    /// versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// let mut x = 0x1234_5678_U64;
+    /// x.set_bits(8..16, 0xab_U64);
+    /// assert_eq!(x, 0x1234_ab78_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn set_bits<R: RangeBounds<usize>>(&mut self, range: R, value: Self) {
+        self.checked_set_bits(range, value)
+            .expect("bit range out of bounds or value too large");
+    }
+
+    /// Overwrites the bits in `range` with `value`, or returns [`None`] and
+    /// leaves `self` unchanged if the range is decreasing or ends past
+    /// `BITS`, or if `value` does not fit in the range.
+    #[inline]
+    pub fn checked_set_bits<R: RangeBounds<usize>>(&mut self, range: R, value: Self) -> Option<()> {
+        let (start, end) = Self::bit_range(&range);
+        if start > end || end > BITS || value.bit_len() > end - start {
+            return None;
+        }
+        self.wrapping_set_bits(start..end, value);
+        Some(())
+    }
+
+    /// Overwrites the bits in `range` with the low bits of `value`. Bits of
+    /// the range past `BITS` are dropped and a decreasing range is empty.
+    #[inline]
+    pub fn wrapping_set_bits<R: RangeBounds<usize>>(&mut self, range: R, value: Self) {
+        let (start, end) = Self::bit_range(&range);
+        let end = end.min(BITS);
+        if start >= end {
+            return;
+        }
+        let mask = Self::low_mask(end - start);
+        *self = (*self & !(mask << start)) | ((value & mask) << start);
+    }
+
+    /// Resolves `range` to a half-open `(start, end)` pair, with an unbounded
+    /// end at `BITS`.
+    #[inline]
+    fn bit_range<R: RangeBounds<usize>>(range: &R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => BITS,
+        };
+        (start, end)
+    }
+
+    /// The number with the low `width` bits set, for `0 < width <= BITS`.
+    #[inline]
+    fn low_mask(width: usize) -> Self {
+        Self::MAX >> (BITS - width)
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Not for Uint<BITS, LIMBS> {
@@ -480,6 +815,10 @@ macro_rules! impl_bit_op {
         {
             #[inline]
             fn $fn_assign(&mut self, rhs: &Uint<BITS, LIMBS>) {
+                #[cfg(feature = "portable-simd")]
+                return crate::algorithms::simd::$fn_assign(&mut self.limbs, &rhs.limbs);
+
+                #[cfg(not(feature = "portable-simd"))]
                 for i in 0..LIMBS {
                     u64::$fn_assign(&mut self.limbs[i], rhs.limbs[i]);
                 }
@@ -539,82 +878,95 @@ impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
 impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
 impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
 
-impl<const BITS: usize, const LIMBS: usize> Shl<Self> for Uint<BITS, LIMBS> {
+/// Converts a [`Uint`] shift amount to `usize`, saturating amounts that do not
+/// fit. Shifting by `BITS` or more yields zero, so saturation gives the EVM
+/// `SHL`/`SHR` semantics.
+#[inline(always)]
+fn shift_amount<const BITS: usize, const LIMBS: usize>(rhs: &Uint<BITS, LIMBS>) -> usize {
+    if rhs.bit_len() > 64 {
+        return usize::MAX;
+    }
+    rhs.as_limbs()
+        .first()
+        .map_or(0, |&limb| usize::try_from(limb).unwrap_or(usize::MAX))
+}
+
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    Shl<Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     type Output = Self;
 
     #[inline(always)]
-    fn shl(self, rhs: Self) -> Self::Output {
-        // This check shortcuts, and prevents panics on the `[0]` later
-        if BITS == 0 {
-            return self;
-        }
-        // Rationale: if BITS is larger than 2**64 - 1, it means we're running
-        // on a 128-bit platform with 2.3 exabytes of memory. In this case,
-        // the code produces incorrect output.
-        #[allow(clippy::cast_possible_truncation)]
-        self.wrapping_shl(rhs.as_limbs()[0] as usize)
+    fn shl(self, rhs: Uint<BITS_RHS, LIMBS_RHS>) -> Self::Output {
+        self << &rhs
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> Shl<&Self> for Uint<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    Shl<&Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     type Output = Self;
 
     #[inline(always)]
-    fn shl(self, rhs: &Self) -> Self::Output {
-        self << *rhs
+    fn shl(self, rhs: &Uint<BITS_RHS, LIMBS_RHS>) -> Self::Output {
+        self.wrapping_shl(shift_amount(rhs))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> Shr<Self> for Uint<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    Shr<Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     type Output = Self;
 
     #[inline(always)]
-    fn shr(self, rhs: Self) -> Self::Output {
-        // This check shortcuts, and prevents panics on the `[0]` later
-        if BITS == 0 {
-            return self;
-        }
-        // Rationale: if BITS is larger than 2**64 - 1, it means we're running
-        // on a 128-bit platform with 2.3 exabytes of memory. In this case,
-        // the code produces incorrect output.
-        #[allow(clippy::cast_possible_truncation)]
-        self.wrapping_shr(rhs.as_limbs()[0] as usize)
+    fn shr(self, rhs: Uint<BITS_RHS, LIMBS_RHS>) -> Self::Output {
+        self >> &rhs
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> Shr<&Self> for Uint<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    Shr<&Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     type Output = Self;
 
     #[inline(always)]
-    fn shr(self, rhs: &Self) -> Self::Output {
-        self >> *rhs
+    fn shr(self, rhs: &Uint<BITS_RHS, LIMBS_RHS>) -> Self::Output {
+        self.wrapping_shr(shift_amount(rhs))
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ShlAssign<Self> for Uint<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    ShlAssign<Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     #[inline(always)]
-    fn shl_assign(&mut self, rhs: Self) {
+    fn shl_assign(&mut self, rhs: Uint<BITS_RHS, LIMBS_RHS>) {
         *self = *self << rhs;
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ShlAssign<&Self> for Uint<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    ShlAssign<&Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     #[inline(always)]
-    fn shl_assign(&mut self, rhs: &Self) {
+    fn shl_assign(&mut self, rhs: &Uint<BITS_RHS, LIMBS_RHS>) {
         *self = *self << rhs;
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ShrAssign<Self> for Uint<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    ShrAssign<Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     #[inline(always)]
-    fn shr_assign(&mut self, rhs: Self) {
+    fn shr_assign(&mut self, rhs: Uint<BITS_RHS, LIMBS_RHS>) {
         *self = *self >> rhs;
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> ShrAssign<&Self> for Uint<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize, const BITS_RHS: usize, const LIMBS_RHS: usize>
+    ShrAssign<&Uint<BITS_RHS, LIMBS_RHS>> for Uint<BITS, LIMBS>
+{
     #[inline(always)]
-    fn shr_assign(&mut self, rhs: &Self) {
+    fn shr_assign(&mut self, rhs: &Uint<BITS_RHS, LIMBS_RHS>) {
         *self = *self >> rhs;
     }
 }
@@ -774,7 +1126,11 @@ mod tests {
     }
 
     #[test]
-    #[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+    #[allow(
+        clippy::cast_lossless,
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap
+    )]
     fn test_small() {
         const_for!(BITS in [1, 2, 8, 16, 32, 63, 64] {
             type U = Uint::<BITS, 1>;
@@ -821,6 +1177,49 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_swap_bytes() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            if BITS % 8 == 0 {
+                proptest!(|(value: U)| {
+                    let swapped = value.swap_bytes();
+                    assert!(swapped.iter_bytes_le().eq(value.iter_bytes_be()));
+                    assert_eq!(swapped.swap_bytes(), value);
+                    assert_eq!(U::from_be(value.to_be()), value);
+                    assert_eq!(U::from_le(value.to_le()), value);
+                    #[cfg(target_endian = "little")]
+                    assert_eq!(value.to_le(), value);
+                    #[cfg(target_endian = "big")]
+                    assert_eq!(value.to_be(), value);
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn test_shift_by_uint() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, shift in 0..=BITS + 2, high: U128)| {
+                let amount = U128::from(shift);
+                assert_eq!(value << amount, value << shift);
+                assert_eq!(value >> amount, value >> shift);
+                assert_eq!(value << Uint::<8, 1>::saturating_from(shift), value.wrapping_shl(shift.min(255)));
+                let mut assigned = value;
+                assigned >>= amount;
+                assert_eq!(assigned, value >> shift);
+
+                // Amounts beyond `usize` saturate instead of wrapping around.
+                let huge = (high | U128::from(1)) << 64_usize | amount;
+                assert_eq!(value << huge, U::ZERO);
+                assert_eq!(value >> huge, U::ZERO);
+            });
+        });
+    }
+
     #[test]
     fn test_rotate() {
         const_for!(BITS in SIZES {
@@ -833,6 +1232,48 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_iter_bits() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(value.iter_bits_le().len(), BITS);
+                assert_eq!(value.iter_bits_le().filter(|&bit| bit).count(), value.count_ones());
+                assert!(value.iter_bits_be().eq(value.reverse_bits().iter_bits_le()));
+                assert_eq!(U::from_bits_iter_le(value.iter_bits_le().chain([false; 3])), value);
+                assert_eq!(U::from_bits_iter_be([false; 3].into_iter().chain(value.iter_bits_be())), value);
+                assert_eq!(U::try_from_bits_iter_le((0..=BITS).map(|i| i == BITS)), None);
+                assert_eq!(U::try_from_bits_iter_be(core::iter::once(true).chain(value.iter_bits_be())), None);
+            });
+        });
+    }
+
+    #[test]
+    fn test_bits() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, field: U, a in 0..=BITS, b in 0..=BITS)| {
+                let (start, end) = (min(a, b), a.max(b));
+                let bits = value.bits(start..end);
+                assert_eq!(bits, (value << (BITS - end)) >> (BITS - end + start));
+                assert_eq!(value.wrapping_bits(start..=BITS + 1), value.bits(start..));
+                assert_eq!(value.checked_bits(start..=BITS), None);
+
+                let mut set = value;
+                set.wrapping_set_bits(start..end, field);
+                assert_eq!(set.bits(start..end), field.wrapping_bits(..end - start));
+                assert_eq!(set.wrapping_bits(..start), value.wrapping_bits(..start));
+                assert_eq!(set.bits(end..), value.bits(end..));
+                let mut checked = value;
+                let fits = field.bit_len() <= end - start;
+                assert_eq!(checked.checked_set_bits(start..end, field).is_some(), fits);
+                assert_eq!(checked, if fits { set } else { value });
+            });
+        });
+    }
+
     #[test]
     fn test_arithmetic_shr() {
         const_for!(BITS in SIZES {
@@ -866,10 +1307,10 @@ mod tests {
         );
 
         // Test: Two limbs right shift from 0x0010_0000_0000_0000 and 0 by 1 bit.
-        // Expects resulting limbs: [0x0080_0000_0000_000, 0] with no fractional part.
+        // Expects resulting limbs: [0x0008_0000_0000_0000, 0] with no fractional part.
         assert_eq!(
             Uint::<65, 2>::from_limbs([0x0010_0000_0000_0000, 0]).overflowing_shr(1),
-            (Uint::<65, 2>::from_limbs([0x0080_0000_0000_000, 0]), false)
+            (Uint::<65, 2>::from_limbs([0x0008_0000_0000_0000, 0]), false)
         );
 
         // Test: Shift beyond single limb capacity with MAX value.
@@ -193,6 +193,38 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         bytes
     }
 
+    /// Returns an iterator over the [`Self::BYTES`] bytes, starting with the
+    /// least significant byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// let x = uint!(0x123456_U24);
+    /// assert!(x.iter_bytes_le().eq([0x56, 0x34, 0x12]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn iter_bytes_le(self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator {
+        (0..Self::BYTES).map(move |i| self.byte(i))
+    }
+
+    /// Returns an iterator over the [`Self::BYTES`] bytes, starting with the
+    /// most significant byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// let x = uint!(0x123456_U24);
+    /// assert!(x.iter_bytes_be().eq([0x12, 0x34, 0x56]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn iter_bytes_be(self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator {
+        self.iter_bytes_le().rev()
+    }
+
     /// Converts a big-endian byte array of size exactly
     /// [`Self::BYTES`] to [`Uint`].
     ///
@@ -254,6 +286,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 limbs[i] = u64::from_be_bytes(unsafe { *end.sub((i + 1) * 8).cast() });
                 i += 1;
             }
+            if Self::LIMBS > 0 && limbs[Self::LIMBS - 1] > Self::MASK {
+                return None;
+            }
             return Some(Self::from_limbs(limbs));
         }
 
@@ -331,6 +366,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 limbs[i] = u64::from_le_bytes(unsafe { *bytes.as_ptr().add(i * 8).cast() });
                 i += 1;
             }
+            if Self::LIMBS > 0 && limbs[Self::LIMBS - 1] > Self::MASK {
+                return None;
+            }
             return Some(Self::from_limbs(limbs));
         }
 
@@ -345,6 +383,98 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
         Some(Self::from_limbs(limbs))
     }
+
+    /// Creates a new integer from an iterator over little endian bytes.
+    /// The iterator can be any length, trailing zeros are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is larger than fits the [`Uint`].
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub fn from_bytes_iter_le<I: IntoIterator<Item = u8>>(bytes: I) -> Self {
+        Self::try_from_bytes_iter_le(bytes).expect("Value too large for Uint")
+    }
+
+    /// Creates a new integer from an iterator over little endian bytes.
+    /// The iterator can be any length, trailing zeros are ignored.
+    ///
+    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    #[must_use]
+    #[inline]
+    pub fn try_from_bytes_iter_le<I: IntoIterator<Item = u8>>(bytes: I) -> Option<Self> {
+        let mut limbs = [0; LIMBS];
+        for (i, byte) in bytes.into_iter().enumerate() {
+            if byte == 0 {
+                continue;
+            }
+            if i >= Self::BYTES {
+                return None;
+            }
+            limbs[i / 8] |= u64::from(byte) << ((i % 8) * 8);
+        }
+        if Self::LIMBS > 0 && limbs[Self::LIMBS - 1] > Self::MASK {
+            return None;
+        }
+        Some(Self::from_limbs(limbs))
+    }
+
+    /// Creates a new integer from an iterator over big endian bytes.
+    /// The iterator can be any length, leading zeros are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is larger than fits the [`Uint`].
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub fn from_bytes_iter_be<I: IntoIterator<Item = u8>>(bytes: I) -> Self {
+        Self::try_from_bytes_iter_be(bytes).expect("Value too large for Uint")
+    }
+
+    /// Creates a new integer from an iterator over big endian bytes.
+    /// The iterator can be any length, leading zeros are ignored.
+    ///
+    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint};
+    /// let x = uint!(0x123456_U24);
+    /// assert_eq!(
+    ///     Uint::<24, 1>::try_from_bytes_iter_be(x.iter_bytes_be()),
+    ///     Some(x)
+    /// );
+    /// assert_eq!(
+    ///     Uint::<24, 1>::try_from_bytes_iter_be([0, 0, 1, 2, 3]),
+    ///     Some(uint!(0x010203_U24))
+    /// );
+    /// assert_eq!(Uint::<24, 1>::try_from_bytes_iter_be([1, 2, 3, 4]), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn try_from_bytes_iter_be<I: IntoIterator<Item = u8>>(bytes: I) -> Option<Self> {
+        let mut result = Self::ZERO;
+        for byte in bytes {
+            let (shifted, overflow) = result.overflowing_shl(8);
+            if overflow {
+                return None;
+            }
+            result = shifted;
+            if byte != 0 {
+                if LIMBS == 0 {
+                    return None;
+                }
+                result.limbs[0] |= u64::from(byte);
+                if LIMBS == 1 && result.limbs[0] > Self::MASK {
+                    return None;
+                }
+            }
+        }
+        Some(result)
+    }
 }
 
 /// Number of bytes required to represent the given number of bits.
@@ -382,12 +512,12 @@ mod tests {
     #[test]
     const fn const_from_to_bytes() {
         const NL: [u64; 2] = N.limbs;
+        const KL: [u64; 2] = K.limbs;
         assert!(matches!(Uint::<128, 2>::from_be_bytes(BE).limbs, NL));
         assert!(matches!(Uint::<128, 2>::from_le_bytes(LE).limbs, NL));
         assert!(matches!(N.to_be_bytes::<{ BE.len() }>(), BE));
         assert!(matches!(N.to_le_bytes::<{ LE.len() }>(), LE));
 
-        const KL: [u64; 2] = K.limbs;
         assert!(matches!(Uint::<72, 2>::from_be_bytes(KBE).limbs, KL));
         assert!(matches!(Uint::<72, 2>::from_le_bytes(KLE).limbs, KL));
         assert!(matches!(K.to_be_bytes::<{ KBE.len() }>(), KBE));
@@ -461,6 +591,14 @@ mod tests {
         assert_eq!(Uint::from_le_bytes(KLE), K);
     }
 
+    #[test]
+    fn test_try_from_slice_too_large() {
+        assert_eq!(Uint::<63, 1>::try_from_be_slice(&[0xff; 8]), None);
+        assert_eq!(Uint::<63, 1>::try_from_le_slice(&[0xff; 8]), None);
+        assert_eq!(Uint::<255, 4>::try_from_be_slice(&[0xff; 32]), None);
+        assert_eq!(Uint::<255, 4>::try_from_le_slice(&[0xff; 32]), None);
+    }
+
     #[test]
     fn test_to_bytes() {
         assert_eq!(Uint::<0, 0>::ZERO.to_le_bytes(), [0_u8; 0]);
@@ -485,6 +623,10 @@ mod tests {
                 assert_eq!(value, Uint::try_from_le_slice(&value.to_le_bytes_trimmed_vec()).unwrap());
                 assert_eq!(value, Uint::from_be_bytes(value.to_be_bytes::<BYTES>()));
                 assert_eq!(value, Uint::from_le_bytes(value.to_le_bytes::<BYTES>()));
+                assert!(value.iter_bytes_le().eq(value.as_le_bytes().iter().copied()));
+                assert!(value.iter_bytes_be().eq(value.to_be_bytes::<BYTES>()));
+                assert_eq!(value, Uint::from_bytes_iter_le(value.iter_bytes_le().chain([0; 9])));
+                assert_eq!(value, Uint::from_bytes_iter_be([0; 9].into_iter().chain(value.iter_bytes_be())));
             });
         });
     }
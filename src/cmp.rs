@@ -22,11 +22,55 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     pub fn is_zero(&self) -> bool {
         *self == Self::ZERO
     }
+
+    /// Returns the smaller of `self` and `other`.
+    ///
+    /// This is the same as [`Ord::min`], but does not require the trait in
+    /// scope.
+    #[inline]
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of `self` and `other`.
+    ///
+    /// This is the same as [`Ord::max`], but does not require the trait in
+    /// scope.
+    #[inline]
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to the interval `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, like [`Ord::clamp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(5_U64.clamp(10_U64, 20_U64), 10_U64);
+    /// assert_eq!(15_U64.clamp(10_U64, 20_U64), 15_U64);
+    /// assert_eq!(25_U64.clamp(10_U64, 20_U64), 20_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Uint;
+    use crate::{const_for, nlimbs, Uint};
+    use proptest::proptest;
 
     #[test]
     fn test_is_zero() {
@@ -39,4 +83,20 @@ mod tests {
         assert!(!Uint::<7, 1>::from_limbs([1]).is_zero());
         assert!(!Uint::<64, 1>::from_limbs([1]).is_zero());
     }
+
+    #[test]
+    fn test_min_max_clamp() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, c: U)| {
+                assert_eq!(a.min(b), if a <= b { a } else { b });
+                assert_eq!(a.max(b), if a <= b { b } else { a });
+                let (low, high) = (b.min(c), b.max(c));
+                let clamped = a.clamp(low, high);
+                assert!(low <= clamped && clamped <= high);
+                assert!(clamped == a || (a < low && clamped == low) || (a > high && clamped == high));
+            });
+        });
+    }
 }
@@ -0,0 +1,199 @@
+#![cfg(feature = "alloc")] // see comments on `mul_mod`
+
+use crate::Uint;
+use alloc::vec::Vec;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Reconstruct a value from its residues using the Chinese Remainder
+    /// Theorem.
+    ///
+    /// Given pairs $(r_i, m_i)$ of residues and pairwise coprime moduli, it
+    /// returns the unique $x < \prod_i m_i$ such that $x ≡ r_i \pmod{m_i}$
+    /// for all $i$.
+    ///
+    /// Returns `None` if a modulus is zero, the moduli are not pairwise
+    /// coprime, or if their product does not fit in the [`Uint`].
+    ///
+    /// See [`CrtContext`] to reuse the precomputation for the same moduli.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U64::crt(&[(2_U64, 3_U64), (3_U64, 5_U64), (2_U64, 7_U64)]),
+    ///     Some(23_U64)
+    /// );
+    /// assert_eq!(U64::crt(&[(1_U64, 4_U64), (1_U64, 6_U64)]), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn crt(residues_and_moduli: &[(Self, Self)]) -> Option<Self> {
+        let moduli: Vec<Self> = residues_and_moduli.iter().map(|&(_, m)| m).collect();
+        let residues: Vec<Self> = residues_and_moduli.iter().map(|&(r, _)| r).collect();
+        Some(CrtContext::new(&moduli)?.reconstruct(&residues))
+    }
+}
+
+/// Precomputed context for the Chinese Remainder Theorem with fixed
+/// pairwise coprime moduli.
+///
+/// Uses Garner's algorithm: the value is built incrementally as
+/// $x_{i+1} = x_i + M_i ⋅ \mod{(r_i - x_i) ⋅ M_i^{-1}}_{m_i}$ where $M_i$ is
+/// the product of the preceding moduli. The products $M_i$ and the inverses
+/// $M_i^{-1} \bmod m_i$ only depend on the moduli and are computed once.
+///
+/// ```
+/// # use ruint::{uint, CrtContext, aliases::*};
+/// # uint!{
+/// let ctx = CrtContext::new(&[3_U64, 5_U64, 7_U64]).unwrap();
+/// assert_eq!(ctx.modulus(), 105_U64);
+/// assert_eq!(ctx.reconstruct(&[2_U64, 3_U64, 2_U64]), 23_U64);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CrtContext<const BITS: usize, const LIMBS: usize> {
+    moduli:   Vec<Uint<BITS, LIMBS>>,
+    /// Product of the moduli preceding each modulus.
+    products: Vec<Uint<BITS, LIMBS>>,
+    /// Inverse of `products[i]` modulo `moduli[i]`.
+    inverses: Vec<Uint<BITS, LIMBS>>,
+    modulus:  Uint<BITS, LIMBS>,
+}
+
+impl<const BITS: usize, const LIMBS: usize> CrtContext<BITS, LIMBS> {
+    /// Precompute the constants for `moduli`.
+    ///
+    /// Returns `None` if a modulus is zero, the moduli are not pairwise
+    /// coprime, or if their product does not fit in the [`Uint`].
+    #[inline]
+    #[must_use]
+    pub fn new(moduli: &[Uint<BITS, LIMBS>]) -> Option<Self> {
+        if BITS == 0 {
+            // Only the empty product fits.
+            return moduli.is_empty().then(|| Self {
+                moduli:   Vec::new(),
+                products: Vec::new(),
+                inverses: Vec::new(),
+                modulus:  Uint::ZERO,
+            });
+        }
+        let mut products = Vec::with_capacity(moduli.len());
+        let mut inverses = Vec::with_capacity(moduli.len());
+        let mut modulus = Uint::from(1);
+        for &m in moduli {
+            // A modulus of one imposes no constraint and has no inverse.
+            let inverse = if m == Uint::from(1) {
+                Uint::ZERO
+            } else {
+                modulus.reduce_mod(m).inv_mod(m)?
+            };
+            products.push(modulus);
+            inverses.push(inverse);
+            modulus = modulus.checked_mul(m)?;
+        }
+        Some(Self {
+            moduli: moduli.to_vec(),
+            products,
+            inverses,
+            modulus,
+        })
+    }
+
+    /// The product of all moduli.
+    ///
+    /// For `BITS == 0` this is zero, as one does not fit.
+    #[inline]
+    #[must_use]
+    pub const fn modulus(&self) -> Uint<BITS, LIMBS> {
+        self.modulus
+    }
+
+    /// The moduli of this context.
+    #[inline]
+    #[must_use]
+    pub fn moduli(&self) -> &[Uint<BITS, LIMBS>] {
+        &self.moduli
+    }
+
+    /// Reconstruct the unique value less than [`modulus`](Self::modulus)
+    /// that is congruent to `residues[i]` modulo `moduli[i]` for all `i`.
+    ///
+    /// The residues do not need to be reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of residues differs from the number of moduli.
+    #[inline]
+    #[must_use]
+    pub fn reconstruct(&self, residues: &[Uint<BITS, LIMBS>]) -> Uint<BITS, LIMBS> {
+        assert_eq!(
+            residues.len(),
+            self.moduli.len(),
+            "number of residues must match the number of moduli"
+        );
+        let mut result = Uint::ZERO;
+        for (i, &r) in residues.iter().enumerate() {
+            let m = self.moduli[i];
+            // t = (r - result) ⋅ M_i^{-1} mod m
+            let t = r.add_mod(m - result.reduce_mod(m), m);
+            let t = t.mul_mod(self.inverses[i], m);
+            // Does not overflow as the result stays below the partial product.
+            result += self.products[i] * t;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_crt() {
+        const_for!(BITS in NON_ZERO if (BITS >= 16) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, moduli: Vec<u16>)| {
+                // Build pairwise coprime moduli whose product fits.
+                let mut selected = Vec::new();
+                let mut product = U::from(1);
+                for m in moduli.into_iter().map(U::from).filter(|&m| m > U::ZERO) {
+                    if product.gcd(m) == U::from(1) {
+                        if let Some(p) = product.checked_mul(m) {
+                            product = p;
+                            selected.push(m);
+                        }
+                    }
+                }
+                let value = value % product;
+                let pairs: Vec<_> = selected.iter().map(|&m| (value % m, m)).collect();
+                assert_eq!(U::crt(&pairs), Some(value));
+
+                let ctx = CrtContext::new(&selected).unwrap();
+                assert_eq!(ctx.modulus(), product);
+                let residues: Vec<_> = pairs.iter().map(|&(r, _)| r).collect();
+                assert_eq!(ctx.reconstruct(&residues), value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_crt_invalid() {
+        type U = Uint<64, 1>;
+        assert_eq!(U::crt(&[]), Some(U::ZERO));
+        assert_eq!(U::crt(&[(U::from(1), U::ZERO)]), None);
+        assert_eq!(
+            U::crt(&[(U::from(1), U::from(6)), (U::from(1), U::from(9))]),
+            None
+        );
+        assert_eq!(
+            U::crt(&[(U::from(1), U::MAX), (U::from(1), U::from(2))]),
+            None
+        );
+    }
+}
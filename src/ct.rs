@@ -0,0 +1,211 @@
+#![cfg(feature = "subtle")]
+
+use crate::{algorithms, Uint};
+use subtle::{Choice, ConditionallySelectable};
+
+// See the `subtle` support module for `ConstantTimeEq`, `ct_lt`/`ct_gt` and
+// `ConditionallySelectable`.
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Compute $\mod{\mathtt{self} + \mathtt{rhs}}_{\mathtt{modulus}}$ in
+    /// constant time.
+    ///
+    /// Unlike [`add_mod`](Self::add_mod) the inputs are not reduced, both
+    /// `self` and `rhs` must be less than `modulus`. Branches and memory
+    /// accesses do not depend on the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(5_U64.ct_add_mod(4_U64, 7_U64), 2_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ct_add_mod(self, rhs: Self, modulus: Self) -> Self {
+        let (sum, carry) = self.overflowing_add(rhs);
+        let (reduced, borrow) = sum.overflowing_sub(modulus);
+        // Use the reduced value if the sum overflowed or is at least the modulus.
+        let choice = Choice::from(u8::from(carry)) | !Choice::from(u8::from(borrow));
+        Self::conditional_select(&sum, &reduced, choice)
+    }
+
+    /// Compute $\mod{\mathtt{self} - \mathtt{rhs}}_{\mathtt{modulus}}$ in
+    /// constant time.
+    ///
+    /// Both `self` and `rhs` must be less than `modulus`. Branches and memory
+    /// accesses do not depend on the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(2_U64.ct_sub_mod(4_U64, 7_U64), 5_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ct_sub_mod(self, rhs: Self, modulus: Self) -> Self {
+        let (difference, borrow) = self.overflowing_sub(rhs);
+        let (corrected, _) = difference.overflowing_add(modulus);
+        Self::conditional_select(&difference, &corrected, Choice::from(u8::from(borrow)))
+    }
+
+    /// Compute $\mod{\mathtt{self}^{\mathtt{exp}}}_{\mathtt{modulus}}$ in
+    /// constant time.
+    ///
+    /// Uses a Montgomery ladder over all `BITS` bits of `exp` with
+    /// constant-time Montgomery multiplications, so branches and memory
+    /// accesses do not depend on `self` or `exp`. Only the modulus, which is
+    /// assumed to be public, affects the timing of the precomputation.
+    ///
+    /// `self` does not need to be reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(3_U64.pow_mod_ct(5_U64, 7_U64), 5_U64);
+    /// assert_eq!(10_U64.pow_mod_ct(0_U64, 7_U64), 1_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn pow_mod_ct(self, exp: Self, modulus: Self) -> Self {
+        if BITS == 0 {
+            return Self::ZERO;
+        }
+        assert!(modulus.bit(0), "modulus must be odd");
+        let inv = Uint::<64, 1>::from(modulus.limbs[0].wrapping_neg())
+            .inv_ring()
+            .unwrap()
+            .as_limbs()[0];
+
+        // Compute $R$ and $R^2$ modulo `modulus` by repeated doubling.
+        let mut one = Self::from(1).reduce_mod(modulus);
+        for _ in 0..64 * LIMBS {
+            one = one.add_mod(one, modulus);
+        }
+        let mut r2 = one;
+        for _ in 0..64 * LIMBS {
+            r2 = r2.add_mod(r2, modulus);
+        }
+
+        // Any `self` below $R$ is reduced by the multiplication with $R^2$.
+        let base = self.mul_redc_ct(r2, modulus, inv);
+
+        // Montgomery ladder, maintaining `high = low ⋅ base`.
+        let mut low = one;
+        let mut high = base;
+        for i in (0..BITS).rev() {
+            let bit = Choice::from(u8::from(exp.bit(i)));
+            Self::conditional_swap(&mut low, &mut high, bit);
+            high = low.mul_redc_ct(high, modulus, inv);
+            low = low.mul_redc_ct(low, modulus, inv);
+            Self::conditional_swap(&mut low, &mut high, bit);
+        }
+        low.mul_redc_ct(Self::from(1), modulus, inv)
+    }
+
+    /// Constant-time Montgomery multiplication, see
+    /// [`mul_redc`](Self::mul_redc).
+    ///
+    /// Requires $\mathtt{self} ⋅ \mathtt{other} < \mathtt{modulus} ⋅ R$, the
+    /// result is fully reduced.
+    ///
+    /// Uses the coarsely integrated operand scanning (CIOS) method, which
+    /// needs no allocation.
+    #[allow(clippy::cast_possible_truncation)] // Intentional
+    #[allow(clippy::many_single_char_names)] // Follows the literature.
+    fn mul_redc_ct(self, other: Self, modulus: Self, inv: u64) -> Self {
+        let a = self.as_limbs();
+        let b = other.as_limbs();
+        let m = modulus.as_limbs();
+
+        // Accumulator of `LIMBS + 2` limbs.
+        let mut t = [0; LIMBS];
+        let mut t_high = 0;
+        for &b in b {
+            let mut carry = 0;
+            for (t, &a) in t.iter_mut().zip(a) {
+                let product = u128::from(a) * u128::from(b) + u128::from(*t) + u128::from(carry);
+                *t = product as u64;
+                carry = (product >> 64) as u64;
+            }
+            let (sum, t_top) = algorithms::adc(t_high, carry, 0);
+
+            // Add a multiple of the modulus to clear the lowest limb and shift.
+            let u = t[0].wrapping_mul(inv);
+            let product = u128::from(u) * u128::from(m[0]) + u128::from(t[0]);
+            let mut carry = (product >> 64) as u64;
+            for j in 1..LIMBS {
+                let product =
+                    u128::from(u) * u128::from(m[j]) + u128::from(t[j]) + u128::from(carry);
+                t[j - 1] = product as u64;
+                carry = (product >> 64) as u64;
+            }
+            let (last, carry) = algorithms::adc(sum, carry, 0);
+            t[LIMBS - 1] = last;
+            t_high = t_top + carry;
+        }
+
+        // Conditionally subtract the modulus once. The accumulator can exceed
+        // `BITS` bits, so this is done on the limbs.
+        let mut reduced = t;
+        let borrow = algorithms::sbb_n(&mut reduced, m, 0);
+        let use_reduced = Choice::from(u8::from(t_high != 0)) | Choice::from(u8::from(borrow == 0));
+        for (t, reduced) in t.iter_mut().zip(reduced) {
+            t.conditional_assign(&reduced, use_reduced);
+        }
+        Self::from_limbs(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use core::cmp::min;
+    use proptest::{prop_assume, proptest, test_runner::Config};
+
+    #[test]
+    fn test_add_sub_mod() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, m: U)| {
+                prop_assume!(m != U::ZERO);
+                let (a, b) = (a.reduce_mod(m), b.reduce_mod(m));
+                assert_eq!(a.ct_add_mod(b, m), a.add_mod(b, m));
+                assert_eq!(a.ct_sub_mod(b, m).add_mod(b, m), a);
+                assert!(a.ct_sub_mod(b, m) < m);
+            });
+        });
+    }
+
+    #[test]
+    fn test_pow_mod_ct() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut config = Config::default();
+            config.cases = min(config.cases, if BITS > 500 { 3 } else { 20 });
+            proptest!(config, |(a: U, b: U, e: U, m: U)| {
+                let m = m | U::from(1);
+                let inv = Uint::<64, 1>::from(m.as_limbs()[0].wrapping_neg()).inv_ring().unwrap().as_limbs()[0];
+                // The base does not need to be reduced.
+                assert_eq!(a.pow_mod_ct(e, m), a.pow_mod(e, m));
+                let (a, b) = (a.reduce_mod(m), b.reduce_mod(m));
+                assert_eq!(a.mul_redc_ct(b, m, inv), a.mul_redc(b, m, inv));
+            });
+        });
+    }
+}
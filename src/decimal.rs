@@ -0,0 +1,626 @@
+use crate::{ParseErrorAt, ParseErrorKind, Rounding, Uint};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+/// Suffixes accepted by [`Uint::from_str_scientific`], with the powers of ten
+/// and two they multiply by.
+const SUFFIXES: [(&str, i64, usize); 12] = [
+    ("k", 3, 0),
+    ("M", 6, 0),
+    ("G", 9, 0),
+    ("T", 12, 0),
+    ("P", 15, 0),
+    ("E", 18, 0),
+    ("Ki", 0, 10),
+    ("Mi", 0, 20),
+    ("Gi", 0, 30),
+    ("Ti", 0, 40),
+    ("Pi", 0, 50),
+    ("Ei", 0, 60),
+];
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Parse a decimal number with an optional fraction, exponent and suffix,
+    /// as long as the value is an integer.
+    ///
+    /// The number is decimal digits with an optional fractional part after
+    /// a `.`, and `_` separators between two digits. It can be followed by an
+    /// exponent `e` or `E` with an optional sign, and then by an SI suffix
+    /// (`k`, `M`, `G`, `T`, `P`, `E`) or a binary suffix (`Ki`, `Mi`, `Gi`,
+    /// `Ti`, `Pi`, `Ei`), optionally after spaces.
+    ///
+    /// ```
+    /// # use ruint::{aliases::U256, ParseErrorKind};
+    /// let parse = |s| U256::from_str_scientific(s);
+    /// assert_eq!(parse("30e6"), Ok(U256::from(30_000_000)));
+    /// assert_eq!(parse("2.5e6"), Ok(U256::from(2_500_000)));
+    /// assert_eq!(parse("1e18"), Ok(U256::from(10).pow(U256::from(18))));
+    /// assert_eq!(parse("10k"), Ok(U256::from(10_000)));
+    /// assert_eq!(parse("3 Gi"), Ok(U256::from(3_u64 << 30)));
+    /// assert_eq!(parse("1.5").unwrap_err().kind, ParseErrorKind::NotAnInteger);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseErrorAt`] with the byte offset of the first problem.
+    /// [`ParseErrorKind::NotAnInteger`] and [`ParseErrorKind::Overflow`]
+    /// are reported at offset zero.
+    #[inline]
+    pub fn from_str_scientific(src: &str) -> Result<Self, ParseErrorAt> {
+        let error = |offset, kind| Err(ParseErrorAt { offset, kind });
+        let invalid = |offset: usize| {
+            let c = src[offset..].chars().next().unwrap_or_default();
+            error(offset, ParseErrorKind::InvalidDigit(c))
+        };
+        let bytes = src.as_bytes();
+        let is_digit = |index: usize| bytes.get(index).map_or(false, u8::is_ascii_digit);
+
+        // The value is `mantissa * 10^(zeros + exponent)`, where `zeros` are
+        // trailing zero digits not yet multiplied in.
+        let mut mantissa = Self::ZERO;
+        let mut overflow = false;
+        let mut zeros: i64 = 0;
+        let mut exponent: i64 = 0;
+        let mut digits = 0;
+        let mut fraction = false;
+        let mut index = 0;
+        while index < bytes.len() {
+            match bytes[index] {
+                digit @ b'0'..=b'9' => {
+                    digits += 1;
+                    if fraction {
+                        exponent = exponent.saturating_sub(1);
+                    }
+                    if digit == b'0' {
+                        zeros = zeros.saturating_add(1);
+                    } else {
+                        for _ in 0..zeros {
+                            overflow |= mantissa.mul_add_small(10, 0).is_err();
+                        }
+                        zeros = 0;
+                        overflow |= mantissa.mul_add_small(10, u64::from(digit - b'0')).is_err();
+                    }
+                }
+                b'_' if index > 0 && is_digit(index - 1) && is_digit(index + 1) => {}
+                b'_' => return error(index, ParseErrorKind::InvalidSeparator),
+                b'.' if !fraction => fraction = true,
+                _ => break,
+            }
+            index += 1;
+        }
+        if digits == 0 {
+            if index < bytes.len() {
+                return invalid(index);
+            }
+            return error(index, ParseErrorKind::Empty);
+        }
+
+        if matches!(bytes.get(index), Some(b'e' | b'E')) {
+            let negative = bytes.get(index + 1) == Some(&b'-');
+            let start = index + 1 + usize::from(matches!(bytes.get(index + 1), Some(b'+' | b'-')));
+            if is_digit(start) {
+                let mut power: i64 = 0;
+                index = start;
+                while is_digit(index) {
+                    let digit = i64::from(bytes[index] - b'0');
+                    power = power.saturating_mul(10).saturating_add(digit);
+                    index += 1;
+                }
+                exponent = if negative {
+                    exponent.saturating_sub(power)
+                } else {
+                    exponent.saturating_add(power)
+                };
+            }
+        }
+
+        let rest = &src[index..];
+        let suffix = rest.trim_start_matches(' ');
+        let offset = src.len() - suffix.len();
+        let mut shift = 0;
+        if suffix.is_empty() {
+            if !rest.is_empty() {
+                return invalid(index);
+            }
+        } else if let Some(&(_, tens, twos)) = SUFFIXES.iter().find(|(name, ..)| *name == suffix) {
+            exponent = exponent.saturating_add(tens);
+            shift = twos;
+        } else if suffix.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return error(offset, ParseErrorKind::UnknownSuffix);
+        } else {
+            return invalid(offset);
+        }
+
+        if overflow {
+            return error(0, ParseErrorKind::Overflow);
+        }
+        mantissa
+            .scale_exact(exponent.saturating_add(zeros), shift)
+            .map_err(|kind| ParseErrorAt { offset: 0, kind })
+    }
+
+    /// Computes `self * 10^tens * 2^twos`, failing if the result does not fit
+    /// or is not an integer.
+    fn scale_exact(mut self, tens: i64, mut twos: usize) -> Result<Self, ParseErrorKind> {
+        if self.is_zero() {
+            return Ok(self);
+        }
+        if tens >= 0 {
+            // Stops at the first overflow, so a large exponent is fine.
+            for _ in 0..tens {
+                self.mul_add_small(10, 0)
+                    .map_err(|_| ParseErrorKind::Overflow)?;
+            }
+        } else {
+            // Divide by `10^n` as `5^n` here and `2^n` in the shift below.
+            for _ in 0..tens.unsigned_abs() {
+                let (quotient, remainder) = self.div_rem_limb(5);
+                if remainder != 0 {
+                    return Err(ParseErrorKind::NotAnInteger);
+                }
+                self = quotient;
+            }
+            let fives = usize::try_from(tens.unsigned_abs()).unwrap_or(usize::MAX);
+            if fives > twos {
+                if self.trailing_zeros() < fives - twos {
+                    return Err(ParseErrorKind::NotAnInteger);
+                }
+                return Ok(self >> (fives - twos));
+            }
+            twos -= fives;
+        }
+        if self.leading_zeros() < twos {
+            return Err(ParseErrorKind::Overflow);
+        }
+        Ok(self << twos)
+    }
+
+    /// Parse a decimal number with `decimals` implied decimal places, like
+    /// ethers' `parse_units`.
+    ///
+    /// The input is decimal digits with an optional fractional part after a
+    /// `.`, and `_` separators between two digits. The result is the number
+    /// times `10^decimals`, which must be an integer. Use
+    /// [`from_decimal_str_scaled_rounding`](Self::from_decimal_str_scaled_rounding)
+    /// to round away extra fractional digits instead.
+    ///
+    /// ```
+    /// # use ruint::{aliases::U256, ParseErrorKind};
+    /// let wei = U256::from_decimal_str_scaled("1.5", 18).unwrap();
+    /// assert_eq!(wei, U256::from(1_500_000_000_000_000_000_u64));
+    /// assert_eq!(
+    ///     U256::from_decimal_str_scaled("42", 6),
+    ///     Ok(U256::from(42_000_000))
+    /// );
+    ///
+    /// let error = U256::from_decimal_str_scaled("0.1234567", 6).unwrap_err();
+    /// assert_eq!(
+    ///     (error.offset, error.kind),
+    ///     (8, ParseErrorKind::NotAnInteger)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseErrorAt`] with the byte offset of the first problem.
+    /// [`ParseErrorKind::NotAnInteger`] is reported at the first non-zero
+    /// digit past `decimals` fractional digits, and
+    /// [`ParseErrorKind::Overflow`] at offset zero.
+    #[inline]
+    pub fn from_decimal_str_scaled(src: &str, decimals: usize) -> Result<Self, ParseErrorAt> {
+        Self::parse_scaled(src, decimals, None)
+    }
+
+    /// Parse a decimal number with `decimals` implied decimal places,
+    /// rounding away any further fractional digits.
+    ///
+    /// See [`from_decimal_str_scaled`](Self::from_decimal_str_scaled) for the
+    /// accepted syntax.
+    ///
+    /// ```
+    /// # use ruint::{aliases::U64, Rounding};
+    /// let parse = |rounding| U64::from_decimal_str_scaled_rounding("0.1235", 3, rounding);
+    /// assert_eq!(parse(Rounding::Down), Ok(U64::from(123)));
+    /// assert_eq!(parse(Rounding::Up), Ok(U64::from(124)));
+    /// assert_eq!(parse(Rounding::Nearest), Ok(U64::from(124)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseErrorAt`] with the byte offset of the first problem.
+    /// [`ParseErrorKind::Overflow`] is reported at offset zero, also when
+    /// rounding up overflows.
+    #[inline]
+    pub fn from_decimal_str_scaled_rounding(
+        src: &str,
+        decimals: usize,
+        rounding: Rounding,
+    ) -> Result<Self, ParseErrorAt> {
+        Self::parse_scaled(src, decimals, Some(rounding))
+    }
+
+    /// Parses `src` times `10^decimals`, failing on dropped non-zero digits
+    /// unless a `rounding` is given.
+    fn parse_scaled(
+        src: &str,
+        decimals: usize,
+        rounding: Option<Rounding>,
+    ) -> Result<Self, ParseErrorAt> {
+        let error = |offset, kind| Err(ParseErrorAt { offset, kind });
+        let bytes = src.as_bytes();
+        let is_digit = |index: usize| bytes.get(index).map_or(false, u8::is_ascii_digit);
+
+        let mut value = Self::ZERO;
+        let mut overflow = false;
+        let mut digits = 0;
+        // Fractional digits taken so far, `None` before the `.`.
+        let mut fraction: Option<usize> = None;
+        // The first dropped digit, and the offset of the first non-zero one.
+        let mut dropped = None;
+        let mut inexact = None;
+        for (index, &byte) in bytes.iter().enumerate() {
+            match byte {
+                b'0'..=b'9' => {
+                    digits += 1;
+                    let digit = byte - b'0';
+                    if fraction == Some(decimals) {
+                        dropped.get_or_insert(digit);
+                        if digit != 0 {
+                            inexact.get_or_insert(index);
+                        }
+                    } else {
+                        overflow |= value.mul_add_small(10, u64::from(digit)).is_err();
+                        if let Some(taken) = &mut fraction {
+                            *taken += 1;
+                        }
+                    }
+                }
+                b'_' if index > 0 && is_digit(index - 1) && is_digit(index + 1) => {}
+                b'_' => return error(index, ParseErrorKind::InvalidSeparator),
+                b'.' if fraction.is_none() => fraction = Some(0),
+                _ => {
+                    let c = src[index..].chars().next().unwrap_or_default();
+                    return error(index, ParseErrorKind::InvalidDigit(c));
+                }
+            }
+        }
+        if digits == 0 {
+            return error(src.len(), ParseErrorKind::Empty);
+        }
+
+        let round_up = match (rounding, inexact) {
+            (_, None) | (Some(Rounding::Down), _) => false,
+            (None, Some(offset)) => return error(offset, ParseErrorKind::NotAnInteger),
+            (Some(Rounding::Up), _) => true,
+            (Some(Rounding::Nearest), _) => dropped >= Some(5),
+        };
+        if round_up {
+            overflow |= value.mul_add_small(1, 1).is_err();
+        } else if !value.is_zero() {
+            // Stops at the first overflow, so many decimals are fine.
+            for _ in fraction.unwrap_or(0)..decimals {
+                if value.mul_add_small(10, 0).is_err() {
+                    overflow = true;
+                    break;
+                }
+            }
+        }
+        if overflow {
+            return error(0, ParseErrorKind::Overflow);
+        }
+        Ok(value)
+    }
+
+    /// Formats the number with `decimals` implied decimal places, like
+    /// ethers' `format_units`, the inverse of
+    /// [`from_decimal_str_scaled`](Self::from_decimal_str_scaled).
+    ///
+    /// Trailing zeros of the fractional part are left out, and so is the `.`
+    /// if nothing remains after it.
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let wei = U256::from(1_500_000_000_000_000_000_u64);
+    /// assert_eq!(wei.to_decimal_str_scaled(18), "1.5");
+    /// assert_eq!(U256::from(42).to_decimal_str_scaled(6), "0.000042");
+    /// assert_eq!(U256::from(42).to_decimal_str_scaled(0), "42");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn to_decimal_str_scaled(&self, decimals: usize) -> String {
+        insert_point(self.to_string(), decimals)
+    }
+
+    /// Formats the number with `decimals` implied decimal places, rounded to
+    /// at most `precision` fractional digits.
+    ///
+    /// The rounding is done on the decimal digits, so rounding up
+    /// [`Uint::MAX`] gives a number that does not fit `Self`.
+    ///
+    /// ```
+    /// # use ruint::{aliases::U256, Rounding};
+    /// let wei = U256::from(1_234_567_000_000_000_000_u64);
+    /// assert_eq!(
+    ///     wei.to_decimal_str_scaled_rounding(18, 2, Rounding::Down),
+    ///     "1.23"
+    /// );
+    /// assert_eq!(
+    ///     wei.to_decimal_str_scaled_rounding(18, 4, Rounding::Nearest),
+    ///     "1.2346"
+    /// );
+    /// assert_eq!(wei.to_decimal_str_scaled_rounding(18, 0, Rounding::Up), "2");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn to_decimal_str_scaled_rounding(
+        &self,
+        decimals: usize,
+        precision: usize,
+        rounding: Rounding,
+    ) -> String {
+        let mut digits = self.to_string();
+        let drop = decimals.saturating_sub(precision);
+        if drop == 0 {
+            return insert_point(digits, decimals);
+        }
+        if digits.len() <= drop {
+            digits.insert_str(0, &"0".repeat(drop + 1 - digits.len()));
+        }
+        let dropped = digits.split_off(digits.len() - drop);
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => dropped.bytes().any(|digit| digit != b'0'),
+            Rounding::Nearest => dropped.as_bytes()[0] >= b'5',
+        };
+        if round_up {
+            increment(&mut digits);
+        }
+        insert_point(digits, precision)
+    }
+}
+
+/// Inserts a `.` before the last `decimals` of `digits`, padding with leading
+/// zeros and dropping trailing ones.
+#[cfg(feature = "alloc")]
+fn insert_point(mut digits: String, decimals: usize) -> String {
+    if digits.len() <= decimals {
+        digits.insert_str(0, &"0".repeat(decimals + 1 - digits.len()));
+    }
+    let point = digits.len() - decimals;
+    let fraction = digits[point..].trim_end_matches('0').len();
+    digits.truncate(point + fraction);
+    if fraction > 0 {
+        digits.insert(point, '.');
+    }
+    digits
+}
+
+/// Adds one to a string of decimal digits.
+#[cfg(feature = "alloc")]
+fn increment(digits: &mut String) {
+    // Everything after the last digit that is not a `9` carries.
+    let start = digits
+        .rfind(|c| c != '9')
+        .map_or(0, |position| position + 1);
+    let nines = digits.len() - start;
+    digits.truncate(start);
+    match digits.pop() {
+        Some(digit) => digits.extend(char::from_u32(u32::from(digit) + 1)),
+        None => digits.push('1'),
+    }
+    digits.push_str(&"0".repeat(nines));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+
+    fn parse(src: &str) -> Result<U256, ParseErrorAt> {
+        U256::from_str_scientific(src)
+    }
+
+    fn error(src: &str) -> (usize, ParseErrorKind) {
+        let error = parse(src).unwrap_err();
+        (error.offset, error.kind)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, shift in 0_usize..20)| {
+                let decimal = value.to_string();
+                assert_eq!(U::from_str_scientific(&decimal), Ok(value));
+
+                // Move the point left and compensate with the exponent.
+                let shift = shift.min(decimal.len());
+                let (integer, fraction) = decimal.split_at(decimal.len() - shift);
+                let scientific = format!("{integer}.{fraction}e{shift}");
+                assert_eq!(U::from_str_scientific(&scientific), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_scientific() {
+        assert_eq!(parse("30e6"), Ok(U256::from(30_000_000)));
+        assert_eq!(parse("30E+6"), Ok(U256::from(30_000_000)));
+        assert_eq!(parse("2.5e6"), Ok(U256::from(2_500_000)));
+        assert_eq!(parse("1_000.000_1e4"), Ok(U256::from(10_000_001)));
+        assert_eq!(parse("1500e-2"), Ok(U256::from(15)));
+        assert_eq!(parse("1000e-3"), Ok(U256::from(1)));
+        assert_eq!(parse(".5e1"), Ok(U256::from(5)));
+        assert_eq!(parse("5."), Ok(U256::from(5)));
+        assert_eq!(parse("0.0e-999999999999999999999"), Ok(U256::ZERO));
+        assert_eq!(parse("0e999999999999999999999"), Ok(U256::ZERO));
+        assert_eq!(parse("1e77"), Ok(U256::from(10).pow(U256::from(77))));
+        assert_eq!(parse(&format!("{}e0", U256::MAX)), Ok(U256::MAX));
+        assert_eq!(parse(&format!("{}0e-1", U256::MAX)), Ok(U256::MAX));
+    }
+
+    #[test]
+    fn test_suffixes() {
+        assert_eq!(parse("10k"), Ok(U256::from(10_000)));
+        assert_eq!(parse("3 Gi"), Ok(U256::from(3_u64 << 30)));
+        assert_eq!(parse("2.5k"), Ok(U256::from(2_500)));
+        assert_eq!(parse("1.5Ki"), Ok(U256::from(1_536)));
+        assert_eq!(parse("0.25Ki"), Ok(U256::from(256)));
+        assert_eq!(parse("1e-3k"), Ok(U256::from(1)));
+        assert_eq!(parse("1E"), Ok(U256::from(10).pow(U256::from(18))));
+        assert_eq!(parse("1e3E"), Ok(U256::from(10).pow(U256::from(21))));
+        assert_eq!(parse("1Ei"), Ok(U256::from(1_u64 << 60)));
+        assert_eq!(error("0.3Ki"), (0, ParseErrorKind::NotAnInteger));
+
+        let kind = |src| Uint::<8, 1>::from_str_scientific(src).unwrap_err().kind;
+        assert_eq!(kind("1k"), ParseErrorKind::Overflow);
+        assert_eq!(kind("0.25Ki"), ParseErrorKind::Overflow);
+        assert_eq!(
+            Uint::<11, 1>::from_str_scientific("1Ki"),
+            Ok(Uint::from(1024))
+        );
+        assert_eq!(
+            Uint::<10, 1>::from_str_scientific("1Ki").unwrap_err().kind,
+            ParseErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(error(""), (0, ParseErrorKind::Empty));
+        assert_eq!(error("."), (1, ParseErrorKind::Empty));
+        assert_eq!(error("k"), (0, ParseErrorKind::InvalidDigit('k')));
+        assert_eq!(error("1.5"), (0, ParseErrorKind::NotAnInteger));
+        assert_eq!(error("1e-1"), (0, ParseErrorKind::NotAnInteger));
+        assert_eq!(error("1.2.3"), (3, ParseErrorKind::InvalidDigit('.')));
+        assert_eq!(error("1_"), (1, ParseErrorKind::InvalidSeparator));
+        assert_eq!(error("1_.5"), (1, ParseErrorKind::InvalidSeparator));
+        assert_eq!(error("1e"), (1, ParseErrorKind::UnknownSuffix));
+        assert_eq!(error("1e+"), (1, ParseErrorKind::UnknownSuffix));
+        assert_eq!(error("5m"), (1, ParseErrorKind::UnknownSuffix));
+        assert_eq!(error("5 "), (1, ParseErrorKind::InvalidDigit(' ')));
+        assert_eq!(error("5 k "), (2, ParseErrorKind::UnknownSuffix));
+        assert_eq!(error("5 -"), (2, ParseErrorKind::InvalidDigit('-')));
+        assert_eq!(error("-5"), (0, ParseErrorKind::InvalidDigit('-')));
+        assert_eq!(error("1e78"), (0, ParseErrorKind::Overflow));
+        assert_eq!(
+            error(&format!("{}1", U256::MAX)),
+            (0, ParseErrorKind::Overflow)
+        );
+        assert_eq!(
+            error("1e999999999999999999999"),
+            (0, ParseErrorKind::Overflow)
+        );
+        assert_eq!(
+            error("1e-999999999999999999999"),
+            (0, ParseErrorKind::NotAnInteger)
+        );
+    }
+
+    #[test]
+    fn test_scaled_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, decimals in 0_usize..100, precision in 0_usize..100)| {
+                let formatted = value.to_decimal_str_scaled(decimals);
+                assert_eq!(U::from_decimal_str_scaled(&formatted, decimals), Ok(value));
+
+                // Rounding while formatting or while parsing agrees.
+                for rounding in [Rounding::Down, Rounding::Up, Rounding::Nearest] {
+                    let rounded = value.to_decimal_str_scaled_rounding(decimals, precision, rounding);
+                    assert_eq!(
+                        U::from_decimal_str_scaled(&rounded, precision),
+                        U::from_decimal_str_scaled_rounding(&formatted, precision, rounding)
+                    );
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_scaled_parse() {
+        let parse = |src: &str, decimals| U256::from_decimal_str_scaled(src, decimals);
+        let wei = |n: u64| U256::from(n) * U256::from(10).pow(U256::from(9));
+        assert_eq!(parse("1.5", 18), Ok(wei(1_500_000_000)));
+        assert_eq!(
+            parse("1_000.000_000_001", 9),
+            Ok(U256::from(1_000_000_000_001_u64))
+        );
+        assert_eq!(parse(".5", 1), Ok(U256::from(5)));
+        assert_eq!(parse("5.", 1), Ok(U256::from(50)));
+        assert_eq!(parse("0.10", 1), Ok(U256::from(1)));
+        assert_eq!(parse("0", usize::MAX), Ok(U256::ZERO));
+        assert_eq!(parse(&U256::MAX.to_string(), 0), Ok(U256::MAX));
+
+        let error = |src, decimals| {
+            let error = parse(src, decimals).unwrap_err();
+            (error.offset, error.kind)
+        };
+        assert_eq!(error("", 2), (0, ParseErrorKind::Empty));
+        assert_eq!(error(".", 2), (1, ParseErrorKind::Empty));
+        assert_eq!(error("1.005", 2), (4, ParseErrorKind::NotAnInteger));
+        assert_eq!(error("1.2.3", 2), (3, ParseErrorKind::InvalidDigit('.')));
+        assert_eq!(error("1e3", 2), (1, ParseErrorKind::InvalidDigit('e')));
+        assert_eq!(error("-1", 2), (0, ParseErrorKind::InvalidDigit('-')));
+        assert_eq!(error("1._5", 2), (2, ParseErrorKind::InvalidSeparator));
+        assert_eq!(error("1", 78), (0, ParseErrorKind::Overflow));
+        assert_eq!(error("1", usize::MAX), (0, ParseErrorKind::Overflow));
+
+        let round = |src, rounding| U256::from_decimal_str_scaled_rounding(src, 2, rounding);
+        assert_eq!(round("1.005", Rounding::Down), Ok(U256::from(100)));
+        assert_eq!(round("1.005", Rounding::Up), Ok(U256::from(101)));
+        assert_eq!(round("1.005", Rounding::Nearest), Ok(U256::from(101)));
+        assert_eq!(round("1.00499", Rounding::Nearest), Ok(U256::from(100)));
+        assert_eq!(round("1.0000001", Rounding::Up), Ok(U256::from(101)));
+        assert_eq!(round("0.009", Rounding::Nearest), Ok(U256::from(1)));
+        assert_eq!(
+            Uint::<8, 1>::from_decimal_str_scaled_rounding("2.541", 2, Rounding::Up),
+            Ok(Uint::from(255))
+        );
+        assert_eq!(
+            Uint::<8, 1>::from_decimal_str_scaled_rounding("2.551", 2, Rounding::Up)
+                .unwrap_err()
+                .kind,
+            ParseErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn test_scaled_format() {
+        let value = U256::from(1_234_567_u64);
+        assert_eq!(value.to_decimal_str_scaled(0), "1234567");
+        assert_eq!(value.to_decimal_str_scaled(3), "1234.567");
+        assert_eq!(value.to_decimal_str_scaled(7), "0.1234567");
+        assert_eq!(value.to_decimal_str_scaled(9), "0.001234567");
+        assert_eq!(U256::from(1_200).to_decimal_str_scaled(3), "1.2");
+        assert_eq!(U256::from(1_000).to_decimal_str_scaled(3), "1");
+        assert_eq!(U256::ZERO.to_decimal_str_scaled(18), "0");
+
+        let round =
+            |value: u64, rounding| U256::from(value).to_decimal_str_scaled_rounding(3, 1, rounding);
+        assert_eq!(round(1_250, Rounding::Down), "1.2");
+        assert_eq!(round(1_250, Rounding::Up), "1.3");
+        assert_eq!(round(1_250, Rounding::Nearest), "1.3");
+        assert_eq!(round(1_249, Rounding::Nearest), "1.2");
+        assert_eq!(round(1_201, Rounding::Up), "1.3");
+        assert_eq!(round(1_200, Rounding::Up), "1.2");
+        assert_eq!(round(1_000, Rounding::Up), "1");
+        assert_eq!(round(9_960, Rounding::Nearest), "10");
+        assert_eq!(round(1, Rounding::Up), "0.1");
+        assert_eq!(round(1, Rounding::Nearest), "0");
+        assert_eq!(round(1_234, Rounding::Down), "1.2");
+        assert_eq!(
+            U256::from(1_234).to_decimal_str_scaled_rounding(3, 5, Rounding::Up),
+            "1.234"
+        );
+        assert_eq!(
+            U256::MAX.to_decimal_str_scaled_rounding(1, 0, Rounding::Up),
+            (U256::MAX / U256::from(10) + U256::from(1)).to_string()
+        );
+    }
+}
@@ -24,6 +24,30 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Some(self.rem(rhs))
     }
 
+    /// Computes `self / rhs` and `self % rhs` in a single division, returning
+    /// [`None`] if `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(
+    ///     1234_U256.checked_div_rem(100_U256),
+    ///     Some((12_U256, 34_U256))
+    /// );
+    /// assert_eq!(1234_U256.checked_div_rem(0_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs == Self::ZERO {
+            return None;
+        }
+        Some(self.div_rem(rhs))
+    }
+
     /// Computes `self / rhs` rounding up.
     ///
     /// # Panics
@@ -42,8 +66,41 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Computes the Euclidean quotient of `self` by `rhs`.
+    ///
+    /// For unsigned integers this is the same as `self / rhs`. It exists for
+    /// parity with [`u64::div_euclid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        self.wrapping_div(rhs)
+    }
+
+    /// Computes the least non-negative remainder of `self` by `rhs`.
+    ///
+    /// For unsigned integers this is the same as `self % rhs`. It exists for
+    /// parity with [`u64::rem_euclid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        self.wrapping_rem(rhs)
+    }
+
     /// Computes `self / rhs` and `self % rhs`.
     ///
+    /// Both are obtained from a single division, so this is as fast as either
+    /// `/` or `%` alone.
+    ///
     /// # Panics
     ///
     /// Panics if `rhs == 0`.
@@ -56,6 +113,129 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         (self, rhs)
     }
 
+    /// Computes `self / rhs` and `self % rhs` for a single limb `rhs`.
+    ///
+    /// This avoids converting `rhs` to a [`Uint`] and the dispatch in the
+    /// general division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(1234_U256.div_rem_limb(100), (12_U256, 34));
+    /// assert_eq!(
+    ///     U256::MAX.div_rem_limb(u64::MAX),
+    ///     (U256::MAX / Uint::from(u64::MAX), 0)
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn div_rem_limb(mut self, rhs: u64) -> (Self, u64) {
+        assert!(rhs != 0, "Division by zero");
+        // Normalize the divisor and shift the numerator on the fly. The
+        // shifted numerator has one more limb, but as this limb is less than
+        // the normalized divisor it does not contribute to the quotient.
+        let shift = rhs.leading_zeros();
+        let d = rhs << shift;
+        let v = algorithms::div::reciprocal(d);
+        let mut r = if shift == 0 || LIMBS == 0 {
+            0
+        } else {
+            self.limbs[LIMBS - 1] >> (64 - shift)
+        };
+        for i in (0..LIMBS).rev() {
+            let lower = if shift == 0 || i == 0 {
+                0
+            } else {
+                self.limbs[i - 1] >> (64 - shift)
+            };
+            let limb = (self.limbs[i] << shift) | lower;
+            (self.limbs[i], r) =
+                algorithms::div::div_2x1(u128::from(r) << 64 | u128::from(limb), d, v);
+        }
+        (self, r >> shift)
+    }
+
+    /// Computes `self / rhs` where `rhs` is known to divide `self`.
+    ///
+    /// This uses Jebelean's exact division algorithm, which avoids the
+    /// quotient estimation and normalization of general division. If `rhs`
+    /// does not divide `self` the result is unspecified (but a valid value);
+    /// use [`Uint::div_rem`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::U256};
+    /// # uint!{
+    /// let n = 0x1234567890abcdef_U256 * 1000_U256;
+    /// assert_eq!(n.div_exact(1000_U256), 0x1234567890abcdef_U256);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`. In debug builds also panics if `rhs` does not
+    /// divide `self`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn div_exact(self, rhs: Self) -> Self {
+        assert!(rhs != Self::ZERO, "Division by zero");
+        debug_assert!(self.is_multiple_of(rhs), "Division is not exact");
+
+        // Remove the common factor of two so the divisor becomes odd.
+        let shift = rhs.trailing_zeros();
+        let mut result = self >> shift;
+        let divisor = rhs >> shift;
+        let len = divisor.limbs.iter().rposition(|&limb| limb != 0).unwrap() + 1;
+        algorithms::div::div_exact(&mut result.limbs, &divisor.limbs[..len]);
+        if BITS > 0 {
+            result.limbs[LIMBS - 1] &= Self::MASK;
+        }
+        result
+    }
+
+    /// Computes `self / rhs` rounding down.
+    ///
+    /// Returns a tuple of the quotient along with a boolean indicating whether
+    /// an arithmetic overflow would occur. Unsigned division never overflows,
+    /// so the boolean is always `false`. This exists for parity with the
+    /// other `overflowing_*` methods and [`u64::overflowing_div`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_div(rhs), false)
+    }
+
+    /// Computes `self % rhs`.
+    ///
+    /// Returns a tuple of the remainder along with a boolean indicating
+    /// whether an arithmetic overflow would occur, which is always `false`.
+    /// See [`overflowing_div`](Self::overflowing_div).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_rem(rhs), false)
+    }
+
     /// Computes `self / rhs` rounding down.
     ///
     /// # Panics
@@ -141,4 +321,48 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_div_rem_limb() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d in 1_u64.., shift in 0_u32..64)| {
+                let d = (d >> shift).max(1);
+                let wide = Uint::<4096, 64>::from(n);
+                let (q, r) = wide.div_rem(Uint::from(d));
+                assert_eq!(n.div_rem_limb(d), (U::from(q), r.to::<u64>()));
+            });
+        });
+    }
+
+    #[test]
+    fn test_div_exact() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(q: U, d: U, shift: usize)| {
+                let d = (d >> (shift % (64 * LIMBS))).max(U::from(1));
+                let q = q / d;
+                let n = q * d;
+                assert_eq!(n.div_exact(d), q);
+            });
+        });
+    }
+
+    #[test]
+    fn test_overflowing() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(U::from(1).checked_div_rem(U::ZERO), None);
+            proptest!(|(n: U, d: U)| {
+                prop_assume!(d != U::ZERO);
+                assert_eq!(n.overflowing_div(d), (n / d, false));
+                assert_eq!(n.overflowing_rem(d), (n % d, false));
+                assert_eq!(n.checked_div_rem(d), Some((n / d, n % d)));
+                assert_eq!((n.div_euclid(d), n.rem_euclid(d)), n.div_rem(d));
+            });
+        });
+    }
 }
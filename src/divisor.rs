@@ -0,0 +1,205 @@
+use crate::{
+    algorithms::div::{
+        div_2x1, div_3x2, div_nxm_normalized_with_reciprocal, reciprocal, reciprocal_2,
+    },
+    utils::flatten_mut,
+    Uint,
+};
+use core::ops::{Div, Rem};
+
+/// A divisor with a precomputed normalized reciprocal.
+///
+/// Dividing many values by the same divisor with [`Uint::div_rem`] repeats
+/// the normalization and reciprocal computation for every division. This
+/// type does that work once in [`Divisor::new`]. Unlike
+/// [`BarrettReducer`](crate::BarrettReducer) it computes quotients as well as
+/// remainders and does not allocate.
+///
+/// ```
+/// # use ruint::{uint, Divisor, aliases::U256};
+/// # uint!{
+/// let wei = Divisor::new(1_000_000_000_000_000_000_U256).unwrap();
+/// let amount = 12_345_678_900_000_000_000_U256;
+/// assert_eq!(wei.div_rem(amount), (12_U256, 345_678_900_000_000_000_U256));
+/// assert_eq!(amount / &wei, 12_U256);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "alloc", derive(Debug))]
+pub struct Divisor<const BITS: usize, const LIMBS: usize> {
+    value:      Uint<BITS, LIMBS>,
+    /// The divisor shifted left by `shift` bits, stored in `len` limbs.
+    normalized: [u64; LIMBS],
+    /// Number of non-zero limbs of the divisor.
+    len:        usize,
+    /// Leading zeros of the most significant non-zero limb of the divisor.
+    shift:      u32,
+    /// Reciprocal of the most significant one or two normalized limbs.
+    reciprocal: u64,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Divisor<BITS, LIMBS> {
+    /// Precomputes the reciprocal of `divisor`.
+    ///
+    /// Returns [`None`] if `divisor` is zero.
+    #[inline]
+    #[must_use]
+    pub fn new(divisor: Uint<BITS, LIMBS>) -> Option<Self> {
+        let len = divisor.limbs.iter().rposition(|&limb| limb != 0)? + 1;
+        let shift = divisor.limbs[len - 1].leading_zeros();
+        let mut normalized = [0; LIMBS];
+        normalized[..len].copy_from_slice(&divisor.limbs[..len]);
+        if shift > 0 {
+            for i in (0..len).rev() {
+                let lower = if i > 0 { normalized[i - 1] } else { 0 };
+                normalized[i] = (normalized[i] << shift) | (lower >> (64 - shift));
+            }
+        }
+        let reciprocal = if len == 1 {
+            reciprocal(normalized[0])
+        } else {
+            reciprocal_2(u128::from(normalized[len - 1]) << 64 | u128::from(normalized[len - 2]))
+        };
+        Some(Self {
+            value: divisor,
+            normalized,
+            len,
+            shift,
+            reciprocal,
+        })
+    }
+
+    /// The divisor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn divisor(&self) -> Uint<BITS, LIMBS> {
+        self.value
+    }
+
+    /// Computes `value / divisor` and `value % divisor`.
+    #[inline]
+    #[must_use]
+    pub fn div_rem(&self, value: Uint<BITS, LIMBS>) -> (Uint<BITS, LIMBS>, Uint<BITS, LIMBS>) {
+        let (n, shift, v) = (self.len, self.shift, self.reciprocal);
+
+        // Shift the numerator by the same amount as the divisor. This needs
+        // one extra limb.
+        let mut buffer = [[0; LIMBS]; 2];
+        let numerator = &mut flatten_mut(&mut buffer)[..=LIMBS];
+        numerator[..LIMBS].copy_from_slice(&value.limbs);
+        if shift > 0 {
+            for i in (0..=LIMBS).rev() {
+                let lower = if i > 0 { numerator[i - 1] } else { 0 };
+                numerator[i] = (numerator[i] << shift) | (lower >> (64 - shift));
+            }
+        }
+
+        let mut quotient = Uint::ZERO;
+        let mut remainder = Uint::ZERO;
+        match n {
+            1 => {
+                let d = self.normalized[0];
+                let mut r = 0;
+                for i in (0..=LIMBS).rev() {
+                    (numerator[i], r) =
+                        div_2x1(u128::from(r) << 64 | u128::from(numerator[i]), d, v);
+                }
+                quotient.limbs.copy_from_slice(&numerator[..LIMBS]);
+                remainder.limbs[0] = r >> shift;
+            }
+            2 => {
+                let d = u128::from(self.normalized[1]) << 64 | u128::from(self.normalized[0]);
+                let mut r = 0;
+                for i in (0..=LIMBS).rev() {
+                    (numerator[i], r) = div_3x2(r, numerator[i], d, v);
+                }
+                quotient.limbs.copy_from_slice(&numerator[..LIMBS]);
+                let r = r >> shift;
+                #[allow(clippy::cast_possible_truncation)] // Intentional
+                {
+                    remainder.limbs[0] = r as u64;
+                    remainder.limbs[1] = (r >> 64) as u64;
+                }
+            }
+            _ => {
+                // Stores the quotient in `numerator[n..]` and the remainder in
+                // `numerator[..n]`.
+                div_nxm_normalized_with_reciprocal(numerator, &self.normalized[..n], v);
+                quotient.limbs[..=LIMBS - n].copy_from_slice(&numerator[n..]);
+                for i in 0..n {
+                    remainder.limbs[i] = if shift == 0 {
+                        numerator[i]
+                    } else {
+                        let upper = if i + 1 < n { numerator[i + 1] } else { 0 };
+                        (numerator[i] >> shift) | (upper << (64 - shift))
+                    };
+                }
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Computes `value / divisor`.
+    #[inline]
+    #[must_use]
+    pub fn div(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.div_rem(value).0
+    }
+
+    /// Computes `value % divisor`.
+    #[inline]
+    #[must_use]
+    pub fn rem(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.div_rem(value).1
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Div<&Divisor<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: &Divisor<BITS, LIMBS>) -> Self {
+        rhs.div(self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Rem<&Divisor<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: &Divisor<BITS, LIMBS>) -> Self {
+        rhs.rem(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_zero() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            assert!(Divisor::new(Uint::<BITS, LIMBS>::ZERO).is_none());
+        });
+    }
+
+    #[test]
+    fn test_div_rem() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U, shift: usize)| {
+                // Cover all divisor lengths.
+                let d = (d >> (shift % (64 * LIMBS))).max(U::from(1));
+                let divisor = Divisor::new(d).unwrap();
+                assert_eq!(divisor.divisor(), d);
+                assert_eq!(divisor.div_rem(n), n.div_rem(d));
+                assert_eq!(n / &divisor, n / d);
+                assert_eq!(n % &divisor, n % d);
+            });
+        });
+    }
+}
@@ -0,0 +1,220 @@
+#![cfg(feature = "alloc")]
+
+use crate::{algorithms, Montgomery, Uint};
+use alloc::vec::Vec;
+use core::cmp::min;
+
+/// Trial division bound for [`Uint::factorize`].
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 10;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes the prime factorization.
+    ///
+    /// Returns the distinct prime factors in increasing order together with
+    /// their multiplicities. One has the empty factorization.
+    ///
+    /// Small factors are removed by trial division, the remaining cofactor is
+    /// split using perfect power detection and Brent's variant of [Pollard's
+    /// rho algorithm][rho]. Primality is decided using
+    /// [`is_prime_bpsw`](Self::is_prime_bpsw).
+    ///
+    /// The running time grows with the square root of the second largest
+    /// prime factor, so this is only practical if that factor is below
+    /// roughly $2^{80}$.
+    ///
+    /// [rho]: https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(360_U64.factorize(), vec![
+    ///     (2_U64, 3),
+    ///     (3_U64, 2),
+    ///     (5_U64, 1)
+    /// ]);
+    /// assert_eq!(0x10000000000000001_U128.factorize(), vec![
+    ///     (274177_U128, 1),
+    ///     (67280421310721_U128, 1)
+    /// ]);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn factorize(self) -> Vec<(Self, u32)> {
+        assert!(self != Self::ZERO, "zero has no factorization");
+        let mut factors = Vec::new();
+        let mut n = self;
+
+        // Trial division by small primes.
+        for p in (2..TRIAL_DIVISION_LIMIT).filter(|&p| algorithms::is_prime(p)) {
+            if n.bit_len() <= 10 && n.as_limbs()[0] < p * p {
+                break;
+            }
+            let p = Self::from(p);
+            let mut exponent = 0;
+            loop {
+                let (quotient, remainder) = n.div_rem(p);
+                if remainder != Self::ZERO {
+                    break;
+                }
+                n = quotient;
+                exponent += 1;
+            }
+            if exponent > 0 {
+                factors.push((p, exponent));
+            }
+        }
+
+        // Split the remaining cofactor.
+        if n != Self::from(1) {
+            split(n, 1, &mut factors);
+        }
+
+        // Merge repeated factors.
+        factors.sort_unstable();
+        let mut merged: Vec<(Self, u32)> = Vec::with_capacity(factors.len());
+        for (p, exponent) in factors {
+            match merged.last_mut() {
+                Some((q, e)) if *q == p => *e += exponent,
+                _ => merged.push((p, exponent)),
+            }
+        }
+        merged
+    }
+}
+
+/// Adds the prime factors of `n ^ multiplicity` for an `n` without small
+/// prime factors.
+fn split<const BITS: usize, const LIMBS: usize>(
+    n: Uint<BITS, LIMBS>,
+    multiplicity: u32,
+    factors: &mut Vec<(Uint<BITS, LIMBS>, u32)>,
+) {
+    if n.is_prime_bpsw() {
+        factors.push((n, multiplicity));
+        return;
+    }
+    if let Some((base, exponent)) = n.is_perfect_power() {
+        let exponent = u32::try_from(exponent).unwrap();
+        split(base, multiplicity * exponent, factors);
+        return;
+    }
+    let divisor = pollard_brent(n);
+    split(divisor, multiplicity, factors);
+    split(n / divisor, multiplicity, factors);
+}
+
+/// Finds a non-trivial divisor of an odd composite `n` that is not a
+/// perfect power.
+///
+/// See R. P. Brent (1980). An improved Monte Carlo factorization algorithm.
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+fn pollard_brent<const BITS: usize, const LIMBS: usize>(n: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+    // Number of steps to accumulate before computing a gcd.
+    const BATCH: usize = 128;
+
+    // Values stay in Montgomery form. This does not affect the gcds with $n$
+    // as $R$ is coprime to $n$.
+    let ctx = Montgomery::new(n).unwrap();
+    let distance = |a: Uint<BITS, LIMBS>, b: Uint<BITS, LIMBS>| a.abs_diff(b);
+    for c in 1_u64.. {
+        // Iterate $f(x) = x^2 + c$.
+        let c = ctx.to_montgomery(Uint::from(c));
+        let f = |x| ctx.square(x).add_mod(c, n);
+
+        let mut y = ctx.to_montgomery(Uint::from(2));
+        let mut x = y;
+        let mut ys = y;
+        let mut q = ctx.one();
+        let mut g = Uint::from(1);
+        let mut r = 1;
+        while g == Uint::from(1) {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == Uint::from(1) {
+                ys = y;
+                for _ in 0..min(BATCH, r - k) {
+                    y = f(y);
+                    q = ctx.mul(q, distance(x, y));
+                }
+                g = q.gcd(n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // The batch overshot, redo it one step at a time.
+            loop {
+                ys = f(ys);
+                g = distance(x, ys).gcd(n);
+                if g != Uint::from(1) {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U128, const_for, nlimbs};
+    use proptest::{proptest, test_runner::Config};
+
+    #[test]
+    fn test_factorize() {
+        const_for!(BITS in NON_ZERO if (BITS <= 256) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let config = Config { cases: 20, ..Config::default() };
+            proptest!(config, |(n: u64)| {
+                let n = U::wrapping_from(n).max(U::from(1));
+                let factors = n.factorize();
+                let mut product = U::from(1);
+                for window in factors.windows(2) {
+                    assert!(window[0].0 < window[1].0);
+                }
+                for &(p, e) in &factors {
+                    assert!(p.is_prime_bpsw());
+                    product *= p.pow(U::from(e));
+                }
+                assert_eq!(product, n);
+            });
+        });
+    }
+
+    #[test]
+    fn test_factorize_special() {
+        type U = U128;
+        assert_eq!(U::from(1).factorize(), vec![]);
+        assert_eq!(U::from(3).pow(U::from(80)).factorize(), vec![(
+            U::from(3),
+            80
+        )]);
+        let p = U::from(4_294_967_291_u64);
+        let q = U::from(4_294_967_279_u64);
+        assert_eq!((p * q).factorize(), vec![(q, 1), (p, 1)]);
+        assert_eq!((p * p * U::from(1031)).factorize(), vec![
+            (U::from(1031), 1),
+            (p, 2)
+        ]);
+        let f5 = U::from((1_u64 << 32) + 1);
+        assert_eq!((f5 * f5).factorize(), vec![
+            (U::from(641), 2),
+            (U::from(6_700_417), 2)
+        ]);
+    }
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "alloc")] // see comments on `mul_mod`
+
+use crate::Uint;
+use alloc::vec::Vec;
+
+/// Precomputed powers of a fixed `base` modulo a fixed `modulus`.
+///
+/// The exponent is split in windows of [`WINDOW`](Self::WINDOW) bits, and
+/// the table holds $\mathtt{base}^{d ⋅ 2^{w ⋅ \mathtt{WINDOW}}}$ for every
+/// window $w$ and digit $d$. [`pow`](Self::pow) then multiplies one table
+/// entry per window, without any squarings. For a `U256` modulus this is
+/// about six times faster than [`Uint::pow_mod`], at the cost of a table
+/// of 960 values.
+///
+/// ```
+/// # use ruint::{uint, FixedBase, aliases::U64};
+/// # uint!{
+/// let g = FixedBase::new(3_U64, 1000_U64);
+/// assert_eq!(g.pow(4_U64), 81_U64);
+/// assert_eq!(g.pow(7_U64), 187_U64);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedBase<const BITS: usize, const LIMBS: usize> {
+    base:    Uint<BITS, LIMBS>,
+    modulus: Uint<BITS, LIMBS>,
+    /// Entry $w ⋅ (2^{\mathtt{WINDOW}} - 1) + d - 1$ is
+    /// $\mod{\mathtt{base}^{d ⋅ 2^{w ⋅ \mathtt{WINDOW}}}}_{\mathtt{modulus}}$.
+    table:   Vec<Uint<BITS, LIMBS>>,
+}
+
+impl<const BITS: usize, const LIMBS: usize> FixedBase<BITS, LIMBS> {
+    /// The number of exponent bits handled by one table lookup.
+    pub const WINDOW: usize = 4;
+
+    const DIGITS: usize = (1 << Self::WINDOW) - 1;
+
+    /// Precompute the powers of `base` modulo `modulus`.
+    ///
+    /// This takes about as long as three calls to [`Uint::pow_mod`].
+    #[inline]
+    #[must_use]
+    pub fn new(base: Uint<BITS, LIMBS>, modulus: Uint<BITS, LIMBS>) -> Self {
+        let windows = (BITS + Self::WINDOW - 1) / Self::WINDOW;
+        let mut table = Vec::with_capacity(windows * Self::DIGITS);
+        let mut power = base.reduce_mod(modulus);
+        for _ in 0..windows {
+            // Entry `d` of this window is `power^d`.
+            let mut entry = power;
+            for _ in 0..Self::DIGITS {
+                table.push(entry);
+                entry = entry.mul_mod(power, modulus);
+            }
+            // `entry` is now `power^(DIGITS + 1)`, the unit of the next window.
+            power = entry;
+        }
+        Self {
+            base,
+            modulus,
+            table,
+        }
+    }
+
+    /// The base of this table.
+    #[inline]
+    #[must_use]
+    pub const fn base(&self) -> Uint<BITS, LIMBS> {
+        self.base
+    }
+
+    /// The modulus of this table.
+    #[inline]
+    #[must_use]
+    pub const fn modulus(&self) -> Uint<BITS, LIMBS> {
+        self.modulus
+    }
+
+    /// Compute $\mod{\mathtt{base}^{\mathtt{exp}}}_{\mathtt{modulus}}$.
+    ///
+    /// Returns the same result as [`Uint::pow_mod`], i.e. zero if the modulus
+    /// is zero.
+    #[inline]
+    #[must_use]
+    pub fn pow(&self, exp: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        let mut result = Uint::from(1).reduce_mod(self.modulus);
+        for (window, entries) in self.table.chunks_exact(Self::DIGITS).enumerate() {
+            #[allow(clippy::cast_possible_truncation)] // Intentional
+            let digit = (exp >> (window * Self::WINDOW)).as_limbs()[0] as usize & Self::DIGITS;
+            if digit != 0 {
+                result = result.mul_mod(entries[digit - 1], self.modulus);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use core::cmp::min;
+    use proptest::{proptest, test_runner::Config};
+
+    #[test]
+    fn test_pow() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut config = Config::default();
+            config.cases = min(config.cases, if BITS > 500 { 1 } else { 10 });
+            proptest!(config, |(base: U, m: U, exps: [U; 4])| {
+                let table = FixedBase::new(base, m);
+                for exp in exps {
+                    assert_eq!(table.pow(exp), base.pow_mod(exp, m));
+                }
+            });
+        });
+    }
+}
@@ -2,13 +2,14 @@
 #![cfg(feature = "alloc")]
 
 use crate::Uint;
+use alloc::string::{String, ToString};
 use core::{
     fmt::{self, Write},
     mem::MaybeUninit,
 };
 
-mod base {
-    pub(super) trait Base {
+pub(crate) mod base {
+    pub(crate) trait Base {
         /// Highest power of the base that fits in a `u64`.
         const MAX: u64;
         /// Number of characters written using `MAX` as the base in
@@ -20,28 +21,28 @@ mod base {
         const PREFIX: &'static str;
     }
 
-    pub(super) struct Binary;
+    pub(crate) struct Binary;
     impl Base for Binary {
         const MAX: u64 = 1 << 63;
         const WIDTH: usize = 63;
         const PREFIX: &'static str = "0b";
     }
 
-    pub(super) struct Octal;
+    pub(crate) struct Octal;
     impl Base for Octal {
         const MAX: u64 = 1 << 63;
         const WIDTH: usize = 21;
         const PREFIX: &'static str = "0o";
     }
 
-    pub(super) struct Decimal;
+    pub(crate) struct Decimal;
     impl Base for Decimal {
         const MAX: u64 = 10_000_000_000_000_000_000;
         const WIDTH: usize = 19;
         const PREFIX: &'static str = "";
     }
 
-    pub(super) struct Hexadecimal;
+    pub(crate) struct Hexadecimal;
     impl Base for Hexadecimal {
         const MAX: u64 = 1 << 60;
         const WIDTH: usize = 15;
@@ -52,6 +53,9 @@ use base::Base;
 
 macro_rules! write_digits {
     ($self:expr, $f:expr; $base:ty, $base_char:literal) => {
+        write_digits!($self, $f; $base, $base_char; true)
+    };
+    ($self:expr, $f:expr; $base:ty, $base_char:literal; $is_nonnegative:expr) => {
         if LIMBS == 0 || $self.is_zero() {
             return $f.pad_integral(true, <$base>::PREFIX, "0");
         }
@@ -66,9 +70,10 @@ macro_rules! write_digits {
             )
             .unwrap();
         }
-        return $f.pad_integral(true, <$base>::PREFIX, buffer.as_str());
+        return $f.pad_integral($is_nonnegative, <$base>::PREFIX, buffer.as_str());
     };
 }
+pub(crate) use write_digits;
 
 impl<const BITS: usize, const LIMBS: usize> fmt::Display for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -106,14 +111,113 @@ impl<const BITS: usize, const LIMBS: usize> fmt::UpperHex for Uint<BITS, LIMBS>
     }
 }
 
-struct DisplayBuffer<const SIZE: usize> {
+/// Options for [`Uint::format_with`].
+///
+/// The default formats like [`Display`](fmt::Display). Digits can be grouped
+/// with a separator and padded with leading zeros:
+///
+/// ```
+/// # use ruint::{aliases::U256, FormatOptions};
+/// let balance = U256::from(1_234_567_890_u64);
+/// let options = FormatOptions::new().separator(',');
+/// assert_eq!(balance.format_with(&options), "1,234,567,890");
+///
+/// let options = FormatOptions::new()
+///     .separator(' ')
+///     .group_size(4)
+///     .min_digits(12);
+/// assert_eq!(balance.format_with(&options), "0012 3456 7890");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    separator:  Option<char>,
+    group_size: usize,
+    min_digits: usize,
+}
+
+impl Default for FormatOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatOptions {
+    /// Options formatting plain decimal digits.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            separator:  None,
+            group_size: 3,
+            min_digits: 0,
+        }
+    }
+
+    /// Inserts `separator` between groups of digits, counted from the right.
+    #[inline]
+    #[must_use]
+    pub const fn separator(self, separator: char) -> Self {
+        Self {
+            separator: Some(separator),
+            ..self
+        }
+    }
+
+    /// Sets the number of digits in a group, 3 by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group_size` is zero.
+    #[inline]
+    #[must_use]
+    pub const fn group_size(self, group_size: usize) -> Self {
+        assert!(group_size > 0, "group size must be positive");
+        Self { group_size, ..self }
+    }
+
+    /// Pads with leading zeros to at least `min_digits` digits. Separators
+    /// are not counted, and are also inserted between the zeros.
+    #[inline]
+    #[must_use]
+    pub const fn min_digits(self, min_digits: usize) -> Self {
+        Self { min_digits, ..self }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Formats the number in decimal according to `options`.
+    ///
+    /// See [`FormatOptions`] for examples.
+    #[inline]
+    #[must_use]
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let digits = self.to_string();
+        let padding = options.min_digits.saturating_sub(digits.len());
+        let digits = "0".repeat(padding) + &digits;
+        let Some(separator) = options.separator else {
+            return digits;
+        };
+        let groups = (digits.len() - 1) / options.group_size;
+        let mut result = String::with_capacity(digits.len() + groups * separator.len_utf8());
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % options.group_size == 0 {
+                result.push(separator);
+            }
+            result.push(digit);
+        }
+        result
+    }
+}
+
+pub(crate) struct DisplayBuffer<const SIZE: usize> {
     buf: [MaybeUninit<u8>; SIZE],
     len: usize,
 }
 
 impl<const SIZE: usize> DisplayBuffer<SIZE> {
     #[inline]
-    const fn new() -> Self {
+    pub(crate) const fn new() -> Self {
         Self {
             buf: unsafe { MaybeUninit::uninit().assume_init() },
             len: 0,
@@ -121,7 +225,7 @@ impl<const SIZE: usize> DisplayBuffer<SIZE> {
     }
 
     #[inline]
-    fn as_str(&self) -> &str {
+    pub(crate) fn as_str(&self) -> &str {
         // SAFETY: `buf` is only written to by the `fmt::Write::write_str`
         // implementation which writes a valid UTF-8 string to `buf` and
         // correctly sets `len`.
@@ -151,7 +255,7 @@ impl<const SIZE: usize> fmt::Write for DisplayBuffer<SIZE> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use proptest::{prop_assert_eq, proptest};
+    use proptest::{prop_assert, prop_assert_eq, proptest};
 
     #[allow(unused_imports)]
     use alloc::string::ToString;
@@ -213,4 +317,43 @@ mod tests {
             prop_assert_eq!(format!("{n:#X}"), format!("{value:#X}"));
         });
     }
+
+    #[test]
+    fn test_format_with() {
+        proptest!(|(value: u128, group_size in 1_usize..10, min_digits in 0_usize..50)| {
+            let n: Uint<128, 2> = Uint::from(value);
+            let options = FormatOptions::new().min_digits(min_digits);
+            prop_assert_eq!(n.format_with(&options), format!("{value:0min_digits$}"));
+
+            let grouped = n.format_with(&options.separator('_').group_size(group_size));
+            prop_assert_eq!(grouped.replace('_', ""), format!("{value:0min_digits$}"));
+            let mut groups = grouped.split('_');
+            let first = groups.next().unwrap();
+            prop_assert!((1..=group_size).contains(&first.len()));
+            for group in groups {
+                prop_assert_eq!(group.len(), group_size);
+            }
+        });
+
+        let options = FormatOptions::new().separator(',');
+        assert_eq!(Uint::<0, 0>::ZERO.format_with(&options), "0");
+        assert_eq!(Uint::<64, 1>::from(999).format_with(&options), "999");
+        assert_eq!(Uint::<64, 1>::from(1000).format_with(&options), "1,000");
+        assert_eq!(
+            N.format_with(&options.group_size(30)),
+            "90630363884335538,722706632492458228784305343302,099024356772372330524102404852"
+        );
+        assert_eq!(
+            Uint::<64, 1>::from(42).format_with(&options.min_digits(4)),
+            "0,042"
+        );
+        assert_eq!(
+            Uint::<64, 1>::from(1_234_567).format_with(&FormatOptions::new().separator('\u{202f}')),
+            "1\u{202f}234\u{202f}567"
+        );
+        assert_eq!(
+            Uint::<64, 1>::from(1_234).format_with(&FormatOptions::new().min_digits(2)),
+            "1234"
+        );
+    }
 }
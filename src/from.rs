@@ -303,6 +303,183 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     ) -> Option<Self> {
         Self::checked_from_limbs_slice(value.as_limbs())
     }
+
+    /// Convert to a [`Uint`] of at least the same size.
+    ///
+    /// Unlike [`to`](Self::to) this can not fail: converting to a smaller
+    /// size is a compile time error. Use [`wrapping_to`](Self::wrapping_to),
+    /// [`saturating_to`](Self::saturating_to) or [`truncate`](Self::truncate)
+    /// to narrow a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1337_U128.widen::<256, 4>(), 0x1337_U256);
+    /// const WIDE: U512 = U256::MAX.widen();
+    /// assert_eq!(WIDE, U512::from(U256::MAX));
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::*;
+    /// let narrow: U128 = U256::MAX.widen();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn widen<const BITS_DST: usize, const LIMBS_DST: usize>(
+        self,
+    ) -> Uint<BITS_DST, LIMBS_DST> {
+        let () = SumLe::<BITS, 0, BITS_DST, 0>::OK;
+        let mut limbs = [0; LIMBS_DST];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = self.limbs[i];
+            i += 1;
+        }
+        Uint::from_limbs(limbs)
+    }
+
+    /// Convert to a [`Uint`] of at most the same size, keeping the least
+    /// significant bits.
+    ///
+    /// This is the same as [`wrapping_to`](Self::wrapping_to), but converting
+    /// to a larger size is a compile time error. Use [`widen`](Self::widen)
+    /// for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1337cafec0d3_U256.truncate::<32, 1>(), 0xcafec0d3_U32);
+    /// const LOW: U128 = U256::MAX.truncate();
+    /// assert_eq!(LOW, U128::MAX);
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::*;
+    /// let wide: U512 = U256::MAX.truncate();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate<const BITS_DST: usize, const LIMBS_DST: usize>(
+        self,
+    ) -> Uint<BITS_DST, LIMBS_DST> {
+        let () = SumLe::<BITS_DST, 0, BITS, 0>::OK;
+        let mut limbs = [0; LIMBS_DST];
+        let mut i = 0;
+        while i < LIMBS_DST {
+            limbs[i] = self.limbs[i];
+            i += 1;
+        }
+        if LIMBS_DST > 0 {
+            limbs[LIMBS_DST - 1] &= Uint::<BITS_DST, LIMBS_DST>::MASK;
+        }
+        Uint::from_limbs(limbs)
+    }
+
+    /// Construct a [`Uint`] from the two halves `hi` and `lo`, i.e.
+    /// $\mathtt{hi} ⋅ 2^{\mathtt{BITS}} + \mathtt{lo}$.
+    ///
+    /// The result must have at least `2 * BITS` bits, which is checked at
+    /// compile time. See [`from_hi_lo`](Uint::from_hi_lo) for halves of
+    /// different sizes and [`split`](Self::split) for the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// let wide: U512 = U256::concat(1_U256, 2_U256);
+    /// assert_eq!(wide, (1_U512 << 256) + 2_U512);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn concat<const BITS_DST: usize, const LIMBS_DST: usize>(
+        hi: Self,
+        lo: Self,
+    ) -> Uint<BITS_DST, LIMBS_DST> {
+        Uint::from_hi_lo(hi, lo)
+    }
+
+    /// Construct a [`Uint`] from the most significant bits `hi` and the least
+    /// significant bits `lo`, i.e. $\mathtt{hi} ⋅ 2^{\mathtt{BITS\_LO}} +
+    /// \mathtt{lo}$.
+    ///
+    /// The halves must fit in `BITS` bits together, which is checked at
+    /// compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U256::from_hi_lo(0xab_U8, U128::MAX),
+    ///     (0xac_U256 << 128) - 1_U256
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_hi_lo<
+        const BITS_HI: usize,
+        const LIMBS_HI: usize,
+        const BITS_LO: usize,
+        const LIMBS_LO: usize,
+    >(
+        hi: Uint<BITS_HI, LIMBS_HI>,
+        lo: Uint<BITS_LO, LIMBS_LO>,
+    ) -> Self {
+        let () = SumLe::<BITS_HI, BITS_LO, BITS, 0>::OK;
+        let hi = Self::wrapping_from_limbs_slice(hi.as_limbs());
+        let lo = Self::wrapping_from_limbs_slice(lo.as_limbs());
+        (hi << BITS_LO) | lo
+    }
+
+    /// Split into the most significant bits `hi` and the least significant
+    /// `BITS_LO` bits `lo`, the inverse of [`from_hi_lo`](Self::from_hi_lo).
+    ///
+    /// The halves must be able to hold `BITS` bits together, which is checked
+    /// at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// let (hi, lo): (U256, U256) = ((1_U512 << 256_usize) + 2_U512).split();
+    /// assert_eq!((hi, lo), (1_U256, 2_U256));
+    /// let (hi, lo): (Uint<4, 1>, Uint<12, 1>) = 0xabcd_U16.split();
+    /// assert_eq!((hi, lo), (0xa_U4, 0xbcd_U12));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split<
+        const BITS_HI: usize,
+        const LIMBS_HI: usize,
+        const BITS_LO: usize,
+        const LIMBS_LO: usize,
+    >(
+        self,
+    ) -> (Uint<BITS_HI, LIMBS_HI>, Uint<BITS_LO, LIMBS_LO>) {
+        let () = SumLe::<BITS, 0, BITS_HI, BITS_LO>::OK;
+        let lo = Uint::wrapping_from_limbs_slice(self.as_limbs());
+        let hi = Uint::wrapping_from_limbs_slice((self >> BITS_LO).as_limbs());
+        (hi, lo)
+    }
+}
+
+/// Compile time check that `A + B <= C + D`, evaluated when `OK` is used.
+struct SumLe<const A: usize, const B: usize, const C: usize, const D: usize>;
+
+impl<const A: usize, const B: usize, const C: usize, const D: usize> SumLe<A, B, C, D> {
+    const OK: () = assert!(A + B <= C + D, "Uint does not fit in the target size");
 }
 
 /// ⚠️ Workaround for [Rust issue #50133](https://github.com/rust-lang/rust/issues/50133).
@@ -719,6 +896,7 @@ impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for f64 {
 mod test {
     use super::*;
     use crate::{const_for, nlimbs};
+    use proptest::proptest;
 
     #[test]
     fn test_u64() {
@@ -734,6 +912,40 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_widen_truncate() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let wide: Uint<4160, 65> = value.widen();
+                assert_eq!(wide, Uint::from(value));
+                assert_eq!(wide.truncate::<BITS, LIMBS>(), value);
+                assert_eq!(value.widen::<BITS, LIMBS>(), value);
+                assert_eq!(value.truncate::<BITS, LIMBS>(), value);
+                assert_eq!(value.truncate::<0, 0>(), Uint::ZERO);
+                assert_eq!(value.truncate::<1, 1>(), value.wrapping_to::<Uint<1, 1>>());
+            });
+        });
+    }
+
+    #[test]
+    fn test_split() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            type W = Uint<{ 2 * BITS }, { nlimbs(2 * BITS) }>;
+            proptest!(|(hi: U, lo: U)| {
+                let wide: W = U::concat(hi, lo);
+                assert_eq!(wide, (W::from(hi) << BITS) + W::from(lo));
+                assert_eq!(wide.split(), (hi, lo));
+                assert_eq!(W::from_hi_lo(hi, lo), wide);
+                let (high, low): (Uint<64, 1>, W) = wide.split();
+                assert_eq!((high, low), (Uint::ZERO, wide));
+            });
+        });
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_f64() {
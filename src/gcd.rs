@@ -28,20 +28,42 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
     /// ⚠️ Compute the greatest common divisor and the Bézout coefficients.
     ///
-    /// **Warning.** This is API is unstable and may change in a minor release.
+    /// **Warning.** This API is unstable and may change in a minor release.
     ///
     /// Returns $(\mathtt{gcd}, \mathtt{x}, \mathtt{y}, \mathtt{sign})$ such
     /// that
     ///
     /// $$
     /// \gcd(\mathtt{self}, \mathtt{other}) = \mathtt{gcd} = \begin{cases}
-    ///     \mathtt{self} · \mathtt{x} - \mathtt{other} . \mathtt{y} &
+    ///     \mathtt{self} · \mathtt{x} - \mathtt{other} · \mathtt{y} &
     /// \mathtt{sign} \\\\     \mathtt{other} · \mathtt{y} - \mathtt{self} ·
     /// \mathtt{x} & ¬\mathtt{sign} \end{cases}
     /// $$
     ///
+    /// The cofactors are returned as magnitudes $\mathtt{x}, \mathtt{y} ≥ 0$
+    /// and the single flag `sign` determines which of the two terms is
+    /// subtracted. In signed terms the Bézout coefficients are $(\mathtt{x},
+    /// -\mathtt{y})$ if `sign` is set and $(-\mathtt{x}, \mathtt{y})$
+    /// otherwise.
+    ///
     /// Note that the intermediate products may overflow, even though the result
     /// after subtraction will fit in the bit size of the [`Uint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint! {
+    /// let (a, b) = (240_U64, 46_U64);
+    /// let (gcd, x, y, sign) = a.gcd_extended(b);
+    /// assert_eq!(gcd, 2_U64);
+    /// if sign {
+    ///     assert_eq!(a * x - b * y, gcd);
+    /// } else {
+    ///     assert_eq!(b * y - a * x, gcd);
+    /// }
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
     pub fn gcd_extended(self, other: Self) -> (Self, Self, Self, bool) {
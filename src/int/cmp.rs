@@ -0,0 +1,51 @@
+use super::Int;
+use core::cmp::Ordering;
+
+impl<const BITS: usize, const LIMBS: usize> PartialOrd for Int<BITS, LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Ord for Int<BITS, LIMBS> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        // Values of the same sign compare like their two's-complement bits.
+        match (self.is_negative(), rhs.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => self.0.cmp(&rhs.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs, Uint};
+    use proptest::proptest;
+
+    #[test]
+    fn test_cmp_i8() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                assert_eq!(Int::<8, 1>::from(a).cmp(&Int::from(b)), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(raw: Uint<BITS, LIMBS>)| {
+                let value = I::from_raw(raw);
+                assert!(I::MIN <= value);
+                assert!(value <= I::MAX);
+                assert_eq!(value < I::ZERO, value.is_negative());
+            });
+        });
+    }
+}
@@ -0,0 +1,84 @@
+#![allow(clippy::missing_inline_in_public_items)] // allow format functions
+#![cfg(feature = "alloc")]
+
+use super::Int;
+use crate::fmt::{
+    base::{self, Base},
+    write_digits, DisplayBuffer,
+};
+use core::fmt::{self, Write};
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Display for Int<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abs = self.unsigned_abs();
+        write_digits!(abs, f; base::Decimal, ""; !self.is_negative());
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Debug for Int<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// Like the std signed integers, the radix formats print the two's-complement
+// representation.
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Binary for Int<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Octal for Int<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::LowerHex for Int<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::UpperHex for Int<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn test_fmt() {
+        proptest!(|(value: i128)| {
+            let n: Int<128, 2> = Int::from(value);
+
+            prop_assert_eq!(format!("{n:}"), format!("{value:}"));
+            prop_assert_eq!(format!("{n:+}"), format!("{value:+}"));
+            prop_assert_eq!(format!("{n:064}"), format!("{value:064}"));
+            prop_assert_eq!(format!("{n:>64}"), format!("{value:>64}"));
+            prop_assert_eq!(format!("{n:?}"), format!("{value:?}"));
+
+            prop_assert_eq!(format!("{n:b}"), format!("{value:b}"));
+            prop_assert_eq!(format!("{n:#o}"), format!("{value:#o}"));
+            prop_assert_eq!(format!("{n:x}"), format!("{value:x}"));
+            prop_assert_eq!(format!("{n:#X}"), format!("{value:#X}"));
+        });
+    }
+
+    #[test]
+    fn test_min() {
+        assert_eq!(Int::<8, 1>::MIN.to_string(), "-128");
+        assert_eq!(Int::<0, 0>::ZERO.to_string(), "0");
+        assert_eq!(Int::<1, 1>::MIN.to_string(), "-1");
+        assert_eq!(
+            Int::<256, 4>::MIN.to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+    }
+}
@@ -0,0 +1,358 @@
+use super::Int;
+use crate::{ToUintError, Uint};
+use core::fmt;
+
+/// Error for [`TryFrom<T>`][TryFrom] for [`Int`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ToIntError<T> {
+    /// Value is too large to fit the Int.
+    ///
+    /// `.0` is `BITS` and `.1` is the wrapped value.
+    ValueTooLarge(usize, T),
+
+    /// Value is too small (too negative) to fit the Int.
+    ///
+    /// `.0` is `BITS` and `.1` is the wrapped value.
+    ValueTooSmall(usize, T),
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for ToIntError<T> {}
+
+impl<T> fmt::Display for ToIntError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ValueTooLarge(bits, _) => write!(f, "Value is too large for Int<{bits}>"),
+            Self::ValueTooSmall(bits, _) => write!(f, "Value is too small for Int<{bits}>"),
+        }
+    }
+}
+
+/// Error for [`TryFrom<Int>`][TryFrom].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromIntError<T> {
+    /// The Int value is too large for the target type.
+    ///
+    /// `.0` number of `BITS` in the Int, `.1` is the wrapped value and
+    /// `.2` is the maximum representable value in the target type.
+    Overflow(usize, T, T),
+
+    /// The Int value is too small for the target type.
+    ///
+    /// `.0` number of `BITS` in the Int, `.1` is the wrapped value and
+    /// `.2` is the minimum representable value in the target type.
+    Underflow(usize, T, T),
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for FromIntError<T> {}
+
+impl<T> fmt::Display for FromIntError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow(bits, ..) => write!(
+                f,
+                "Int<{bits}> value is too large for {}",
+                core::any::type_name::<T>()
+            ),
+            Self::Underflow(bits, ..) => write!(
+                f,
+                "Int<{bits}> value is too small for {}",
+                core::any::type_name::<T>()
+            ),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Int<BITS, LIMBS> {
+    /// Construct a new [`Int`] from the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the conversion fails, for example if the value is out of range
+    /// for the bit-size of the [`Int`]. The panic will be attributed to the
+    /// call site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Int, aliases::*};
+    /// assert_eq!(I8::from(-128_i16), I8::MIN);
+    /// assert_eq!(I256::from(-1_i8), I256::MINUS_ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn from<T>(value: T) -> Self
+    where
+        Self: TryFrom<T, Error = ToIntError<Self>>,
+    {
+        match Self::try_from(value) {
+            Ok(n) => n,
+            Err(e) => panic!("Int conversion error: {e}"),
+        }
+    }
+
+    /// Construct a new [`Int`] from the value saturating the value to the
+    /// minimum or maximum value of the [`Int`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Int, aliases::*};
+    /// assert_eq!(I8::saturating_from(300_i16), I8::MAX);
+    /// assert_eq!(I8::saturating_from(-300_i16), I8::MIN);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_from<T>(value: T) -> Self
+    where
+        Self: TryFrom<T, Error = ToIntError<Self>>,
+    {
+        match Self::try_from(value) {
+            Ok(n) => n,
+            Err(ToIntError::ValueTooLarge(..)) => Self::MAX,
+            Err(ToIntError::ValueTooSmall(..)) => Self::MIN,
+        }
+    }
+
+    /// Construct a new [`Int`] from the value, wrapping the two's-complement
+    /// representation to the bit-size of the [`Int`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Int, aliases::*};
+    /// assert_eq!(I8::wrapping_from(300_i16), I8::from(44_i8));
+    /// assert_eq!(I8::wrapping_from(255_u8), I8::MINUS_ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn wrapping_from<T>(value: T) -> Self
+    where
+        Self: TryFrom<T, Error = ToIntError<Self>>,
+    {
+        match Self::try_from(value) {
+            Ok(n) | Err(ToIntError::ValueTooLarge(_, n) | ToIntError::ValueTooSmall(_, n)) => n,
+        }
+    }
+
+    /// Construct from a sign and the little-endian limbs of the absolute
+    /// value, reporting the wrapped value on overflow.
+    #[inline]
+    pub(crate) fn try_from_sign_and_abs_limbs(
+        negative: bool,
+        abs: &[u64],
+    ) -> Result<Self, ToIntError<Self>> {
+        let (abs, truncated) = Uint::overflowing_from_limbs_slice(abs);
+        // Negating the truncated absolute value gives the wrapped value.
+        match Self::overflowing_from_sign_and_abs(negative, abs) {
+            (n, false) if !truncated => Ok(n),
+            (n, _) if negative => Err(ToIntError::ValueTooSmall(BITS, n)),
+            (n, _) => Err(ToIntError::ValueTooLarge(BITS, n)),
+        }
+    }
+
+    /// The low 128 bits of the two's-complement representation, sign
+    /// extended.
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)] // Intentional
+    fn low_i128(&self) -> i128 {
+        let mut low = 0_u128;
+        for (i, &limb) in self.as_limbs().iter().take(2).enumerate() {
+            low |= u128::from(limb) << (64 * i);
+        }
+        if BITS == 0 || BITS >= 128 {
+            low as i128
+        } else {
+            let shift = 128 - BITS;
+            ((low << shift) as i128) >> shift
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Uint<BITS, LIMBS>> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let result = Self(value);
+        if result.is_negative() {
+            Err(ToIntError::ValueTooLarge(BITS, result))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Int<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            Err(ToUintError::ValueNegative(BITS, value.0))
+        } else {
+            Ok(value.0)
+        }
+    }
+}
+
+// i128 is the base case for all signed primitives.
+impl<const BITS: usize, const LIMBS: usize> TryFrom<i128> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)] // Intentional
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        let abs = value.unsigned_abs();
+        Self::try_from_sign_and_abs_limbs(value.is_negative(), &[abs as u64, (abs >> 64) as u64])
+    }
+}
+
+// u128 is the base case for all unsigned primitives.
+impl<const BITS: usize, const LIMBS: usize> TryFrom<u128> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)] // Intentional
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        let (raw, truncated) =
+            Uint::overflowing_from_limbs_slice(&[value as u64, (value >> 64) as u64]);
+        let result = Self(raw);
+        if truncated || result.is_negative() {
+            Err(ToIntError::ValueTooLarge(BITS, result))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($base:ty; $($int:ty)*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> TryFrom<$int> for Int<BITS, LIMBS> {
+            type Error = ToIntError<Self>;
+
+            #[inline]
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                Self::try_from(value as $base)
+            }
+        }
+    )*};
+}
+
+impl_from_primitive!(i128; i8 i16 i32 i64 isize);
+impl_from_primitive!(u128; bool u8 u16 u32 u64 usize);
+
+macro_rules! to_primitive {
+    ($($int:ty)*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> TryFrom<Int<BITS, LIMBS>> for $int {
+            type Error = FromIntError<Self>;
+
+            #[inline]
+            fn try_from(value: Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+                Self::try_from(&value)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> TryFrom<&Int<BITS, LIMBS>> for $int {
+            type Error = FromIntError<Self>;
+
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            fn try_from(value: &Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+                const SIGNED: bool = <$int>::MIN != 0;
+                // The magnitude of the most negative value, if any.
+                const CAPACITY: usize = if SIGNED { <$int>::BITS - 1 } else { <$int>::BITS } as usize;
+                let wrapped = value.low_i128() as Self;
+                let (negative, abs) = value.into_sign_and_abs();
+                let fits = if negative {
+                    SIGNED
+                        && (abs.bit_len() <= CAPACITY
+                            || (abs.bit_len() == CAPACITY + 1 && abs.is_power_of_two()))
+                } else {
+                    abs.bit_len() <= CAPACITY
+                };
+                if fits {
+                    Ok(wrapped)
+                } else if negative {
+                    Err(FromIntError::Underflow(BITS, wrapped, Self::MIN))
+                } else {
+                    Err(FromIntError::Overflow(BITS, wrapped, Self::MAX))
+                }
+            }
+        }
+    )*};
+}
+
+to_primitive!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_i128_roundtrip() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(value: i128)| {
+                match I::try_from(value) {
+                    Ok(n) => assert_eq!(i128::try_from(n), Ok(value)),
+                    Err(ToIntError::ValueTooLarge(..)) => assert!(BITS < 128 && value > 0),
+                    Err(ToIntError::ValueTooSmall(..)) => assert!(BITS < 128 && value < 0),
+                }
+                let wrapped = I::wrapping_from(value);
+                if BITS >= 128 {
+                    assert_eq!(wrapped.low_i128(), value);
+                } else {
+                    assert_eq!(wrapped.low_i128(), (value << (128 - BITS)) >> (128 - BITS));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_to_primitive() {
+        type I = Int<12, 1>;
+        assert_eq!(i8::try_from(I::from(-128)), Ok(-128));
+        assert_eq!(
+            i8::try_from(I::from(-129)),
+            Err(FromIntError::Underflow(12, 127, i8::MIN))
+        );
+        assert_eq!(
+            i8::try_from(I::from(128)),
+            Err(FromIntError::Overflow(12, -128, i8::MAX))
+        );
+        assert_eq!(u8::try_from(I::from(255)), Ok(255));
+        assert_eq!(
+            u8::try_from(I::from(-1)),
+            Err(FromIntError::Underflow(12, 255, 0))
+        );
+        assert_eq!(i128::try_from(I::MIN), Ok(-2048));
+        assert_eq!(u128::try_from(I::MAX), Ok(2047));
+        assert_eq!(i64::try_from(Int::<64, 1>::MIN), Ok(i64::MIN));
+        assert_eq!(i128::try_from(Int::<128, 2>::MIN), Ok(i128::MIN));
+        assert!(i128::try_from(Int::<129, 3>::MIN).is_err());
+    }
+
+    #[test]
+    fn test_uint() {
+        type I = Int<64, 1>;
+        type U = Uint<64, 1>;
+        assert_eq!(I::try_from(U::from(5)), Ok(I::from(5)));
+        assert_eq!(
+            I::try_from(U::MAX),
+            Err(ToIntError::ValueTooLarge(64, I::MINUS_ONE))
+        );
+        assert_eq!(U::try_from(I::from(5)), Ok(U::from(5)));
+        assert_eq!(
+            U::try_from(I::MINUS_ONE),
+            Err(ToUintError::ValueNegative(64, U::MAX))
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! Signed two's-complement integers backed by [`Uint`].
+
+use crate::Uint;
+
+mod cmp;
+mod fmt;
+mod from;
+mod ops;
+mod string;
+
+pub use self::from::{FromIntError, ToIntError};
+
+/// The signed integers $\left[-2^{\mathtt{BITS} - 1}, 2^{\mathtt{BITS} -
+/// 1}\right)$ in two's-complement representation.
+///
+/// [`Int`] is a thin wrapper around a [`Uint`] of the same size that
+/// interprets the most significant bit as the sign bit. It mirrors the std
+/// signed integer types the same way [`Uint`] mirrors the unsigned ones.
+///
+/// # Notable differences from `std` int types.
+///
+/// * The operators `+`, `-`, `*`, etc. use wrapping math, just like [`Uint`].
+/// * The `pow` family of methods take an unsigned [`Uint`] exponent.
+/// * [`Int::signum`] returns an `i8`, since `1` is not representable for `BITS
+///   == 1`.
+/// * For `BITS == 0` the only value is zero, so [`Int::MIN`], [`Int::MAX`] and
+///   [`Int::MINUS_ONE`] are all zero.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+#[repr(transparent)]
+pub struct Int<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> Int<BITS, LIMBS> {
+    /// The size of this integer type in 64-bit limbs.
+    pub const LIMBS: usize = Uint::<BITS, LIMBS>::LIMBS;
+
+    /// The size of this integer type in bits.
+    pub const BITS: usize = BITS;
+
+    /// The value zero.
+    pub const ZERO: Self = Self(Uint::ZERO);
+
+    /// The value minus one, with all bits set.
+    pub const MINUS_ONE: Self = Self(Uint::MAX);
+
+    /// The smallest value that can be represented by this integer type,
+    /// $-2^{\mathtt{BITS} - 1}$.
+    pub const MIN: Self = {
+        let mut limbs = [0; LIMBS];
+        if BITS > 0 {
+            limbs[(BITS - 1) / 64] = 1 << ((BITS - 1) % 64);
+        }
+        Self(Uint::from_limbs(limbs))
+    };
+
+    /// The largest value that can be represented by this integer type,
+    /// $2^{\mathtt{BITS} - 1} − 1$.
+    pub const MAX: Self = {
+        let mut limbs = Uint::<BITS, LIMBS>::MAX.into_limbs();
+        if BITS > 0 {
+            limbs[(BITS - 1) / 64] ^= 1 << ((BITS - 1) % 64);
+        }
+        Self(Uint::from_limbs(limbs))
+    };
+
+    /// Reinterprets the two's-complement bits of a [`Uint`] as an [`Int`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_raw(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+
+    /// Returns the two's-complement bits as a [`Uint`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_raw(self) -> Uint<BITS, LIMBS> {
+        self.0
+    }
+
+    /// View the two's-complement bits as a [`Uint`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_raw(&self) -> &Uint<BITS, LIMBS> {
+        &self.0
+    }
+
+    /// Construct a new integer from a little-endian array of two's-complement
+    /// limbs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is to large for the bit-size of the Int.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self(Uint::from_limbs(limbs))
+    }
+
+    /// View the array of two's-complement limbs.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_limbs(&self) -> &[u64; LIMBS] {
+        self.0.as_limbs()
+    }
+
+    /// Convert to a array of two's-complement limbs.
+    ///
+    /// Limbs are least significant first.
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_limbs(self) -> [u64; LIMBS] {
+        self.0.into_limbs()
+    }
+
+    /// Returns `true` if `self` is negative and `false` if the number is zero
+    /// or positive.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        BITS > 0 && self.0.bit(BITS - 1)
+    }
+
+    /// Returns `true` if `self` is positive and `false` if the number is zero
+    /// or negative.
+    #[inline]
+    #[must_use]
+    pub fn is_positive(&self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    /// Returns true if the value is zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of `self`.
+    #[inline]
+    #[must_use]
+    pub fn signum(&self) -> i8 {
+        if self.is_negative() {
+            -1
+        } else {
+            i8::from(!self.is_zero())
+        }
+    }
+
+    /// Returns the sign and the absolute value of `self`.
+    ///
+    /// The sign is `true` for negative numbers. The absolute value of
+    /// [`Int::MIN`] is $2^{\mathtt{BITS} - 1}$, which is always representable
+    /// as a [`Uint`].
+    #[inline]
+    #[must_use]
+    pub const fn into_sign_and_abs(self) -> (bool, Uint<BITS, LIMBS>) {
+        (self.is_negative(), self.unsigned_abs())
+    }
+
+    /// Construct an [`Int`] from a sign and an absolute value.
+    ///
+    /// Returns a tuple of the value along with a boolean indicating whether
+    /// the value is out of range. If so, the wrapped value is returned.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_from_sign_and_abs(negative: bool, abs: Uint<BITS, LIMBS>) -> (Self, bool) {
+        let value = Self(if negative { abs.wrapping_neg() } else { abs });
+        let overflow = value.is_negative() != negative && !abs.is_zero();
+        (value, overflow)
+    }
+
+    /// Construct an [`Int`] from a sign and an absolute value, returning
+    /// [`None`] if the value is out of range.
+    #[inline]
+    #[must_use]
+    pub fn checked_from_sign_and_abs(negative: bool, abs: Uint<BITS, LIMBS>) -> Option<Self> {
+        match Self::overflowing_from_sign_and_abs(negative, abs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes the absolute value of `self` without any wrapping or
+    /// panicking.
+    #[inline]
+    #[must_use]
+    pub const fn unsigned_abs(self) -> Uint<BITS, LIMBS> {
+        if self.is_negative() {
+            self.0.wrapping_neg()
+        } else {
+            self.0
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// Returns a tuple of the absolute value along with a boolean indicating
+    /// whether an overflow happened. This only happens for [`Int::MIN`], in
+    /// which case [`Int::MIN`] is returned.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_abs(self) -> (Self, bool) {
+        if self.is_negative() {
+            self.overflowing_neg()
+        } else {
+            (self, false)
+        }
+    }
+
+    /// Computes the absolute value of `self`, returning [`None`] if
+    /// `self == MIN`.
+    #[inline]
+    #[must_use]
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.overflowing_abs() {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes the absolute value of `self`, saturating at [`Int::MAX`].
+    #[inline]
+    #[must_use]
+    pub const fn saturating_abs(self) -> Self {
+        match self.overflowing_abs() {
+            (value, false) => value,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Computes the absolute value of `self`, wrapping around at the boundary
+    /// of the type.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_abs(self) -> Self {
+        self.overflowing_abs().0
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// This wraps around for [`Int::MIN`], like the other operators.
+    #[inline]
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        self.wrapping_abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Int::<0, 0>::MIN, Int::ZERO);
+        assert_eq!(Int::<0, 0>::MAX, Int::ZERO);
+        assert_eq!(Int::<1, 1>::MIN, Int::MINUS_ONE);
+        assert_eq!(Int::<1, 1>::MAX, Int::ZERO);
+        assert_eq!(Int::<8, 1>::MIN.into_raw(), Uint::from(0x80));
+        assert_eq!(Int::<8, 1>::MAX.into_raw(), Uint::from(0x7f));
+        assert_eq!(Int::<65, 2>::MIN.into_limbs(), [0, 1]);
+        assert_eq!(Int::<65, 2>::MAX.into_limbs(), [u64::MAX, 0]);
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type I = Int<BITS, LIMBS>;
+            assert!(I::MIN.is_negative());
+            assert!(!I::MAX.is_negative());
+            assert_eq!(I::MAX.wrapping_add(I::MINUS_ONE.wrapping_neg()), I::MIN);
+        });
+    }
+
+    #[test]
+    fn test_sign_and_abs() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(raw: Uint<BITS, LIMBS>)| {
+                let value = I::from_raw(raw);
+                let (negative, abs) = value.into_sign_and_abs();
+                assert_eq!(negative, value.is_negative());
+                assert_eq!(I::checked_from_sign_and_abs(negative, abs), Some(value));
+                assert_eq!(value.checked_abs().is_none(), BITS != 0 && value == I::MIN);
+            });
+        });
+    }
+
+    #[test]
+    fn test_abs_i8() {
+        for i in i8::MIN..=i8::MAX {
+            let value = Int::<8, 1>::from(i);
+            assert_eq!(value.unsigned_abs(), Uint::from(i.unsigned_abs()));
+            assert_eq!(value.checked_abs(), i.checked_abs().map(Int::from));
+            assert_eq!(value.saturating_abs(), Int::from(i.saturating_abs()));
+            assert_eq!(value.wrapping_abs(), Int::from(i.wrapping_abs()));
+            assert_eq!(value.signum(), i.signum());
+        }
+    }
+}
@@ -0,0 +1,767 @@
+use super::Int;
+use crate::Uint;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+impl<const BITS: usize, const LIMBS: usize> Int<BITS, LIMBS> {
+    /// Computes `self + rhs`, returning [`None`] if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes `-self`, returning [`None`] if `self == MIN`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.overflowing_neg() {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes `self - rhs`, returning [`None`] if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether
+    /// an arithmetic overflow would occur. If an overflow would have occurred
+    /// then the wrapped value is returned.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let result = Self(self.0.wrapping_add(rhs.0));
+        // Overflow iff both operands have the same sign and the result doesn't.
+        let overflow =
+            self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Calculates `-self`.
+    ///
+    /// Returns a tuple of the negation along with a boolean indicating whether
+    /// an arithmetic overflow would occur. This only happens for
+    /// [`Int::MIN`], in which case [`Int::MIN`] is returned.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        let result = Self(self.0.wrapping_neg());
+        // Only `MIN` is its own non-zero negation.
+        let overflow = self.is_negative() && result.is_negative();
+        (result, overflow)
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating
+    /// whether an arithmetic overflow would occur. If an overflow would have
+    /// occurred then the wrapped value is returned.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let result = Self(self.0.wrapping_sub(rhs.0));
+        // Overflow iff the operands have different signs and the result has the
+        // sign of `rhs`.
+        let overflow =
+            self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Computes `self + rhs`, saturating at the numeric bounds instead of
+    /// overflowing.
+    #[inline(always)]
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.overflowing_add(rhs) {
+            (value, false) => value,
+            _ if self.is_negative() => Self::MIN,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Computes `-self`, saturating at [`Int::MAX`] instead of overflowing.
+    #[inline(always)]
+    #[must_use]
+    pub const fn saturating_neg(self) -> Self {
+        match self.overflowing_neg() {
+            (value, false) => value,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Computes `self - rhs`, saturating at the numeric bounds instead of
+    /// overflowing.
+    #[inline(always)]
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.overflowing_sub(rhs) {
+            (value, false) => value,
+            _ if self.is_negative() => Self::MIN,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Computes `self + rhs`, wrapping around at the boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Computes `-self`, wrapping around at the boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Computes `self - rhs`, wrapping around at the boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Computes `self * rhs`, returning [`None`] if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Calculates the multiplication of self and rhs.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating
+    /// whether an arithmetic overflow would occur. If an overflow would have
+    /// occurred then the wrapped value is returned.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let negative = self.is_negative() != rhs.is_negative();
+        let (abs, overflow) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+        // Negating the wrapped absolute value gives the wrapped product.
+        let (result, out_of_range) = Self::overflowing_from_sign_and_abs(negative, abs);
+        (result, overflow || out_of_range)
+    }
+
+    /// Computes `self * rhs`, saturating at the numeric bounds instead of
+    /// overflowing.
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        match self.overflowing_mul(rhs) {
+            (value, false) => value,
+            _ if self.is_negative() != rhs.is_negative() => Self::MIN,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Computes `self * rhs`, wrapping around at the boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        // Two's-complement multiplication is identical to unsigned.
+        Self(self.0.wrapping_mul(rhs.0))
+    }
+
+    /// Computes `self / rhs`, returning [`None`] if `rhs == 0` or the division
+    /// results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        match self.overflowing_div(rhs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes `self % rhs`, returning [`None`] if `rhs == 0` or the division
+    /// results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        match self.overflowing_rem(rhs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes `self / rhs` rounding towards zero and `self % rhs` with the
+    /// sign of `self`, wrapping around at the boundary of the type.
+    ///
+    /// The only case where wrapping occurs is `MIN / -1`, which results in
+    /// `(MIN, 0)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let (quotient, remainder) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+        let quotient = if self.is_negative() == rhs.is_negative() {
+            quotient
+        } else {
+            quotient.wrapping_neg()
+        };
+        let remainder = if self.is_negative() {
+            remainder.wrapping_neg()
+        } else {
+            remainder
+        };
+        (Self(quotient), Self(remainder))
+    }
+
+    /// Calculates `self / rhs` rounding towards zero.
+    ///
+    /// Returns a tuple of the quotient along with a boolean indicating whether
+    /// an arithmetic overflow would occur. This only happens for `MIN / -1`,
+    /// in which case `MIN` is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        let overflow = self.is_div_overflow(rhs);
+        (self.div_rem(rhs).0, overflow)
+    }
+
+    /// Calculates `self % rhs` with the sign of `self`.
+    ///
+    /// Returns a tuple of the remainder along with a boolean indicating whether
+    /// the corresponding division would overflow. This only happens for
+    /// `MIN % -1`, in which case `0` is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        let overflow = self.is_div_overflow(rhs);
+        (self.div_rem(rhs).1, overflow)
+    }
+
+    /// Computes `self / rhs` rounding towards zero, saturating at
+    /// [`Int::MAX`] for `MIN / -1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        match self.overflowing_div(rhs) {
+            (value, false) => value,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Computes `self / rhs` rounding towards zero, wrapping around at the
+    /// boundary of the type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Computes `self % rhs` with the sign of `self`, wrapping around at the
+    /// boundary of the type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    /// Computes the Euclidean quotient of `self / rhs`, wrapping around at
+    /// the boundary of the type.
+    ///
+    /// This rounds such that the remainder [`rem_euclid`](Self::rem_euclid)
+    /// is always non-negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let (quotient, remainder) = self.div_rem(rhs);
+        if remainder.is_negative() {
+            if rhs.is_negative() {
+                quotient.wrapping_add(Self::from_raw(Uint::from(1)))
+            } else {
+                quotient.wrapping_sub(Self::from_raw(Uint::from(1)))
+            }
+        } else {
+            quotient
+        }
+    }
+
+    /// Computes the non-negative remainder of `self / rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self.div_rem(rhs).1;
+        if remainder.is_negative() {
+            // The magnitude of `rhs` always fits in the unsigned range.
+            Self(remainder.0.wrapping_add(rhs.unsigned_abs()))
+        } else {
+            remainder
+        }
+    }
+
+    /// Computes `self.div_euclid(rhs)`, returning [`None`] if `rhs == 0` or
+    /// the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || self.is_div_overflow(rhs) {
+            return None;
+        }
+        Some(self.div_euclid(rhs))
+    }
+
+    /// Computes `self.rem_euclid(rhs)`, returning [`None`] if `rhs == 0` or
+    /// the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || self.is_div_overflow(rhs) {
+            return None;
+        }
+        Some(self.rem_euclid(rhs))
+    }
+
+    /// Whether `self / rhs` overflows, i.e. `self == MIN && rhs == -1`.
+    #[inline]
+    fn is_div_overflow(self, rhs: Self) -> bool {
+        BITS > 0 && self == Self::MIN && rhs == Self::MINUS_ONE
+    }
+
+    /// Raises self to the power of `exp`.
+    ///
+    /// Returns [`None`] if the result would overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_pow(self, exp: Uint<BITS, LIMBS>) -> Option<Self> {
+        match self.overflowing_pow(exp) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Raises self to the power of `exp` and if the result would overflow.
+    ///
+    /// Returns a tuple of the power along with a boolean indicating whether an
+    /// arithmetic overflow would occur. If an overflow would have occurred
+    /// then the wrapped value is returned.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_pow(self, exp: Uint<BITS, LIMBS>) -> (Self, bool) {
+        if BITS == 0 {
+            return (self, false);
+        }
+        let negative = self.is_negative() && exp.bit(0);
+        let (abs, overflow) = self.unsigned_abs().overflowing_pow(exp);
+        let (result, out_of_range) = Self::overflowing_from_sign_and_abs(negative, abs);
+        (result, overflow || out_of_range)
+    }
+
+    /// Raises self to the power of `exp`, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn pow(self, exp: Uint<BITS, LIMBS>) -> Self {
+        self.wrapping_pow(exp)
+    }
+
+    /// Raises self to the power of `exp`, saturating on overflow.
+    #[inline]
+    #[must_use]
+    pub fn saturating_pow(self, exp: Uint<BITS, LIMBS>) -> Self {
+        match self.overflowing_pow(exp) {
+            (value, false) => value,
+            _ if self.is_negative() && exp.bit(0) => Self::MIN,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Raises self to the power of `exp`, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_pow(self, exp: Uint<BITS, LIMBS>) -> Self {
+        Self(self.0.wrapping_pow(exp))
+    }
+
+    /// Shifts `self` left by `rhs` bits, returning [`None`] if `rhs >= BITS`.
+    #[inline]
+    #[must_use]
+    pub fn checked_shl(self, rhs: usize) -> Option<Self> {
+        if rhs >= BITS {
+            return None;
+        }
+        Some(self.wrapping_shl(rhs))
+    }
+
+    /// Shifts `self` right by `rhs` bits, returning [`None`] if `rhs >= BITS`.
+    #[inline]
+    #[must_use]
+    pub fn checked_shr(self, rhs: usize) -> Option<Self> {
+        if rhs >= BITS {
+            return None;
+        }
+        Some(self.wrapping_shr(rhs))
+    }
+
+    /// Shifts `self` left by `rhs` bits.
+    ///
+    /// Bits shifted out are discarded. Shifting by `BITS` or more results in
+    /// zero.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_shl(self, rhs: usize) -> Self {
+        Self(self.0.wrapping_shl(rhs))
+    }
+
+    /// Arithmetic shift of `self` right by `rhs` bits.
+    ///
+    /// The sign bit is copied into the vacated bits. Shifting by `BITS` or
+    /// more results in `0` for non-negative and `-1` for negative values.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_shr(self, rhs: usize) -> Self {
+        Self(self.0.arithmetic_shr(rhs))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Neg for Int<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.wrapping_neg()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Neg for &Int<BITS, LIMBS> {
+    type Output = Int<BITS, LIMBS>;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.wrapping_neg()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Not for Int<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Not for &Int<BITS, LIMBS> {
+    type Output = Int<BITS, LIMBS>;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Int(!self.0)
+    }
+}
+
+macro_rules! impl_int_bin_op {
+    ($trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $fdel:ident) => {
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign<Int<BITS, LIMBS>>
+            for Int<BITS, LIMBS>
+        {
+            #[inline(always)]
+            #[track_caller]
+            fn $fn_assign(&mut self, rhs: Int<BITS, LIMBS>) {
+                *self = self.$fdel(rhs);
+            }
+        }
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign<&Int<BITS, LIMBS>>
+            for Int<BITS, LIMBS>
+        {
+            #[inline(always)]
+            #[track_caller]
+            fn $fn_assign(&mut self, rhs: &Int<BITS, LIMBS>) {
+                *self = self.$fdel(*rhs);
+            }
+        }
+        impl<const BITS: usize, const LIMBS: usize> $trait<Int<BITS, LIMBS>> for Int<BITS, LIMBS> {
+            type Output = Int<BITS, LIMBS>;
+
+            #[inline(always)]
+            #[track_caller]
+            fn $fn(self, rhs: Int<BITS, LIMBS>) -> Self::Output {
+                self.$fdel(rhs)
+            }
+        }
+        impl<const BITS: usize, const LIMBS: usize> $trait<&Int<BITS, LIMBS>> for Int<BITS, LIMBS> {
+            type Output = Int<BITS, LIMBS>;
+
+            #[inline(always)]
+            #[track_caller]
+            fn $fn(self, rhs: &Int<BITS, LIMBS>) -> Self::Output {
+                self.$fdel(*rhs)
+            }
+        }
+        impl<const BITS: usize, const LIMBS: usize> $trait<Int<BITS, LIMBS>> for &Int<BITS, LIMBS> {
+            type Output = Int<BITS, LIMBS>;
+
+            #[inline(always)]
+            #[track_caller]
+            fn $fn(self, rhs: Int<BITS, LIMBS>) -> Self::Output {
+                self.$fdel(rhs)
+            }
+        }
+        impl<const BITS: usize, const LIMBS: usize> $trait<&Int<BITS, LIMBS>>
+            for &Int<BITS, LIMBS>
+        {
+            type Output = Int<BITS, LIMBS>;
+
+            #[inline(always)]
+            #[track_caller]
+            fn $fn(self, rhs: &Int<BITS, LIMBS>) -> Self::Output {
+                self.$fdel(*rhs)
+            }
+        }
+    };
+}
+
+impl<const BITS: usize, const LIMBS: usize> Int<BITS, LIMBS> {
+    #[inline(always)]
+    fn raw_and(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+
+    #[inline(always)]
+    fn raw_or(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+
+    #[inline(always)]
+    fn raw_xor(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl_int_bin_op!(Add, add, AddAssign, add_assign, wrapping_add);
+impl_int_bin_op!(Sub, sub, SubAssign, sub_assign, wrapping_sub);
+impl_int_bin_op!(Mul, mul, MulAssign, mul_assign, wrapping_mul);
+impl_int_bin_op!(Div, div, DivAssign, div_assign, wrapping_div);
+impl_int_bin_op!(Rem, rem, RemAssign, rem_assign, wrapping_rem);
+impl_int_bin_op!(BitAnd, bitand, BitAndAssign, bitand_assign, raw_and);
+impl_int_bin_op!(BitOr, bitor, BitOrAssign, bitor_assign, raw_or);
+impl_int_bin_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, raw_xor);
+
+macro_rules! impl_int_shift {
+    ($($u:ty),*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> Shl<$u> for Int<BITS, LIMBS> {
+            type Output = Self;
+
+            #[inline(always)]
+            #[allow(clippy::cast_possible_truncation)]
+            fn shl(self, rhs: $u) -> Self::Output {
+                self.wrapping_shl(rhs as usize)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> Shr<$u> for Int<BITS, LIMBS> {
+            type Output = Self;
+
+            #[inline(always)]
+            #[allow(clippy::cast_possible_truncation)]
+            fn shr(self, rhs: $u) -> Self::Output {
+                self.wrapping_shr(rhs as usize)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> ShlAssign<$u> for Int<BITS, LIMBS> {
+            #[inline(always)]
+            fn shl_assign(&mut self, rhs: $u) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> ShrAssign<$u> for Int<BITS, LIMBS> {
+            #[inline(always)]
+            fn shr_assign(&mut self, rhs: $u) {
+                *self = *self >> rhs;
+            }
+        }
+    )*};
+}
+
+impl_int_shift!(usize, u8, u16, u32);
+
+// Only when losslessy castable to usize.
+#[cfg(target_pointer_width = "64")]
+impl_int_shift!(u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::{prop_assume, proptest};
+
+    type I8 = Int<8, 1>;
+    type I128 = Int<128, 2>;
+
+    #[test]
+    fn test_exhaustive_i8() {
+        for a in i8::MIN..=i8::MAX {
+            let ia = I8::from(a);
+            assert_eq!(
+                ia.overflowing_neg(),
+                (I8::from(a.wrapping_neg()), a.checked_neg().is_none())
+            );
+            assert_eq!(ia.saturating_neg(), I8::from(a.saturating_neg()));
+            for b in i8::MIN..=i8::MAX {
+                let ib = I8::from(b);
+                let (sum, o) = a.overflowing_add(b);
+                assert_eq!(ia.overflowing_add(ib), (I8::from(sum), o));
+                let (diff, o) = a.overflowing_sub(b);
+                assert_eq!(ia.overflowing_sub(ib), (I8::from(diff), o));
+                let (prod, o) = a.overflowing_mul(b);
+                assert_eq!(ia.overflowing_mul(ib), (I8::from(prod), o));
+                assert_eq!(ia.saturating_add(ib), I8::from(a.saturating_add(b)));
+                assert_eq!(ia.saturating_sub(ib), I8::from(a.saturating_sub(b)));
+                assert_eq!(ia.saturating_mul(ib), I8::from(a.saturating_mul(b)));
+                assert_eq!(ia.checked_div(ib), a.checked_div(b).map(I8::from));
+                assert_eq!(ia.checked_rem(ib), a.checked_rem(b).map(I8::from));
+                assert_eq!(
+                    ia.checked_div_euclid(ib),
+                    a.checked_div_euclid(b).map(I8::from)
+                );
+                assert_eq!(
+                    ia.checked_rem_euclid(ib),
+                    a.checked_rem_euclid(b).map(I8::from)
+                );
+                if b != 0 {
+                    assert_eq!(
+                        ia.overflowing_div(ib),
+                        (I8::from(a.wrapping_div(b)), a.checked_div(b).is_none())
+                    );
+                    assert_eq!(ia.wrapping_rem(ib), I8::from(a.wrapping_rem(b)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_i8() {
+        for a in i8::MIN..=i8::MAX {
+            for e in 0_u32..10 {
+                let (pow, o) = a.overflowing_pow(e);
+                let exp = Uint::from(e);
+                assert_eq!(I8::from(a).overflowing_pow(exp), (I8::from(pow), o));
+                assert_eq!(
+                    I8::from(a).saturating_pow(exp),
+                    I8::from(a.saturating_pow(e))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_i128() {
+        proptest!(|(a: i128, b: i128, s in 0_usize..128)| {
+            let (ia, ib) = (I128::from(a), I128::from(b));
+            assert_eq!(ia.overflowing_add(ib), (I128::from(a.wrapping_add(b)), a.checked_add(b).is_none()));
+            assert_eq!(ia.overflowing_sub(ib), (I128::from(a.wrapping_sub(b)), a.checked_sub(b).is_none()));
+            assert_eq!(ia.overflowing_mul(ib), (I128::from(a.wrapping_mul(b)), a.checked_mul(b).is_none()));
+            assert_eq!(ia << s, I128::from(a << s));
+            assert_eq!(ia >> s, I128::from(a >> s));
+            assert_eq!(ia & ib, I128::from(a & b));
+            assert_eq!(ia | ib, I128::from(a | b));
+            assert_eq!(ia ^ ib, I128::from(a ^ b));
+            assert_eq!(!ia, I128::from(!a));
+            prop_assume!(b != 0);
+            assert_eq!(ia / ib, I128::from(a.wrapping_div(b)));
+            assert_eq!(ia % ib, I128::from(a.wrapping_rem(b)));
+            assert_eq!(ia.div_euclid(ib), I128::from(a.wrapping_div_euclid(b)));
+            assert_eq!(ia.rem_euclid(ib), I128::from(a.wrapping_rem_euclid(b)));
+        });
+    }
+
+    #[test]
+    fn test_div_rem() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(a: Uint<BITS, LIMBS>, b: Uint<BITS, LIMBS>)| {
+                let (a, b) = (I::from_raw(a), I::from_raw(b));
+                prop_assume!(!b.is_zero());
+                let (q, r) = a.div_rem(b);
+                assert_eq!(q * b + r, a);
+                assert!(r.is_zero() || r.is_negative() == a.is_negative());
+                assert!(r.unsigned_abs() < b.unsigned_abs());
+            });
+        });
+    }
+
+    #[test]
+    fn test_identities() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(a: Uint<BITS, LIMBS>, b: Uint<BITS, LIMBS>)| {
+                let (a, b) = (I::from_raw(a), I::from_raw(b));
+                assert_eq!(a + b, b + a);
+                assert_eq!(a - b, -(b - a));
+                assert_eq!(a * b, b * a);
+                assert_eq!(a + (-a), I::ZERO);
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(sum.checked_sub(b), Some(a));
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,73 @@
+#![allow(clippy::missing_inline_in_public_items)] // allow format functions
+
+use super::Int;
+use crate::{BaseConvertError, ParseError, Uint};
+use core::str::FromStr;
+
+impl<const BITS: usize, const LIMBS: usize> Int<BITS, LIMBS> {
+    /// Parse a string into a [`Int`].
+    ///
+    /// The string may start with a `-` or `+` sign, the remainder is parsed
+    /// as the absolute value using [`Uint::from_str_radix`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseError::InvalidDigit`] if the string contains a non-digit.
+    /// * [`ParseError::InvalidRadix`] if the radix is larger than 64.
+    /// * [`ParseError::BaseConvertError`] if [`Uint::from_base_be`] fails or
+    ///   the value is out of range.
+    pub fn from_str_radix(src: &str, radix: u64) -> Result<Self, ParseError> {
+        let (negative, src) = split_sign(src);
+        let abs = Uint::from_str_radix(src, radix)?;
+        Self::checked_from_sign_and_abs(negative, abs)
+            .ok_or(ParseError::BaseConvertError(BaseConvertError::Overflow))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromStr for Int<BITS, LIMBS> {
+    type Err = ParseError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let (negative, src) = split_sign(src);
+        let abs = Uint::from_str(src)?;
+        Self::checked_from_sign_and_abs(negative, abs)
+            .ok_or(ParseError::BaseConvertError(BaseConvertError::Overflow))
+    }
+}
+
+fn split_sign(src: &str) -> (bool, &str) {
+    if let Some(rest) = src.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, src.strip_prefix('+').unwrap_or(src))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn test_parse() {
+        proptest!(|(value: i128)| {
+            type I = Int<128, 2>;
+            prop_assert_eq!(I::from_str(&format!("{value:}")), Ok(I::from(value)));
+            prop_assert_eq!(I::from_str(&format!("{value:+}")), Ok(I::from(value)));
+            let (sign, abs) = if value < 0 { ("-", value.unsigned_abs()) } else { ("", value.unsigned_abs()) };
+            prop_assert_eq!(I::from_str(&format!("{sign}{abs:#x}")), Ok(I::from(value)));
+            prop_assert_eq!(I::from_str_radix(&format!("{sign}{abs:o}"), 8), Ok(I::from(value)));
+        });
+    }
+
+    #[test]
+    fn test_range() {
+        type I = Int<8, 1>;
+        assert_eq!(I::from_str("-128"), Ok(I::MIN));
+        assert_eq!(I::from_str("127"), Ok(I::MAX));
+        assert_eq!(I::from_str("-0"), Ok(I::ZERO));
+        let overflow = Err(ParseError::BaseConvertError(BaseConvertError::Overflow));
+        assert_eq!(I::from_str("128"), overflow);
+        assert_eq!(I::from_str("-129"), overflow);
+    }
+}
@@ -18,13 +18,22 @@
     clippy::option_if_let_else,
     clippy::cast_sign_loss,
     clippy::cast_lossless,
+    clippy::elidable_lifetime_names,
+)]
+#![cfg_attr(
+    test,
+    allow(
+        clippy::wildcard_imports,
+        clippy::cognitive_complexity,
+        clippy::ignore_without_reason
+    )
 )]
-#![cfg_attr(test, allow(clippy::wildcard_imports, clippy::cognitive_complexity))]
 #![cfg_attr(not(feature = "std"), no_std)]
 // Unstable features
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
-#![cfg_attr(feature = "nightly", feature(core_intrinsics))]
+#![cfg_attr(feature = "nightly", feature(core_intrinsics, step_trait))]
 #![cfg_attr(feature = "nightly", allow(internal_features))]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 #![cfg_attr(
     feature = "generic_const_exprs",
     feature(generic_const_exprs),
@@ -41,24 +50,40 @@ mod macros;
 mod add;
 pub mod algorithms;
 pub mod aliases;
+mod barrett;
 mod base_convert;
+#[cfg(feature = "rayon")]
+pub mod batch;
 mod bit_arr;
 mod bits;
 mod bytes;
 mod cmp;
 mod const_for;
+mod crt;
+mod ct;
+mod decimal;
 mod div;
+mod divisor;
+mod factor;
+mod fixed_base;
 mod fmt;
 mod from;
 mod gcd;
+mod int;
 mod log;
 mod modular;
+mod montgomery;
 mod mul;
+mod mul_div;
+mod nonzero;
 mod pow;
+mod prime;
+mod range;
 mod root;
 mod special;
 mod string;
 mod utils;
+mod wrapping;
 
 pub mod support;
 
@@ -69,8 +94,21 @@ pub use bit_arr::Bits;
 pub use self::{
     base_convert::BaseConvertError,
     bytes::nbytes,
+    divisor::Divisor,
     from::{FromUintError, ToFieldError, ToUintError, UintTryFrom, UintTryTo},
-    string::ParseError,
+    int::{FromIntError, Int, ToIntError},
+    mul_div::Rounding,
+    nonzero::NonZeroUint,
+    range::UintRange,
+    string::{Alphabet, ParseError, ParseErrorAt, ParseErrorKind, ParseOptions},
+    wrapping::{Saturating, Wrapping},
+};
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::{
+    barrett::BarrettReducer, crt::CrtContext, fixed_base::FixedBase, fmt::FormatOptions,
+    montgomery::Montgomery,
 };
 
 // For documentation purposes we expose the macro directly, otherwise it is
@@ -142,6 +180,10 @@ pub mod nightly {
 ///
 /// [std-overflow]: https://doc.rust-lang.org/reference/expressions/operator-expr.html#overflow
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct Uint<const BITS: usize, const LIMBS: usize> {
     limbs: [u64; LIMBS],
@@ -345,8 +387,8 @@ mod test {
         assert_eq!(mask(0), 0);
         assert_eq!(mask(1), 1);
         assert_eq!(mask(5), 0x1f);
-        assert_eq!(mask(63), u64::max_value() >> 1);
-        assert_eq!(mask(64), u64::max_value());
+        assert_eq!(mask(63), u64::MAX >> 1);
+        assert_eq!(mask(64), u64::MAX);
     }
 
     #[test]
@@ -1,18 +1,15 @@
-#![cfg(feature = "std")]
-
 use crate::Uint;
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Returns the logarithm of the number, rounded down.
     ///
     /// Returns None if the base is less than two, or this number is zero.
+    ///
+    /// This is the same as [`checked_ilog`](Self::checked_ilog).
     #[inline]
     #[must_use]
     pub fn checked_log(self, base: Self) -> Option<usize> {
-        if base < Self::from(2) || self == Self::ZERO {
-            return None;
-        }
-        Some(self.log(base))
+        self.checked_ilog(base)
     }
 
     /// Returns the base 10 logarithm of the number, rounded down.
@@ -21,7 +18,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub fn checked_log10(self) -> Option<usize> {
-        self.checked_log(Self::from(10))
+        self.checked_ilog10()
     }
 
     /// Returns the base 2 logarithm of the number, rounded down.
@@ -32,60 +29,21 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub fn checked_log2(self) -> Option<usize> {
-        self.checked_log(Self::from(2))
+        self.checked_ilog2()
     }
 
     /// Returns the logarithm of the number, rounded down.
     ///
+    /// This is the same as [`ilog`](Self::ilog).
+    ///
     /// # Panics
     ///
     /// Panics if the `base` is less than 2 or if the number is zero.
     #[inline]
     #[must_use]
+    #[track_caller]
     pub fn log(self, base: Self) -> usize {
-        assert!(self != Self::ZERO);
-        assert!(base >= Self::from(2));
-        if base == Self::from(2) {
-            return self.bit_len() - 1;
-        }
-        if self < base {
-            return 0;
-        }
-
-        // Find approximate result
-        #[allow(clippy::cast_precision_loss)] // Casting base to `f64` is fine.
-        let result = self.approx_log2() / base.approx_log2();
-        // We handled edge cases above, so the result should be normal and fit `Self`.
-        assert!(result.is_normal());
-        let mut result = result.try_into().unwrap();
-
-        // Adjust result to get the exact value. At most one of these should happen, but
-        // we loop regardless.
-        loop {
-            if let Some(value) = base.checked_pow(result) {
-                if value > self {
-                    assert!(result != Self::ZERO);
-                    result -= Self::from(1);
-                    continue;
-                }
-            } else {
-                // Overflow, so definitely larger than `value`
-                result -= Self::from(1);
-            }
-            break;
-        }
-        while let Some(trial) = result.checked_add(Self::from(1)) {
-            if let Some(value) = base.checked_pow(trial) {
-                if value <= self {
-                    result = trial;
-                    continue;
-                }
-            }
-            break;
-        }
-
-        // Should always be possible.
-        result.to()
+        self.ilog(base)
     }
 
     /// Returns the base 10 logarithm of the number, rounded down.
@@ -95,8 +53,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Panics if the `base` if the number is zero.
     #[inline]
     #[must_use]
+    #[track_caller]
     pub fn log10(self) -> usize {
-        self.log(Self::from(10))
+        self.ilog10()
     }
 
     /// Returns the base 2 logarithm of the number, rounded down.
@@ -106,10 +65,14 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Panics if the `base` if the number is zero.
     #[inline]
     #[must_use]
+    #[track_caller]
     pub fn log2(self) -> usize {
-        self.log(Self::from(2))
+        self.ilog2()
     }
+}
 
+#[cfg(feature = "std")]
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Double precision logarithm.
     #[inline]
     #[must_use]
@@ -153,6 +116,190 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 }
 
+/// Powers of ten that fit a limb.
+const POW10: [u64; 20] = {
+    let mut table = [1; 20];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns the logarithm of the number to the given `base`, rounded down,
+    /// computed without floating point.
+    ///
+    /// Returns [`None`] if the base is less than two, or this number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(1000_U256.checked_ilog(10_U256), Some(3));
+    /// assert_eq!(999_U256.checked_ilog(10_U256), Some(2));
+    /// assert_eq!(U256::MAX.checked_ilog(3_U256), Some(161));
+    /// assert_eq!(0_U256.checked_ilog(10_U256), None);
+    /// assert_eq!(10_U256.checked_ilog(1_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_ilog(self, base: Self) -> Option<usize> {
+        self.checked_log_rem(base).map(|(exp, _)| exp)
+    }
+
+    /// Returns the logarithm of the number to the given `base`, rounded down,
+    /// computed without floating point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `base` is less than two or if the number is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn ilog(self, base: Self) -> usize {
+        self.checked_ilog(base)
+            .expect("ilog of zero or with base less than two")
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down, or [`None`]
+    /// if the number is zero.
+    ///
+    /// This is the index of the highest set bit.
+    #[inline]
+    #[must_use]
+    pub fn checked_ilog2(self) -> Option<usize> {
+        self.bit_len().checked_sub(1)
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn ilog2(self) -> usize {
+        self.checked_ilog2().expect("ilog2 of zero")
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down, or [`None`]
+    /// if the number is zero. This is one less than the number of decimal
+    /// digits.
+    ///
+    /// The result is estimated from the bit length and corrected with a power
+    /// of ten, or looked up in a table for values that fit a limb.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(1_U256.checked_ilog10(), Some(0));
+    /// assert_eq!(99_U256.checked_ilog10(), Some(1));
+    /// assert_eq!(U256::MAX.checked_ilog10(), Some(77));
+    /// assert_eq!(0_U256.checked_ilog10(), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_ilog10(self) -> Option<usize> {
+        let bits = self.bit_len();
+        if bits == 0 {
+            return None;
+        }
+        // 1233 / 4096 is slightly less than log10(2).
+        let mut exp = (bits * 1233) >> 12;
+        if bits <= 64 {
+            let value = self.limbs[0];
+            return Some(exp - usize::from(value < POW10[exp]));
+        }
+        // The estimate is off by at most one for up to about 200,000 bits.
+        let ten = Self::from(10);
+        let mut power = loop {
+            match ten.checked_pow(Self::from(exp)) {
+                Some(power) if power <= self => break power,
+                _ => exp -= 1,
+            }
+        };
+        while let Some(next) = power.checked_mul(ten).filter(|&next| next <= self) {
+            power = next;
+            exp += 1;
+        }
+        Some(exp)
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn ilog10(self) -> usize {
+        self.checked_ilog10().expect("ilog10 of zero")
+    }
+
+    /// Returns the logarithm of the number to the given `base`, rounded down,
+    /// and the remainder `self - base^exp`. The remainder is zero if and only
+    /// if the number is an exact power of `base`.
+    ///
+    /// Returns [`None`] if the base is less than two, or this number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(1024_U64.checked_log_rem(2_U64), Some((10, 0_U64)));
+    /// assert_eq!(100_U64.checked_log_rem(3_U64), Some((4, 19_U64)));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_log_rem(self, base: Self) -> Option<(usize, Self)> {
+        if base.bit_len() < 2 || self == Self::ZERO {
+            return None;
+        }
+        // Bound the exponent using `2^(b - 1) <= base < 2^b` for `b` the bit
+        // length of `base`, then binary search on `base^exp <= self`.
+        let (bits, base_bits) = (self.bit_len(), base.bit_len());
+        let mut low = (bits - 1) / base_bits;
+        let mut high = bits / (base_bits - 1) + 1;
+        let mut power = base.pow(Self::from(low));
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            match base.checked_pow(Self::from(mid)) {
+                Some(trial) if trial <= self => {
+                    low = mid;
+                    power = trial;
+                }
+                _ => high = mid,
+            }
+        }
+        Some((low, self - power))
+    }
+
+    /// Returns the logarithm of the number to the given `base`, rounded down,
+    /// and the remainder `self - base^exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `base` is less than two or if the number is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn log_rem(self, base: Self) -> (usize, Self) {
+        self.checked_log_rem(base)
+            .expect("log_rem of zero or with base less than two")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,4 +364,42 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_ilog() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, b: U, small in 2_u64..100, shift in 0..=BITS)| {
+                let b = b >> shift;
+                for base in [b, U::saturating_from(small), U::saturating_from(10)] {
+                    match n.checked_log_rem(base) {
+                        Some((e, rem)) => {
+                            let power = base.pow(U::from(e));
+                            assert_eq!(power + rem, n);
+                            assert!(power.checked_mul(base).map_or(true, |next| next > n));
+                            assert_eq!(n.ilog(base), e);
+                        }
+                        None => assert!(n == U::ZERO || base.bit_len() < 2),
+                    }
+                }
+            });
+        });
+        const_for!(BITS in NON_ZERO if (BITS >= 4) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U)| {
+                assert_eq!(n.checked_ilog10(), n.checked_ilog(U::from(10)));
+                assert_eq!(n.checked_ilog2(), n.checked_ilog(U::from(2)));
+            });
+        });
+        // Values next to powers of ten.
+        proptest!(|(e in 1_usize..600)| {
+            type U = Uint<2048, 32>;
+            if let Some(power) = U::from(10).checked_pow(U::from(e)) {
+                assert_eq!(power.ilog10(), e);
+                assert_eq!((power - U::from(1)).ilog10(), e - 1);
+            }
+        });
+    }
 }
@@ -0,0 +1,268 @@
+#![cfg(feature = "alloc")] // see comments on `mul_redc`
+
+use crate::Uint;
+
+/// Precomputed context for Montgomery multiplication modulo an odd
+/// `modulus`.
+///
+/// Values are converted into Montgomery form $\mod{a ⋅ R}_{\mathtt{modulus}}$
+/// with $R = 2^{64 · \mathtt{LIMBS}}$ using
+/// [`to_montgomery`](Self::to_montgomery). Products of values in this form
+/// can be computed with [`mul`](Self::mul) without any division, which makes
+/// it notably faster than [`Uint::mul_mod`] when many operations share the
+/// same modulus.
+///
+/// ```
+/// # use ruint::{uint, Montgomery, aliases::U256};
+/// # uint!{
+/// let modulus =
+///     21888242871839275222246405745257275088548364400416034343698204186575808495617_U256;
+/// let ctx = Montgomery::new(modulus).unwrap();
+/// let a = ctx.to_montgomery(5_U256);
+/// let b = ctx.to_montgomery(6_U256);
+/// assert_eq!(ctx.from_montgomery(ctx.mul(a, b)), 30_U256);
+/// assert_eq!(ctx.from_montgomery(ctx.pow(a, 3_U256)), 125_U256);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Montgomery<const BITS: usize, const LIMBS: usize> {
+    modulus: Uint<BITS, LIMBS>,
+    /// $\mod{-\mathtt{modulus}^{-1}}_{2^{64}}$
+    inv:     u64,
+    /// $\mod{R}_{\mathtt{modulus}}$, i.e. one in Montgomery form.
+    one:     Uint<BITS, LIMBS>,
+    /// $\mod{R^2}_{\mathtt{modulus}}$
+    r2:      Uint<BITS, LIMBS>,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Montgomery<BITS, LIMBS> {
+    /// Precompute the Montgomery constants for `modulus`.
+    ///
+    /// Returns `None` if `modulus` is even (this includes zero and
+    /// `BITS == 0`).
+    #[inline]
+    #[must_use]
+    pub fn new(modulus: Uint<BITS, LIMBS>) -> Option<Self> {
+        if BITS == 0 || !modulus.bit(0) {
+            return None;
+        }
+        let inv = modulus.limbs[0].wrapping_neg();
+        let inv = Uint::<64, 1>::from(inv).inv_ring()?.as_limbs()[0];
+
+        // Compute R mod modulus by repeated doubling.
+        let mut one = Uint::from(1).reduce_mod(modulus);
+        for _ in 0..64 * LIMBS {
+            one = one.add_mod(one, modulus);
+        }
+        let r2 = one.mul_mod(one, modulus);
+        Some(Self {
+            modulus,
+            inv,
+            one,
+            r2,
+        })
+    }
+
+    /// The modulus of this context.
+    #[inline]
+    #[must_use]
+    pub const fn modulus(&self) -> Uint<BITS, LIMBS> {
+        self.modulus
+    }
+
+    /// The value one in Montgomery form.
+    #[inline]
+    #[must_use]
+    pub const fn one(&self) -> Uint<BITS, LIMBS> {
+        self.one
+    }
+
+    /// Convert `value` into Montgomery form.
+    ///
+    /// The value is reduced modulo the modulus first, so any value is
+    /// accepted.
+    #[inline]
+    #[must_use]
+    pub fn to_montgomery(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        value
+            .reduce_mod(self.modulus)
+            .mul_redc(self.r2, self.modulus, self.inv)
+    }
+
+    /// Convert `value` out of Montgomery form.
+    ///
+    /// `value` must be less than the modulus.
+    #[inline]
+    #[must_use]
+    pub fn from_montgomery(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        value.mul_redc(Uint::from(1), self.modulus, self.inv)
+    }
+
+    /// Multiply two values in Montgomery form.
+    ///
+    /// Both arguments must be less than the modulus.
+    #[inline]
+    #[must_use]
+    pub fn mul(&self, lhs: Uint<BITS, LIMBS>, rhs: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        lhs.mul_redc(rhs, self.modulus, self.inv)
+    }
+
+    /// Multiply pairs of values in Montgomery form, storing
+    /// `mul(lhs[i], rhs[i])` in `result[i]`.
+    ///
+    /// All arguments must be less than the modulus. With the `ifma` feature,
+    /// four limb values are multiplied eight at a time with AVX-512 IFMA where
+    /// the CPU supports it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    #[inline]
+    pub fn mul_batch(
+        &self,
+        lhs: &[Uint<BITS, LIMBS>],
+        rhs: &[Uint<BITS, LIMBS>],
+        result: &mut [Uint<BITS, LIMBS>],
+    ) {
+        assert_eq!(lhs.len(), rhs.len());
+        assert_eq!(lhs.len(), result.len());
+        let scalar = |lhs: &[Uint<BITS, LIMBS>], rhs: &[Uint<BITS, LIMBS>], result: &mut [_]| {
+            for ((lhs, rhs), result) in lhs.iter().zip(rhs).zip(result) {
+                *result = self.mul(*lhs, *rhs);
+            }
+        };
+
+        #[cfg(all(feature = "ifma", target_arch = "x86_64"))]
+        if LIMBS == 4 && crate::algorithms::ifma::has_ifma() {
+            let limbs = |value: &Uint<BITS, LIMBS>| -> [u64; 4] {
+                value.as_limbs()[..].try_into().unwrap()
+            };
+            let modulus = limbs(&self.modulus);
+            for ((lhs, rhs), result) in lhs
+                .chunks_exact(8)
+                .zip(rhs.chunks_exact(8))
+                .zip(result.chunks_exact_mut(8))
+            {
+                let a = core::array::from_fn(|i| limbs(&lhs[i]));
+                let b = core::array::from_fn(|i| limbs(&rhs[i]));
+                // SAFETY: The extensions were detected above.
+                let products =
+                    unsafe { crate::algorithms::ifma::mul_redc_4x8(&a, &b, &modulus, self.inv) };
+                for (result, product) in result.iter_mut().zip(products) {
+                    result.limbs.copy_from_slice(&product);
+                }
+            }
+            let done = lhs.len() - lhs.len() % 8;
+            scalar(&lhs[done..], &rhs[done..], &mut result[done..]);
+            return;
+        }
+
+        scalar(lhs, rhs, result);
+    }
+
+    /// Square a value in Montgomery form.
+    ///
+    /// `value` must be less than the modulus.
+    #[inline]
+    #[must_use]
+    pub fn square(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.mul(value, value)
+    }
+
+    /// Raise a value in Montgomery form to the power `exp`.
+    ///
+    /// `base` must be less than the modulus. The result is in Montgomery
+    /// form.
+    #[inline]
+    #[must_use]
+    pub fn pow(
+        &self,
+        mut base: Uint<BITS, LIMBS>,
+        mut exp: Uint<BITS, LIMBS>,
+    ) -> Uint<BITS, LIMBS> {
+        // Exponentiation by squaring
+        let mut result = self.one;
+        while exp > Uint::ZERO {
+            if exp.limbs[0] & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.square(base);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use core::cmp::min;
+    use proptest::{proptest, test_runner::Config};
+
+    #[test]
+    fn test_even() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(m: U)| {
+                assert_eq!(Montgomery::new(m).is_some(), BITS != 0 && m.bit(0));
+            });
+        });
+    }
+
+    #[test]
+    fn test_mul() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, m: U)| {
+                let m = m | U::from(1);
+                let ctx = Montgomery::new(m).unwrap();
+                let am = ctx.to_montgomery(a);
+                let bm = ctx.to_montgomery(b);
+                assert_eq!(ctx.from_montgomery(am), a.reduce_mod(m));
+                assert_eq!(ctx.from_montgomery(ctx.one()), U::from(1).reduce_mod(m));
+                assert_eq!(ctx.from_montgomery(ctx.mul(am, bm)), a.mul_mod(b, m));
+                assert_eq!(ctx.from_montgomery(ctx.square(am)), a.mul_mod(a, m));
+            });
+        });
+    }
+
+    #[test]
+    fn test_mul_batch() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(values: Vec<(U, U)>, m: U)| {
+                let m = m | U::from(1);
+                let ctx = Montgomery::new(m).unwrap();
+                let (lhs, rhs): (Vec<U>, Vec<U>) = values
+                    .into_iter()
+                    .map(|(a, b)| (a.reduce_mod(m), b.reduce_mod(m)))
+                    .unzip();
+                let mut result = vec![U::ZERO; lhs.len()];
+                ctx.mul_batch(&lhs, &rhs, &mut result);
+                for ((&a, &b), &r) in lhs.iter().zip(&rhs).zip(&result) {
+                    assert_eq!(r, ctx.mul(a, b));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_pow() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut config = Config::default();
+            config.cases = min(config.cases, if BITS > 500 { 3 } else { 20 });
+            proptest!(config, |(a: U, e: U, m: U)| {
+                let m = m | U::from(1);
+                let ctx = Montgomery::new(m).unwrap();
+                let result = ctx.from_montgomery(ctx.pow(ctx.to_montgomery(a), e));
+                assert_eq!(result, a.pow_mod(e, m));
+            });
+        });
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{algorithms, nlimbs, Uint};
+use crate::{algorithms, nlimbs, utils::flatten_mut, Uint};
 use core::{
     iter::Product,
     num::Wrapping,
@@ -69,12 +69,95 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         result
     }
 
+    /// Computes `self * self`, wrapping around at the boundary of the type.
+    ///
+    /// This is faster than `self * self` as each cross product is computed
+    /// only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(12_U256.sqr(), 144_U256);
+    /// assert_eq!(U256::MAX.sqr(), 1_U256);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sqr(self) -> Self {
+        self.wrapping_sqr()
+    }
+
+    /// Computes `self * self`, wrapping around at the boundary of the type.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_sqr(self) -> Self {
+        let mut result = Self::ZERO;
+        algorithms::sqr(&mut result.limbs, self.as_limbs());
+        if BITS > 0 {
+            result.limbs[LIMBS - 1] &= Self::MASK;
+        }
+        result
+    }
+
+    /// Computes `self * self` and whether an arithmetic overflow occurred.
+    ///
+    /// See [`Uint::overflowing_mul`].
+    #[inline]
+    #[must_use]
+    pub fn overflowing_sqr(self) -> (Self, bool) {
+        let mut square = [[0; LIMBS]; 2];
+        let square = flatten_mut(&mut square);
+        algorithms::sqr(square, self.as_limbs());
+        let mut result = Self::ZERO;
+        result.limbs.copy_from_slice(&square[..LIMBS]);
+        let mut overflow = square[LIMBS..].iter().any(|&limb| limb != 0);
+        if BITS > 0 {
+            overflow |= result.limbs[LIMBS - 1] > Self::MASK;
+            result.limbs[LIMBS - 1] &= Self::MASK;
+        }
+        (result, overflow)
+    }
+
     /// Computes the inverse modulo $2^{\mathtt{BITS}}$ of `self`, returning
     /// [`None`] if the inverse does not exist.
     #[inline]
     #[must_use]
     pub fn inv_ring(self) -> Option<Self> {
-        if BITS == 0 || self.limbs[0] & 1 == 0 {
+        if BITS == 0 {
+            return None;
+        }
+        self.inv_pow2(BITS)
+    }
+
+    /// Computes the inverse modulo $2^{\mathtt{k}}$ of `self`, returning
+    /// [`None`] if `self` is even or if `k > BITS`.
+    ///
+    /// Uses Newton (Hensel) lifting, which doubles the number of correct bits
+    /// in each step, so only $\log_2 \mathtt{k}$ multiplications are needed.
+    /// For `k == 0` every value is invertible and the result is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(3_U64.inv_pow2(4), Some(11_U64));
+    /// assert_eq!(6_U64.inv_pow2(4), None);
+    /// assert_eq!(3_U64.inv_pow2(65), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn inv_pow2(self, k: usize) -> Option<Self> {
+        if k > BITS {
+            return None;
+        }
+        if k == 0 {
+            return Some(Self::ZERO);
+        }
+        if self.limbs[0] & 1 == 0 {
             return None;
         }
 
@@ -93,15 +176,13 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             inv.0
         };
 
-        // Continue with rest of limbs
-        let mut correct_limbs = 1;
-        while correct_limbs < LIMBS {
+        // Continue with rest of bits
+        let mut correct_bits = 64;
+        while correct_bits < k {
             result *= Self::from(2) - self * result;
-            correct_limbs *= 2;
+            correct_bits *= 2;
         }
-        result.limbs[LIMBS - 1] &= Self::MASK;
-
-        Some(result)
+        Some(result & (Self::MAX >> (BITS - k)))
     }
 
     /// Calculates the complete product `self * rhs` without the possibility to
@@ -147,8 +228,118 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
         result
     }
+
+    /// Calculates the complete product `self * rhs` as a `(low, high)` pair.
+    ///
+    /// This is the same as [`widening_mul`](Self::widening_mul) with the
+    /// result split in two halves of `BITS` bits each, which avoids having to
+    /// name the double-width type. The product equals
+    /// $\mathtt{low} + \mathtt{high} ⋅ 2^{\mathtt{BITS}}$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U256::MAX.widening_mul_pair(U256::MAX),
+    ///     (1_U256, U256::MAX - 1_U256)
+    /// );
+    /// assert_eq!(7_U3.widening_mul_pair(6_U3), (2_U3, 5_U3));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn widening_mul_pair(self, rhs: Self) -> (Self, Self) {
+        // Schoolbook multiplication into the limbs of `lo` followed by `hi`.
+        let mut lo = [0; LIMBS];
+        let mut hi = [0; LIMBS];
+        for (i, &b) in rhs.limbs.iter().enumerate() {
+            let carry_lo = algorithms::addmul_nx1(&mut lo[i..], &self.limbs[..LIMBS - i], b);
+            let carry_hi = algorithms::addmul_nx1(&mut hi[..i], &self.limbs[LIMBS - i..], b);
+            let overflow = algorithms::add_nx1(&mut hi, carry_lo)
+                | algorithms::add_nx1(&mut hi[i..], carry_hi);
+            debug_assert_eq!(overflow, 0);
+        }
+
+        // Split the product at bit `BITS` instead of `64 * LIMBS`.
+        let offset = BITS % 64;
+        if offset == 0 {
+            return (Self::from_limbs(lo), Self::from_limbs(hi));
+        }
+        let limb = |i: usize| match i {
+            _ if i < LIMBS => lo[i],
+            _ if i < 2 * LIMBS => hi[i - LIMBS],
+            _ => 0,
+        };
+        let mut high = [0; LIMBS];
+        for (k, high) in high.iter_mut().enumerate() {
+            let i = LIMBS - 1 + k;
+            *high = (limb(i) >> offset) | (limb(i + 1) << (64 - offset));
+        }
+        lo[LIMBS - 1] &= Self::MASK;
+        (Self::from_limbs(lo), Self::from_limbs(high))
+    }
+
+    /// Calculates `self * rhs + carry` as a `(low, high)` pair.
+    ///
+    /// The result can not overflow. This is the multiplication step of
+    /// schoolbook multiplication on [`Uint`] digits and mirrors the unstable
+    /// `u64::carrying_mul`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U256::MAX.carrying_mul(U256::MAX, U256::MAX),
+    ///     (0_U256, U256::MAX)
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+        let (low, high) = self.widening_mul_pair(rhs);
+        let (low, overflow) = low.overflowing_add(carry);
+        (low, if overflow { high + Self::from(1) } else { high })
+    }
+
+    /// Calculates the complete product `self * rhs` of a single limb `rhs`
+    /// as a `(low, high)` pair.
+    ///
+    /// The product equals $\mathtt{low} + \mathtt{high} ⋅ 2^{\mathtt{BITS}}$.
+    /// This is a single pass over the limbs and much cheaper than
+    /// [`widening_mul_pair`](Self::widening_mul_pair) with a converted `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(12_U256.mul_limb(10), (120_U256, 0));
+    /// assert_eq!(U256::MAX.mul_limb(3), (U256::MAX - 2_U256, 2));
+    /// assert_eq!(7_U3.mul_limb(6), (2_U3, 5));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_limb(mut self, rhs: u64) -> (Self, u64) {
+        let carry = algorithms::mul_nx1(&mut self.limbs, rhs);
+
+        // Split the product at bit `BITS` instead of `64 * LIMBS`.
+        let offset = BITS % 64;
+        if offset == 0 {
+            return (self, carry);
+        }
+        let high = (self.limbs[LIMBS - 1] >> offset) | (carry << (64 - offset));
+        self.limbs[LIMBS - 1] &= Self::MASK;
+        (self, high)
+    }
 }
 
+/// Multiplies wrapping around at the boundary of the type.
 impl<const BITS: usize, const LIMBS: usize> Product<Self> for Uint<BITS, LIMBS> {
     #[inline]
     fn product<I>(iter: I) -> Self
@@ -162,6 +353,7 @@ impl<const BITS: usize, const LIMBS: usize> Product<Self> for Uint<BITS, LIMBS>
     }
 }
 
+/// Multiplies wrapping around at the boundary of the type.
 impl<'a, const BITS: usize, const LIMBS: usize> Product<&'a Self> for Uint<BITS, LIMBS> {
     #[inline]
     fn product<I>(iter: I) -> Self
@@ -241,6 +433,27 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_inv_pow2() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, k: usize)| {
+                let k = k % (BITS + 1);
+                let mask = if k == 0 { U::ZERO } else { U::MAX >> (BITS - k) };
+                match a.inv_pow2(k) {
+                    Some(inv) => {
+                        assert!(a.bit(0) || k == 0);
+                        assert_eq!(inv, inv & mask);
+                        assert_eq!((a * inv) & mask, U::from(1) & mask);
+                    }
+                    None => assert!(!a.bit(0)),
+                }
+            });
+            assert_eq!(U::from(1).inv_pow2(BITS + 1), None);
+        });
+    }
+
     #[test]
     fn test_widening_mul() {
         // Left hand side
@@ -266,4 +479,60 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_sqr() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U)| {
+                assert_eq!(a.sqr(), a * a);
+                assert_eq!(a.wrapping_sqr(), a.wrapping_mul(a));
+                assert_eq!(a.overflowing_sqr(), a.overflowing_mul(a));
+            });
+        });
+    }
+
+    #[test]
+    fn test_widening_mul_pair() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let (low, high) = a.widening_mul_pair(b);
+                assert_eq!(low, a * b);
+                // Compare with the product of the zero-extended operands.
+                let a = Uint::<8192, 128>::from(a);
+                let b = Uint::<8192, 128>::from(b);
+                let product = Uint::<8192, 128>::from(low) | (Uint::<8192, 128>::from(high) << BITS);
+                assert_eq!(product, a * b);
+            });
+        });
+    }
+
+    #[test]
+    fn test_carrying_mul() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, c: U)| {
+                let (low, high) = a.carrying_mul(b, c);
+                let wide = |x: U| Uint::<8192, 128>::from(x);
+                assert_eq!(wide(low) | (wide(high) << BITS), wide(a) * wide(b) + wide(c));
+            });
+        });
+    }
+
+    #[test]
+    fn test_mul_limb() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: u64)| {
+                let wide = Uint::<4160, 65>::from(a) * Uint::from(b);
+                let (low, high) = a.mul_limb(b);
+                assert_eq!(Uint::from(low) + (Uint::from(high) << BITS), wide);
+            });
+        });
+    }
 }
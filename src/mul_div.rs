@@ -0,0 +1,139 @@
+use crate::{algorithms, utils::flatten_mut, Uint};
+
+/// Rounding mode for [`Uint::mul_div_rounding`] and the scaled decimal
+/// conversions such as [`Uint::from_decimal_str_scaled_rounding`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round to the nearest value, ties away from zero.
+    Nearest,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes $\floor{\mathtt{self} ⋅ \mathtt{num} / \mathtt{denom}}$ with a
+    /// double-width intermediate product, i.e. Solidity's `mulDiv`.
+    ///
+    /// Returns [`None`] if `denom` is zero or the result does not fit `Self`.
+    /// The intermediate product never overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(U256::MAX.mul_div(U256::MAX, U256::MAX), Some(U256::MAX));
+    /// assert_eq!(10_U256.mul_div(2_U256, 3_U256), Some(6_U256));
+    /// assert_eq!(U256::MAX.mul_div(2_U256, 1_U256), None);
+    /// assert_eq!(1_U256.mul_div(1_U256, 0_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_div(self, num: Self, denom: Self) -> Option<Self> {
+        self.mul_div_rounding(num, denom, Rounding::Down)
+    }
+
+    /// Computes $\mathtt{self} ⋅ \mathtt{num} / \mathtt{denom}$ with a
+    /// double-width intermediate product, rounded according to `rounding`.
+    ///
+    /// Returns [`None`] if `denom` is zero or the rounded result does not fit
+    /// `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, Rounding, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(
+    ///     10_U256.mul_div_rounding(2_U256, 3_U256, Rounding::Down),
+    ///     Some(6_U256)
+    /// );
+    /// assert_eq!(
+    ///     10_U256.mul_div_rounding(2_U256, 3_U256, Rounding::Up),
+    ///     Some(7_U256)
+    /// );
+    /// assert_eq!(
+    ///     10_U256.mul_div_rounding(2_U256, 3_U256, Rounding::Nearest),
+    ///     Some(7_U256)
+    /// );
+    /// assert_eq!(
+    ///     U256::MAX.mul_div_rounding(1_U256, 2_U256, Rounding::Nearest),
+    ///     Some(U256::MAX / 2_U256 + 1_U256)
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_div_rounding(self, num: Self, mut denom: Self, rounding: Rounding) -> Option<Self> {
+        if denom == Self::ZERO {
+            return None;
+        }
+
+        // Compute the full product in `2 * LIMBS` limbs.
+        let mut product = [[0; LIMBS]; 2];
+        let product = flatten_mut(&mut product);
+        let overflow = algorithms::addmul(product, self.as_limbs(), num.as_limbs());
+        debug_assert!(!overflow);
+
+        // This stores the quotient in `product` and the remainder in `denom`.
+        let divisor = denom;
+        algorithms::div(product, &mut denom.limbs);
+        let (quotient, high) = product.split_at(LIMBS);
+        if high.iter().any(|&limb| limb != 0) {
+            return None;
+        }
+        let quotient = Self::checked_from_limbs_slice(quotient)?;
+        let remainder = denom;
+
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => remainder != Self::ZERO,
+            // Compare `2 * remainder >= divisor` without overflow.
+            Rounding::Nearest => remainder >= divisor - remainder,
+        };
+        if round_up {
+            quotient.checked_add(Self::from(1))
+        } else {
+            Some(quotient)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs, UintTryFrom};
+    use proptest::proptest;
+
+    #[test]
+    fn test_mul_div() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, d: U, shift: usize)| {
+                // Also test small denominators to cover the overflow case.
+                let d = d >> (shift % (64 * LIMBS));
+                let wide = |x: U| Uint::<8192, 128>::from(x);
+                let reference = |rounding| {
+                    if d == U::ZERO {
+                        return None;
+                    }
+                    let (q, r) = (wide(a) * wide(b)).div_rem(wide(d));
+                    let q = match rounding {
+                        Rounding::Down => q,
+                        Rounding::Up => if r == Uint::ZERO { q } else { q + Uint::from(1) },
+                        Rounding::Nearest => if r * Uint::from(2) >= wide(d) { q + Uint::from(1) } else { q },
+                    };
+                    U::uint_try_from(q).ok()
+                };
+                assert_eq!(a.mul_div(b, d), reference(Rounding::Down));
+                for rounding in [Rounding::Down, Rounding::Up, Rounding::Nearest] {
+                    assert_eq!(a.mul_div_rounding(b, d, rounding), reference(rounding));
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{algorithms, Uint};
+use core::ops::{Div, DivAssign, Rem, RemAssign};
+
+/// A [`Uint`] that is known not to be zero.
+///
+/// This is the analogue of [`NonZeroU64`](core::num::NonZeroU64). Unlike the
+/// primitive version it does not provide a niche, so `Option<NonZeroUint>` is
+/// larger than `NonZeroUint`. Division and remainder by a `NonZeroUint` never
+/// panic.
+///
+/// ```
+/// # use ruint::{uint, NonZeroUint, aliases::U256};
+/// # uint!{
+/// let divisor = NonZeroUint::new(7_U256).unwrap();
+/// assert_eq!(100_U256 / divisor, 14_U256);
+/// assert_eq!(100_U256 % divisor, 2_U256);
+/// assert_eq!(NonZeroUint::new(0_U256), None);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroUint<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> NonZeroUint<BITS, LIMBS> {
+    /// Creates a non-zero value if the given value is not zero.
+    #[inline]
+    #[must_use]
+    pub fn new(value: Uint<BITS, LIMBS>) -> Option<Self> {
+        if value.is_zero() {
+            None
+        } else {
+            Some(Self(value))
+        }
+    }
+
+    /// Creates a non-zero value without checking whether it is zero.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be zero. Code, including `unsafe` code, is allowed to
+    /// rely on this invariant.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new_unchecked(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+
+    /// Returns the contained value.
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(self) -> Uint<BITS, LIMBS> {
+        self.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes `self / rhs` and `self % rhs` for a non-zero divisor.
+    #[inline]
+    #[must_use]
+    pub fn div_rem_nonzero(mut self, rhs: NonZeroUint<BITS, LIMBS>) -> (Self, Self) {
+        let mut rhs = rhs.0;
+        algorithms::div(&mut self.limbs, &mut rhs.limbs);
+        (self, rhs)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<NonZeroUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    #[inline(always)]
+    fn from(value: NonZeroUint<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Div<NonZeroUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: NonZeroUint<BITS, LIMBS>) -> Self {
+        self.div_rem_nonzero(rhs).0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Rem<NonZeroUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: NonZeroUint<BITS, LIMBS>) -> Self {
+        self.div_rem_nonzero(rhs).1
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> DivAssign<NonZeroUint<BITS, LIMBS>>
+    for Uint<BITS, LIMBS>
+{
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: NonZeroUint<BITS, LIMBS>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> RemAssign<NonZeroUint<BITS, LIMBS>>
+    for Uint<BITS, LIMBS>
+{
+    #[inline(always)]
+    fn rem_assign(&mut self, rhs: NonZeroUint<BITS, LIMBS>) {
+        *self = *self % rhs;
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($trait:ident),*) => {$(
+        #[cfg(feature = "alloc")] // `Uint` formatting requires `alloc`
+        impl<const BITS: usize, const LIMBS: usize> core::fmt::$trait for NonZeroUint<BITS, LIMBS> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::$trait::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+impl_fmt!(Debug, Display, Binary, Octal, LowerHex, UpperHex);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_new() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(NonZeroUint::new(U::ZERO), None);
+            proptest!(|(n: U)| {
+                assert_eq!(NonZeroUint::new(n).map(NonZeroUint::get), Some(n).filter(|n| *n != U::ZERO));
+            });
+        });
+    }
+
+    #[test]
+    fn test_div_rem() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U, shift: usize)| {
+                let d = (d >> (shift % (64 * LIMBS))).max(U::from(1));
+                let nz = NonZeroUint::new(d).unwrap();
+                assert_eq!(n.div_rem_nonzero(nz), n.div_rem(d));
+                assert_eq!(n / nz, n / d);
+                assert_eq!(n % nz, n % d);
+                let mut m = n;
+                m /= nz;
+                assert_eq!(m, n / d);
+                m = n;
+                m %= nz;
+                assert_eq!(m, n % d);
+                assert_eq!(format!("{nz} {nz:?} {nz:x}"), format!("{d} {d:?} {d:x}"));
+            });
+        });
+    }
+}
@@ -66,7 +66,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             }
 
             // Square base
-            let (s, o) = self.overflowing_mul(self);
+            let (s, o) = self.overflowing_sqr();
             self = s;
             base_overflow |= o;
             exp >>= 1;
@@ -108,7 +108,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             }
 
             // Square base
-            self = self.wrapping_mul(self);
+            self = self.wrapping_sqr();
             exp >>= 1;
         }
         result
@@ -0,0 +1,645 @@
+#![cfg(feature = "alloc")] // see comments on `mul_mod`
+
+use crate::{algorithms, Montgomery, Uint};
+use alloc::vec::Vec;
+
+/// Miller–Rabin rounds for random candidates. This bounds the probability of
+/// returning a composite well below $2^{-64}$.
+#[cfg(feature = "rand")]
+const RANDOM_PRIME_ROUNDS: usize = 32;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns `true` if `self` is a strong probable prime to `base`.
+    ///
+    /// This is a single round of the [Miller–Rabin test][mr]. Primes always
+    /// pass, and an odd composite passes for at most a quarter of all bases.
+    /// Bases that are a multiple of `self` pass.
+    ///
+    /// Even numbers only pass if they equal two.
+    ///
+    /// [mr]: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
+    #[inline]
+    #[must_use]
+    pub fn miller_rabin(self, base: Self) -> bool {
+        if BITS == 0 || !self.bit(0) {
+            return BITS > 1 && self == Self::from(2);
+        }
+        if self == Self::from(1) {
+            return false;
+        }
+        Montgomery::new(self).map_or(false, |ctx| miller_rabin_ctx(&ctx, base))
+    }
+
+    /// Returns `true` if `self` is (probably) prime.
+    ///
+    /// Values below $2^{64}$ are tested exactly using a known sufficient set
+    /// of Miller–Rabin bases and `rounds` is ignored. Larger values are trial
+    /// divided by small primes and then tested using the first `rounds`
+    /// primes as [Miller–Rabin](Self::miller_rabin) bases.
+    ///
+    /// The first 13 prime bases are sufficient for all values below
+    /// $3.3 ⋅ 10^{24}$. Beyond that composites can be constructed that pass
+    /// for any fixed set of bases, use `is_probable_prime` (requires the `rand`
+    /// feature) with random bases for untrusted inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert!(65537_U64.is_prime(0));
+    /// assert!(!3215031751_U64.is_prime(0));
+    /// assert!(0x7fffffffffffffffffffffffffffffff_U128.is_prime(20));
+    /// assert!(!0xffffffffffffffffffffffffffffffff_U128.is_prime(20));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_prime(self, rounds: usize) -> bool {
+        let ctx = match self.prime_precheck() {
+            Ok(ctx) => ctx,
+            Err(result) => return result,
+        };
+        (2..)
+            .filter(|&base| algorithms::is_prime(base))
+            .take(rounds)
+            .all(|base| miller_rabin_ctx(&ctx, Self::from_limbs_slice(&[base])))
+    }
+
+    /// Returns `true` if `self` is probably prime, using `rounds` rounds of
+    /// Miller–Rabin with random bases taken from `rng`.
+    ///
+    /// Values below $2^{64}$ are tested exactly as in
+    /// [`is_prime`](Self::is_prime). For larger values a composite passes
+    /// with probability at most $4^{-\mathtt{rounds}}$, regardless of how it
+    /// was chosen.
+    #[cfg(feature = "rand")]
+    #[inline]
+    #[must_use]
+    pub fn is_probable_prime<R: rand::Rng + ?Sized>(self, rounds: usize, rng: &mut R) -> bool {
+        let ctx = match self.prime_precheck() {
+            Ok(ctx) => ctx,
+            Err(result) => return result,
+        };
+        // Bases are uniform in $[2, n - 2]$, up to a negligible bias.
+        (0..rounds).all(|_| {
+            let base = rng.gen::<Self>().reduce_mod(self - Self::from(3)) + Self::from(2);
+            miller_rabin_ctx(&ctx, base)
+        })
+    }
+
+    /// Generate a random prime of exactly `bits` bits.
+    ///
+    /// A random odd starting point with the top bit set is taken from `rng`
+    /// and incremented until a prime is found. Candidates with small prime
+    /// factors are skipped using a sieve, the remaining ones are tested with
+    /// [`is_probable_prime`](Self::is_probable_prime) using 32 rounds.
+    ///
+    /// Like all incremental searches the result is not uniformly distributed
+    /// over the primes: primes following a large gap are more likely.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is less than two or larger than `BITS`.
+    #[cfg(feature = "rand")]
+    #[inline]
+    #[must_use]
+    pub fn random_prime<R: rand::Rng + ?Sized>(rng: &mut R, bits: usize) -> Self {
+        assert!(bits >= 2 && bits <= BITS, "bits must be in 2..=BITS");
+        Self::sieved_search(rng, bits, false)
+    }
+
+    /// Generate a random safe prime of exactly `bits` bits.
+    ///
+    /// A safe prime $p$ is a prime for which $(p - 1) / 2$ is also prime. The
+    /// search works as in [`random_prime`](Self::random_prime), sieving both
+    /// $p$ and $(p - 1) / 2$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is less than three or larger than `BITS`.
+    #[cfg(feature = "rand")]
+    #[inline]
+    #[must_use]
+    pub fn random_safe_prime<R: rand::Rng + ?Sized>(rng: &mut R, bits: usize) -> Self {
+        assert!(bits >= 3 && bits <= BITS, "bits must be in 3..=BITS");
+        Self::sieved_search(rng, bits, true)
+    }
+
+    #[cfg(feature = "rand")]
+    fn sieved_search<R: rand::Rng + ?Sized>(rng: &mut R, bits: usize, safe: bool) -> Self {
+        // Sieve by odd primes less than the smallest candidate, so that
+        // candidates are never rejected for being one of the sieving primes.
+        let limit = if bits >= 12 {
+            Sieve::LIMIT
+        } else {
+            1 << (bits - 2)
+        };
+
+        // Safe primes are $3 \pmod 4$ so that $(p - 1) / 2$ is odd.
+        let step = if safe { 4 } else { 2 };
+        let mask = Self::MAX >> (BITS - bits);
+        loop {
+            let mut candidate = rng.gen::<Self>() & mask;
+            candidate.set_bit(bits - 1, true);
+            candidate.set_bit(0, true);
+            if safe {
+                candidate.set_bit(1, true);
+            }
+            let mut sieve = Sieve::new(candidate, limit);
+
+            while candidate.bit_len() == bits {
+                // For safe primes $(p - 1) / 2 ≡ 0 \pmod r$ iff $p ≡ 1 \pmod r$.
+                let sieved = sieve
+                    .residues
+                    .iter()
+                    .all(|&residue| residue != 0 && !(safe && residue == 1));
+                if sieved
+                    && candidate.is_probable_prime(RANDOM_PRIME_ROUNDS, rng)
+                    && (!safe || (candidate >> 1_usize).is_probable_prime(RANDOM_PRIME_ROUNDS, rng))
+                {
+                    return candidate;
+                }
+                let Some(next) = candidate.checked_add(Self::from(step)) else {
+                    break;
+                };
+                candidate = next;
+                sieve.advance(step);
+            }
+        }
+    }
+
+    /// Returns the smallest prime larger than `self`, or `None` if it does
+    /// not fit.
+    ///
+    /// Candidates are tested using [`is_prime_bpsw`](Self::is_prime_bpsw),
+    /// see [`next_prime_by`](Self::next_prime_by) to use a different test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0_U64.next_prime(), Some(2_U64));
+    /// assert_eq!(7_U64.next_prime(), Some(11_U64));
+    /// assert_eq!(251_U8.next_prime(), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_prime(self) -> Option<Self> {
+        self.next_prime_by(Self::is_prime_bpsw)
+    }
+
+    /// Returns the largest prime smaller than `self`, or `None` if there is
+    /// none.
+    ///
+    /// Candidates are tested using [`is_prime_bpsw`](Self::is_prime_bpsw),
+    /// see [`previous_prime_by`](Self::previous_prime_by) to use a different
+    /// test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(2_U64.previous_prime(), None);
+    /// assert_eq!(11_U64.previous_prime(), Some(7_U64));
+    /// assert_eq!(U64::MAX.previous_prime(), Some(18446744073709551557_U64));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn previous_prime(self) -> Option<Self> {
+        self.previous_prime_by(Self::is_prime_bpsw)
+    }
+
+    /// Returns the smallest value larger than `self` that passes `is_prime`,
+    /// or `None` if it does not fit.
+    ///
+    /// Only odd candidates without small prime factors are passed to
+    /// `is_prime`, except for the values two and three.
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// let p = 1000000_U64.next_prime_by(|n| n.is_prime(0));
+    /// assert_eq!(p, Some(1000003_U64));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_prime_by(self, mut is_prime: impl FnMut(Self) -> bool) -> Option<Self> {
+        if BITS < 2 {
+            return None;
+        }
+        let mut candidate = if self < Self::from(2) {
+            if is_prime(Self::from(2)) {
+                return Some(Self::from(2));
+            }
+            Self::from(3)
+        } else {
+            (self.checked_add(Self::from(1))?) | Self::from(1)
+        };
+        let mut sieve = Sieve::for_search(candidate);
+        loop {
+            if !sieve.has_factor() && is_prime(candidate) {
+                return Some(candidate);
+            }
+            candidate = candidate.checked_add(Self::from(2))?;
+            sieve.advance(2);
+        }
+    }
+
+    /// Returns the largest value smaller than `self` that passes `is_prime`,
+    /// or `None` if there is none.
+    ///
+    /// Only odd candidates without small prime factors are passed to
+    /// `is_prime`, except for the value two.
+    #[inline]
+    #[must_use]
+    pub fn previous_prime_by(self, mut is_prime: impl FnMut(Self) -> bool) -> Option<Self> {
+        if BITS < 2 || self <= Self::from(2) {
+            return None;
+        }
+        if self == Self::from(3) {
+            return is_prime(Self::from(2)).then(|| Self::from(2));
+        }
+        // Largest odd value smaller than `self`, at least three.
+        let mut candidate = (self - Self::from(2)) | Self::from(1);
+        let mut sieve = Sieve::for_search(candidate);
+        loop {
+            if !sieve.has_factor() && is_prime(candidate) {
+                return Some(candidate);
+            }
+            if candidate == Self::from(3) {
+                return is_prime(Self::from(2)).then(|| Self::from(2));
+            }
+            candidate -= Self::from(2);
+            sieve.retreat(2);
+        }
+    }
+
+    /// Returns `true` if `self` is prime according to the [Baillie–PSW
+    /// test][bpsw].
+    ///
+    /// This combines a strong Fermat test to base two with a strong Lucas
+    /// test using Selfridge's parameters. It is exact for values below
+    /// $2^{64}$ and no composite passing it is known, making it the
+    /// recommended test when false positives are unacceptable.
+    ///
+    /// [bpsw]: https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Uint, aliases::*};
+    /// # uint!{
+    /// assert!(0x7fffffffffffffffffffffffffffffff_U128.is_prime_bpsw());
+    /// // Passes Miller–Rabin for the first 13 prime bases.
+    /// assert!(!3317044064679887385961981_U128.is_prime_bpsw());
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_prime_bpsw(self) -> bool {
+        let ctx = match self.prime_precheck() {
+            Ok(ctx) => ctx,
+            Err(result) => return result,
+        };
+        miller_rabin_ctx(&ctx, Self::from(2)) && strong_lucas(&ctx)
+    }
+
+    /// Decides small values and values with small prime factors. Otherwise
+    /// returns the Montgomery context for the Miller–Rabin rounds.
+    fn prime_precheck(self) -> Result<Montgomery<BITS, LIMBS>, bool> {
+        if self.bit_len() <= 64 {
+            let limb = self.as_limbs().first().copied().unwrap_or(0);
+            return Err(algorithms::is_prime(limb));
+        }
+        let remainder = (self % Self::from(algorithms::SMALL_PRIMES)).as_limbs()[0];
+        if algorithms::gcd_u128(u128::from(remainder), u128::from(algorithms::SMALL_PRIMES)) != 1 {
+            return Err(false);
+        }
+        Montgomery::new(self).ok_or(false)
+    }
+}
+
+/// Residues of a moving candidate modulo small odd primes, used to skip
+/// candidates with small factors without any big integer divisions.
+struct Sieve {
+    primes:   Vec<u64>,
+    residues: Vec<u64>,
+}
+
+impl Sieve {
+    /// Upper bound on the sieving primes.
+    const LIMIT: u64 = 1 << 10;
+
+    /// Sieve by the odd primes less than `limit`. Candidates must stay larger
+    /// than `limit`, otherwise they are rejected for being a sieving prime.
+    fn new<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>, limit: u64) -> Self {
+        let primes: Vec<u64> = (3..limit).filter(|&p| algorithms::is_prime(p)).collect();
+        let residues = primes
+            .iter()
+            .map(|&p| (value % Uint::from(p)).as_limbs()[0])
+            .collect();
+        Self { primes, residues }
+    }
+
+    /// Sieve for a search starting at `value`. Values below $2^{64}$ are not
+    /// sieved as the exact test is fast, which also keeps all candidates away
+    /// from the sieving primes.
+    fn for_search<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) -> Self {
+        Self::new(value, if value.bit_len() > 64 { Self::LIMIT } else { 0 })
+    }
+
+    fn has_factor(&self) -> bool {
+        self.residues.contains(&0)
+    }
+
+    fn advance(&mut self, step: u64) {
+        for (residue, &p) in self.residues.iter_mut().zip(&self.primes) {
+            *residue = (*residue + step) % p;
+        }
+    }
+
+    fn retreat(&mut self, step: u64) {
+        for (residue, &p) in self.residues.iter_mut().zip(&self.primes) {
+            *residue = (*residue + p - step % p) % p;
+        }
+    }
+}
+
+/// Strong probable prime test of the modulus of `ctx` to `base`.
+fn miller_rabin_ctx<const BITS: usize, const LIMBS: usize>(
+    ctx: &Montgomery<BITS, LIMBS>,
+    base: Uint<BITS, LIMBS>,
+) -> bool {
+    let n = ctx.modulus();
+    let one = ctx.one();
+    let minus_one = n - one;
+
+    // Write n - 1 = d ⋅ 2^s with d odd.
+    let s = (n - Uint::from(1)).trailing_zeros();
+    let d = n >> s;
+
+    let mut x = ctx.pow(ctx.to_montgomery(base), d);
+    if x == Uint::ZERO || x == one || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = ctx.square(x);
+        if x == minus_one {
+            return true;
+        }
+        if x == one {
+            return false;
+        }
+    }
+    false
+}
+
+/// Strong Lucas probable prime test of the modulus of `ctx` using Selfridge's
+/// method A for the parameters.
+///
+/// The modulus must be larger than the candidates for $D$.
+///
+/// See <https://en.wikipedia.org/wiki/Lucas_pseudoprime#Strong_Lucas_pseudoprimes>
+#[allow(clippy::many_single_char_names)] // Follows the literature.
+fn strong_lucas<const BITS: usize, const LIMBS: usize>(ctx: &Montgomery<BITS, LIMBS>) -> bool {
+    let n = ctx.modulus();
+    // No suitable $D$ exists for squares.
+    if n.is_perfect_square() {
+        return false;
+    }
+
+    // Find the first $D$ in $5, -7, 9, -11, …$ with $(D/n) = -1$ and set
+    // $P = 1$ and $Q = (1 - D) / 4$.
+    let mut abs_d = 5_u64;
+    let mut negative = false;
+    let (d, q) = loop {
+        let abs = Uint::from(abs_d);
+        let d = if negative { n - abs } else { abs };
+        match d.jacobi(n) {
+            -1 if negative => break (d, Uint::from((abs_d + 1) / 4)),
+            -1 => break (d, n - Uint::from((abs_d - 1) / 4)),
+            // A proper factor as $|D| < n$.
+            0 => return false,
+            _ => {}
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+    let d = ctx.to_montgomery(d);
+    let q = ctx.to_montgomery(q);
+
+    let add = |a: Uint<BITS, LIMBS>, b| a.add_mod(b, n);
+    let sub = |a: Uint<BITS, LIMBS>, b| a.add_mod(n - b, n);
+    // Division by two commutes with the Montgomery form.
+    let half = |a: Uint<BITS, LIMBS>| {
+        if a.bit(0) {
+            // Computes $(a + n) / 2$ without overflow as both are odd.
+            (a >> 1) + (n >> 1) + Uint::from(1)
+        } else {
+            a >> 1
+        }
+    };
+
+    // Write $n + 1 = k ⋅ 2^s$ with $k$ odd, without overflowing $n + 1$.
+    let s = n.trailing_ones();
+    let k = (n >> s) + Uint::from(1);
+
+    // Compute $U_k$, $V_k$ and $Q^k$ using the binary method, starting from
+    // $U_1 = 1$, $V_1 = P$.
+    let mut u = ctx.one();
+    let mut v = ctx.one();
+    let mut q_k = q;
+    for i in (0..k.bit_len() - 1).rev() {
+        u = ctx.mul(u, v);
+        v = sub(ctx.square(v), add(q_k, q_k));
+        q_k = ctx.square(q_k);
+        if k.bit(i) {
+            (u, v) = (half(add(u, v)), half(add(ctx.mul(d, u), v)));
+            q_k = ctx.mul(q_k, q);
+        }
+    }
+
+    if u == Uint::ZERO || v == Uint::ZERO {
+        return true;
+    }
+    for _ in 1..s {
+        v = sub(ctx.square(v), add(q_k, q_k));
+        q_k = ctx.square(q_k);
+        if v == Uint::ZERO {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U1024, U128, U64},
+        const_for, nlimbs,
+    };
+    use core::cmp::min;
+    use proptest::{proptest, test_runner::Config};
+
+    #[test]
+    fn test_small() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: u64, base: u64)| {
+                let n = U::wrapping_from(n);
+                let limb = n.wrapping_to::<u64>();
+                assert_eq!(n.is_prime(0), algorithms::is_prime(limb));
+                if limb > 2 && limb % 2 == 1 {
+                    let expected = algorithms::miller_rabin(limb, base % limb);
+                    assert_eq!(n.miller_rabin(U::wrapping_from(base % limb)), expected);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_composite() {
+        const_for!(BITS in NON_ZERO if (BITS >= 128) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a in 3_u64.., b in 3_u64..)| {
+                let n = U::from(a | 1) * U::from(b | 1);
+                assert!(!n.is_prime(5));
+            });
+        });
+    }
+
+    #[test]
+    fn test_large() {
+        let mersenne = |p: usize| (U1024::from(1) << p) - U1024::from(1);
+        assert!(mersenne(521).is_prime(10));
+        assert!(mersenne(607).is_prime(10));
+        assert!(!mersenne(523).is_prime(10));
+        assert!(U128::from(u128::MAX >> 1).is_prime(10));
+
+        // Strong pseudoprimes to the first 12 and 13 prime bases.
+        let n = U128::from(318_665_857_834_031_151_167_461_u128);
+        assert!(n.is_prime(12));
+        assert!(!n.is_prime(13));
+        let n = U128::from(3_317_044_064_679_887_385_961_981_u128);
+        assert!(n.is_prime(13));
+        assert!(!n.is_prime(14));
+    }
+
+    #[test]
+    fn test_strong_lucas() {
+        type U = Uint<64, 1>;
+        let lucas = |n: u64| strong_lucas(&Montgomery::new(U::from(n)).unwrap());
+        let pseudoprimes = [5459, 5777, 10877, 16109, 18971];
+        for n in (101..20_000).step_by(2) {
+            let expected = algorithms::is_prime(n) || pseudoprimes.contains(&n);
+            assert_eq!(lucas(n), expected, "{n}");
+        }
+        // Strong pseudoprimes to base two.
+        assert!(!lucas(2047));
+        assert!(!lucas(3_215_031_751));
+    }
+
+    #[test]
+    fn test_bpsw() {
+        const_for!(BITS in NON_ZERO if (BITS >= 128) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut config = Config::default();
+            config.cases = min(config.cases, if BITS > 500 { 3 } else { 20 });
+            proptest!(&config, |(n: U)| {
+                let n = n | U::from(1);
+                assert_eq!(n.is_prime_bpsw(), n.is_prime(20));
+            });
+            proptest!(config, |(a in 3_u64.., b in 3_u64..)| {
+                let n = U::from(a | 1) * U::from(b | 1);
+                assert!(!n.is_prime_bpsw());
+            });
+        });
+        let mersenne = |p: usize| (U1024::from(1) << p) - U1024::from(1);
+        assert!(mersenne(521).is_prime_bpsw());
+        assert!(mersenne(607).is_prime_bpsw());
+        assert!(!mersenne(523).is_prime_bpsw());
+        assert!(U128::MAX.wrapping_shr(1).is_prime_bpsw());
+        assert!(!U128::from(318_665_857_834_031_151_167_461_u128).is_prime_bpsw());
+        assert!(!U128::from(3_317_044_064_679_887_385_961_981_u128).is_prime_bpsw());
+    }
+
+    #[test]
+    fn test_next_prime() {
+        assert_eq!(Uint::<0, 0>::ZERO.next_prime(), None);
+        assert_eq!(Uint::<1, 1>::ZERO.next_prime(), None);
+        assert_eq!(Uint::<1, 1>::MAX.previous_prime(), None);
+        const_for!(BITS in NON_ZERO if (BITS >= 2 && BITS <= 256) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut config = Config::default();
+            config.cases = min(config.cases, if BITS > 128 { 3 } else { 20 });
+            proptest!(&config, |(n: U)| {
+                // Compare against a search without sieving.
+                let expected = (1..).map_while(|i| n.checked_add(U::wrapping_from(i))).find(|m| m.is_prime_bpsw());
+                assert_eq!(n.next_prime(), expected);
+                let expected = (1..)
+                    .map_while(|i| n.checked_sub(U::wrapping_from(i)))
+                    .find(|m| m.is_prime_bpsw());
+                assert_eq!(n.previous_prime(), expected);
+            });
+        });
+        // Across the exact range boundary.
+        let below = U128::from(18_446_744_073_709_551_557_u128);
+        let above = U128::from(18_446_744_073_709_551_629_u128);
+        assert_eq!(below.next_prime(), Some(above));
+        assert_eq!(above.previous_prime(), Some(below));
+
+        // Two is passed to the predicate like any other candidate.
+        let mut tested = Vec::new();
+        assert_eq!(
+            U64::ZERO.next_prime_by(|n| {
+                tested.push(n);
+                n != U64::from(2)
+            }),
+            Some(U64::from(3))
+        );
+        assert_eq!(tested, [U64::from(2), U64::from(3)]);
+        assert_eq!(U64::from(3).previous_prime_by(|_| false), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random_prime() {
+        let mut rng = rand::thread_rng();
+        const_for!(BITS in NON_ZERO if (BITS >= 3 && BITS <= 512) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for bits in [2, 3, 4, 10, 64, 65, 128, 256].into_iter().filter(|&bits| bits <= BITS) {
+                let p = U::random_prime(&mut rng, bits);
+                assert_eq!(p.bit_len(), bits);
+                assert!(p.is_prime(20));
+                if (3..=128).contains(&bits) {
+                    let p = U::random_safe_prime(&mut rng, bits);
+                    assert_eq!(p.bit_len(), bits);
+                    assert!(p.is_prime(20));
+                    assert!((p >> 1_usize).is_prime(20));
+                }
+            }
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_probable_prime() {
+        let mut rng = rand::thread_rng();
+        let n = U128::from(3_317_044_064_679_887_385_961_981_u128);
+        assert!(!n.is_probable_prime(20, &mut rng));
+        assert!(U128::from(u128::MAX >> 1).is_probable_prime(20, &mut rng));
+        assert!(U128::from(65537).is_probable_prime(0, &mut rng));
+    }
+}
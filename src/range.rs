@@ -0,0 +1,266 @@
+use crate::Uint;
+use core::{
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+};
+
+/// An iterator over a range of [`Uint`] values.
+///
+/// This is the stable alternative to iterating over a `Range<Uint>` directly,
+/// which requires the unstable [`Step`](core::iter::Step) trait (implemented
+/// with the `nightly` feature). The [`nth`](Iterator::nth) methods are
+/// constant time, so [`step_by`](Iterator::step_by) is efficient.
+///
+/// ```
+/// # use ruint::{uint, UintRange, aliases::U256};
+/// # uint!{
+/// let mut sum = 0_U256;
+/// for x in UintRange::new(0_U256..1000_U256) {
+///     sum += x;
+/// }
+/// assert_eq!(sum, 499500_U256);
+///
+/// let odd: Vec<_> = UintRange::new(1_U256..=9_U256).step_by(2).collect();
+/// assert_eq!(odd, [1_U256, 3_U256, 5_U256, 7_U256, 9_U256]);
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "alloc", derive(Debug))]
+pub struct UintRange<const BITS: usize, const LIMBS: usize> {
+    /// Next value to yield from the front.
+    front: Uint<BITS, LIMBS>,
+    /// Next value to yield from the back (inclusive).
+    back:  Uint<BITS, LIMBS>,
+    /// Whether the range is empty. Needed because `front..=back` can not
+    /// represent an empty range.
+    empty: bool,
+}
+
+impl<const BITS: usize, const LIMBS: usize> UintRange<BITS, LIMBS> {
+    /// Creates an iterator over the values in `range`.
+    ///
+    /// Unbounded ends are taken to be [`Uint::ZERO`] and [`Uint::MAX`].
+    #[inline]
+    #[must_use]
+    pub fn new<R: RangeBounds<Uint<BITS, LIMBS>>>(range: R) -> Self {
+        let front = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => (start != Uint::MAX).then(|| start + Uint::from(1)),
+            Bound::Unbounded => Some(Uint::ZERO),
+        };
+        let back = match range.end_bound() {
+            Bound::Included(&end) => Some(end),
+            Bound::Excluded(&end) => (end != Uint::ZERO).then(|| end - Uint::from(1)),
+            Bound::Unbounded => Some(Uint::MAX),
+        };
+        match (front, back) {
+            (Some(front), Some(back)) if front <= back => Self {
+                front,
+                back,
+                empty: false,
+            },
+            _ => Self {
+                front: Uint::ZERO,
+                back:  Uint::ZERO,
+                empty: true,
+            },
+        }
+    }
+
+    /// Returns the number of remaining values, or `None` if it does not fit a
+    /// `usize`.
+    fn len(&self) -> Option<usize> {
+        if self.empty {
+            return Some(0);
+        }
+        usize::try_from(self.back - self.front)
+            .ok()
+            .and_then(|n| n.checked_add(1))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Iterator for UintRange<BITS, LIMBS> {
+    type Item = Uint<BITS, LIMBS>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.empty {
+            return None;
+        }
+        match Uint::try_from(n)
+            .ok()
+            .and_then(|n| self.front.checked_add(n))
+        {
+            Some(value) if value <= self.back => {
+                if value == self.back {
+                    self.empty = true;
+                } else {
+                    self.front = value + Uint::from(1);
+                }
+                Some(value)
+            }
+            _ => {
+                self.empty = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.len().map_or((usize::MAX, None), |n| (n, Some(n)))
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    #[inline]
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+
+    #[inline]
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> DoubleEndedIterator for UintRange<BITS, LIMBS> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if self.empty {
+            return None;
+        }
+        match Uint::try_from(n)
+            .ok()
+            .and_then(|n| self.back.checked_sub(n))
+        {
+            Some(value) if value >= self.front => {
+                if value == self.front {
+                    self.empty = true;
+                } else {
+                    self.back = value - Uint::from(1);
+                }
+                Some(value)
+            }
+            _ => {
+                self.empty = true;
+                None
+            }
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FusedIterator for UintRange<BITS, LIMBS> {}
+
+#[cfg(feature = "nightly")]
+impl<const BITS: usize, const LIMBS: usize> core::iter::Step for Uint<BITS, LIMBS> {
+    #[inline]
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        if start > end {
+            return (0, None);
+        }
+        usize::try_from(*end - *start).map_or((usize::MAX, None), |n| (n, Some(n)))
+    }
+
+    #[inline]
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_add(Self::try_from(count).ok()?)
+    }
+
+    #[inline]
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_sub(Self::try_from(count).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_empty() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(UintRange::new(U::ZERO..U::ZERO).next(), None);
+            assert_eq!(UintRange::new(U::MAX..U::MAX).next_back(), None);
+            assert_eq!(UintRange::new((Bound::Excluded(U::MAX), Bound::Unbounded)).next(), None);
+            assert_eq!(UintRange::new(U::MAX..=U::MAX).collect::<Vec<_>>(), vec![U::MAX]);
+            assert_eq!(UintRange::new(U::ZERO..=U::ZERO).next_back(), Some(U::ZERO));
+        });
+    }
+
+    #[test]
+    fn test_range() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(start: U, len in 0_u64..100, step in 1_usize..10)| {
+                let end = start.saturating_add(U::wrapping_from(len));
+                let expected = {
+                    let mut values = vec![];
+                    let mut x = start;
+                    while x < end {
+                        values.push(x);
+                        x += U::from(1);
+                    }
+                    values
+                };
+                let range = UintRange::new(start..end);
+                assert_eq!(range.size_hint(), (expected.len(), Some(expected.len())));
+                assert_eq!(range.clone().collect::<Vec<_>>(), expected);
+                assert_eq!(range.clone().rev().collect::<Vec<_>>(), expected.iter().rev().copied().collect::<Vec<_>>());
+                assert_eq!(range.clone().step_by(step).collect::<Vec<_>>(), expected.iter().step_by(step).copied().collect::<Vec<_>>());
+                assert_eq!(range.clone().last(), expected.last().copied());
+                let mut range = range;
+                let mut expected = expected.into_iter();
+                while let Some(value) = range.next() {
+                    assert_eq!(Some(value), expected.next());
+                    assert_eq!(range.next_back(), expected.next_back());
+                }
+                assert_eq!(expected.next(), None);
+                assert_eq!(range.next(), None);
+            });
+        });
+    }
+
+    #[test]
+    fn test_large() {
+        const_for!(BITS in NON_ZERO if (BITS >= 128) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let range = UintRange::new(..);
+            assert_eq!(range.size_hint(), (usize::MAX, None));
+            assert_eq!(range.clone().nth(usize::MAX), Some(U::from(usize::MAX)));
+            assert_eq!(range.clone().nth_back(1), Some(U::MAX - U::from(1)));
+            assert_eq!(range.max(), Some(U::MAX));
+        });
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_step() {
+        const_for!(BITS in NON_ZERO if (BITS >= 8) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let range = U::from(3)..U::from(20);
+            assert!(range.clone().step_by(3).eq(UintRange::new(range).step_by(3)));
+            assert_eq!((U::MAX..=U::MAX).count(), 1);
+        });
+    }
+}
@@ -1,9 +1,184 @@
-#![cfg(feature = "std")]
-
 use crate::Uint;
-use core::cmp::{min, Ordering};
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes the floor of the square root of the number.
+    ///
+    /// $$
+    /// \floor{\sqrt{\mathtt{self}}}
+    /// $$
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0_U64.sqrt(), 0_U64);
+    /// assert_eq!(99_U64.sqrt(), 9_U64);
+    /// assert_eq!(100_U64.sqrt(), 10_U64);
+    /// assert_eq!(3_U2.sqrt(), 1_U2);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        self.isqrt().0
+    }
+
+    /// Computes the floor of the square root of the number and the
+    /// remainder.
+    ///
+    /// Returns `(root, self - root * root)`.
+    ///
+    /// # Algorithm
+    ///
+    /// Uses the divide and conquer Karatsuba square root from [Zim99]
+    /// (Algorithm 1.12 in [BZ10]) for large values and Newton's method
+    /// for values of up to 128 bits.
+    ///
+    /// [Zim99]: https://inria.hal.science/inria-00072854
+    /// [BZ10]: https://members.loria.fr/PZimmermann/mca/mca-cup-0.5.9.pdf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(99_U64.isqrt(), (9_U64, 18_U64));
+    /// assert_eq!(100_U64.isqrt(), (10_U64, 0_U64));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn isqrt(self) -> (Self, Self) {
+        let bits = self.bit_len();
+        if bits <= 128 {
+            let root = self.sqrt_newton();
+            return (root, self - root * root);
+        }
+
+        // Split `self = hi ⋅ 2^{2l} + a1 ⋅ 2^l + a0` with `a1, a0 < 2^l`.
+        // This makes `hi ≥ 2^{2l - 2}`, so no normalization is needed and
+        // all intermediate values fit in `Self`.
+        let l = bits / 4;
+        let mask = Self::MAX >> (BITS - l);
+        let a0 = self & mask;
+        let a1 = (self >> l) & mask;
+        let (root, rem) = (self >> (2 * l)).isqrt();
+
+        let (q, u) = ((rem << l) + a1).div_rem(root << 1);
+        let mut root = (root << l) + q;
+        let t = (u << l) + a0;
+        let q2 = q * q;
+        let rem = if t >= q2 {
+            t - q2
+        } else {
+            // The estimate is at most one too large.
+            root -= Self::from(1);
+            t + (root << 1) + Self::from(1) - q2
+        };
+        (root, rem)
+    }
+
+    /// Computes the floor of the square root using Newton's method.
+    fn sqrt_newton(self) -> Self {
+        // Handle zero and one (including BITS < 2).
+        if self.bit_len() < 2 {
+            return self;
+        }
+
+        // Newton's method converges monotonically downwards from an initial
+        // guess above the root. It has converged once `self / result` is no
+        // longer less than `result`, which also means the sum below can not
+        // overflow.
+        let mut result = Self::from(1) << ((self.bit_len() + 1) / 2);
+        loop {
+            let division = self / result;
+            if division >= result {
+                break result;
+            }
+            result = (result + division) >> 1;
+        }
+    }
+
+    /// Computes the floor of the cube root of the number.
+    ///
+    /// $$
+    /// \floor{\sqrt[3]{\mathtt{self}}}
+    /// $$
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(26_U64.cbrt(), 2_U64);
+    /// assert_eq!(27_U64.cbrt(), 3_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cbrt(self) -> Self {
+        self.root(3)
+    }
+
+    /// Computes the floor of the `degree`-th root of the number.
+    ///
+    /// $$
+    /// \floor{\sqrt[\mathtt{degree}]{\mathtt{self}}}
+    /// $$
+    ///
+    /// Returns `None` if `degree` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(27_U64.checked_root(3), Some(3_U64));
+    /// assert_eq!(27_U64.checked_root(0), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_root(self, degree: usize) -> Option<Self> {
+        if degree == 0 {
+            return None;
+        }
+        Some(self.root(degree))
+    }
+
+    /// Computes the floor of the `degree`-th root of the number and the
+    /// remainder.
+    ///
+    /// Returns `(root, self - root.pow(degree))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(30_U64.root_rem(3), (3_U64, 3_U64));
+    /// assert_eq!(64_U64.root_rem(2), (8_U64, 0_U64));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn root_rem(self, degree: usize) -> (Self, Self) {
+        let root = self.root(degree);
+        // Roots of zero and one are handled separately as `degree` may not fit
+        // in `Self`, but then `degree < BITS` holds.
+        let power = if root <= Self::from(1) {
+            root
+        } else {
+            root.pow(Self::from(degree))
+        };
+        (root, self - power)
+    }
+
     /// Computes the floor of the `degree`-th root of the number.
     ///
     /// $$
@@ -45,49 +220,188 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             return self;
         }
 
-        // Create a first guess.
-        // Root should be less than the value, so approx_pow2 should always succeed.
-        #[allow(clippy::cast_precision_loss)] // Approximation is good enough.
-        #[allow(clippy::cast_sign_loss)] // Result should be positive.
-        let mut result = Self::approx_pow2(self.approx_log2() / degree as f64).unwrap();
+        // Power of two degrees reduce to repeated square roots, as
+        // $\floor{\sqrt{\floor{\sqrt{x}}}} = \floor{\sqrt[4]{x}}$.
+        if degree.is_power_of_two() {
+            let mut result = self;
+            for _ in 0..degree.trailing_zeros() {
+                result = result.sqrt();
+            }
+            return result;
+        }
+
+        // The root has at most `bits` bits, as $2^{\mathtt{bits}}$ raised to
+        // `degree` exceeds `self`.
+        let bits = (self.bit_len() + degree - 1) / degree;
 
-        let deg_m1 = Self::from(degree - 1);
+        // For high degrees the root is small and Newton's method converges
+        // slowly from above (roughly by a factor `(degree - 1) / degree` per
+        // step). Instead determine the root bit by bit, which takes at most
+        // `bits` iterations.
+        if bits <= degree {
+            let degree = Self::from(degree);
+            let mut result = Self::ZERO;
+            for i in (0..bits).rev() {
+                let mut candidate = result;
+                candidate.set_bit(i, true);
+                if candidate
+                    .checked_pow(degree)
+                    .map_or(false, |power| power <= self)
+                {
+                    result = candidate;
+                }
+            }
+            return result;
+        }
 
-        // Iterate using Newton's method
+        // Iterate using Newton's method starting from the upper bound
+        // $2^{\mathtt{bits}}$. From above the iteration decreases monotonically
+        // until it reaches the root.
         // See <https://en.wikipedia.org/wiki/Integer_square_root#Algorithm_using_Newton's_method>
         // See <https://gmplib.org/manual/Nth-Root-Algorithm>
-        let mut decreasing = false;
+        let deg_m1 = degree as u64 - 1;
+        let mut result = Self::from(1) << bits;
         loop {
-            // OPT: This could benefit from single-limb multiplication
-            // and division.
-            //
-            // OPT: The division can be turned into bit-shifts when the degree is a power of
-            // two.
             let division = result
-                .checked_pow(deg_m1)
+                .checked_pow(Self::from(deg_m1))
                 .map_or(Self::ZERO, |power| self / power);
-            let iter = (division + deg_m1 * result) / Self::from(degree);
-            match (decreasing, iter.cmp(&result)) {
-                // Stop when we hit fix point or stop decreasing.
-                (_, Ordering::Equal) | (true, Ordering::Greater) => break result,
-
-                // When `degree` is high and the initial guess is less than or equal to the
-                // (small) true result, it takes a long time to converge. Example:
-                // 0x215f07147d573ef203e1f268ab1516d3f294619db820c5dfd0b334e4d06320b7_U256.
-                // root(196) takes 5918 iterations to converge from the initial guess of `2`.
-                // to the final result of `2`. This is because after the first iteration
-                // it jumps to `1533576856264507`. To fix this we cap the increase at `2x`.
-                // Once `result` exceeds the true result, it will converge downwards.
-                (false, Ordering::Greater) => result = min(iter, result.saturating_shl(1)),
-
-                // Converging downwards.
-                (_, Ordering::Less) => {
-                    decreasing = true;
-                    result = iter;
+            let iter = (division + result.mul_limb(deg_m1).0)
+                .div_rem_limb(degree as u64)
+                .0;
+            if iter >= result {
+                break result;
+            }
+            result = iter;
+        }
+    }
+
+    /// Returns `true` if the number is a perfect square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert!(0_U64.is_perfect_square());
+    /// assert!(49_U64.is_perfect_square());
+    /// assert!(!50_U64.is_perfect_square());
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_perfect_square(self) -> bool {
+        // Bitmask of the 12 quadratic residues modulo 64.
+        const SQUARES_MOD_64: u64 = 0x0202_0212_0203_0213;
+        if BITS == 0 {
+            return true;
+        }
+        if (SQUARES_MOD_64 >> (self.limbs[0] & 63)) & 1 == 0 {
+            return false;
+        }
+        self.isqrt().1 == Self::ZERO
+    }
+
+    /// Decomposes the number as `base.pow(exponent)` with the smallest
+    /// possible `base` (and therefore the largest `exponent`).
+    ///
+    /// Returns `None` if the number is not a perfect power, i.e. there is no
+    /// such decomposition with `exponent > 1`. This includes zero and one,
+    /// which have no unique decomposition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(64_U64.is_perfect_power(), Some((2_U64, 6)));
+    /// assert_eq!(36_U64.is_perfect_power(), Some((6_U64, 2)));
+    /// assert_eq!(12_U64.is_perfect_power(), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_perfect_power(self) -> Option<(Self, usize)> {
+        if self.bit_len() < 2 {
+            return None;
+        }
+
+        // Repeatedly take prime roots. Composite exponents are found as
+        // products of prime ones, and a `base ≥ 2` can only be a `prime`-th
+        // power if `prime` is less than its bit length.
+        let mut base = self;
+        let mut exponent = 1;
+        let mut prime = 2;
+        while prime < base.bit_len() {
+            if is_prime_small(prime as u64) && base.is_power_residue(prime) {
+                let (root, rem) = base.root_rem(prime);
+                if rem == Self::ZERO {
+                    base = root;
+                    exponent *= prime;
+                    continue;
+                }
+            }
+            prime += 1;
+        }
+        (exponent > 1).then_some((base, exponent))
+    }
+
+    /// Cheap filter for [`is_perfect_power`](Self::is_perfect_power) that
+    /// returns `false` if the number is certainly not a `degree`-th power.
+    ///
+    /// For primes $q ≡ 1 \pmod{\mathtt{degree}}$ the `degree`-th powers
+    /// modulo $q$ are the residues $r$ with $r^{(q - 1) / \mathtt{degree}} \in
+    /// \set{0, 1}$.
+    fn is_power_residue(self, degree: usize) -> bool {
+        let degree = degree as u64;
+        let mut checked = 0;
+        let mut q = degree + 1;
+        while checked < 3 && q < 1 << 32 {
+            if is_prime_small(q) {
+                #[allow(clippy::cast_possible_truncation)] // Less than `q`.
+                let residue = self.limbs.iter().rev().fold(0, |r, &limb| {
+                    ((u128::from(r) << 64 | u128::from(limb)) % u128::from(q)) as u64
+                });
+                if pow_mod_small(residue, (q - 1) / degree, q) > 1 {
+                    return false;
                 }
+                checked += 1;
             }
+            q += degree;
+        }
+        true
+    }
+}
+
+/// Trial division primality test for small numbers.
+const fn is_prime_small(n: u64) -> bool {
+    if n < 4 {
+        return n >= 2;
+    }
+    if n % 2 == 0 {
+        return false;
+    }
+    let mut d = 3;
+    while d * d <= n {
+        if n % d == 0 {
+            return false;
+        }
+        d += 2;
+    }
+    true
+}
+
+/// Computes `base^exp mod modulus` for `modulus < 2^32`.
+const fn pow_mod_small(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
         }
+        base = base * base % modulus;
+        exp >>= 1;
     }
+    result
 }
 
 #[cfg(test)]
@@ -136,4 +450,101 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_root_high_degree() {
+        // Converged slowly with a Newton iteration started below the root.
+        let value = uint!(0x215f07147d573ef203e1f268ab1516d3f294619db820c5dfd0b334e4d06320b7_U256);
+        assert_eq!(value.root(196), uint!(2_U256));
+        assert_eq!(value.root(3), uint!(0x147151bffcdaabe787db87_U256));
+        assert_eq!(value.root(5), uint!(0x61d2c451193f7_U256));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let root = value.sqrt();
+                assert!(root * root <= value);
+                let upper = root
+                    .checked_add(U::from(1))
+                    .and_then(|n| n.checked_mul(n));
+                if let Some(upper) = upper {
+                   assert!(value < upper);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_isqrt() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, shift: usize)| {
+                // Also cover values with fewer bits than `BITS`.
+                let value = value >> (shift % (64 * LIMBS));
+                let (root, rem) = value.isqrt();
+                assert_eq!(root, value.sqrt_newton());
+                assert_eq!(rem, value - root * root);
+            });
+        });
+    }
+
+    #[test]
+    fn test_perfect_power_small() {
+        type U = Uint<64, 1>;
+        for n in 0_u64..5000 {
+            let value = U::from(n);
+            // Reference: the largest exponent with an exact root.
+            let expected = (2..64)
+                .rev()
+                .map(|k| (value.root(k), k))
+                .find(|&(root, k)| root.pow(U::from(k)) == value);
+            let expected = expected.filter(|_| n > 1);
+            assert_eq!(value.is_perfect_power(), expected, "{n}");
+            assert_eq!(
+                value.is_perfect_square(),
+                value.sqrt() * value.sqrt() == value
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)] // From macro.
+    fn test_perfect_power() {
+        const_for!(BITS in NON_ZERO if (BITS >= 8) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(base: u64, exponent in 2_usize..=BITS)| {
+                let base = U::from(base % 1000 + 2);
+                if let Some(value) = base.checked_pow(U::from(exponent)) {
+                    let (root, power) = value.is_perfect_power().unwrap();
+                    assert!(root <= base);
+                    assert_eq!(power % exponent, 0);
+                    assert_eq!(root.pow(U::from(power)), value);
+                    assert_eq!(value.is_perfect_square(), power % 2 == 0);
+                }
+            });
+        });
+    }
+
+    #[test]
+    #[allow(clippy::absurd_extreme_comparisons)] // From macro.
+    #[allow(clippy::reversed_empty_ranges)] // From macro.
+    fn test_root_rem() {
+        const_for!(BITS in SIZES if (BITS > 3) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, degree in 1_usize..=2 * BITS)| {
+                let (root, rem) = value.root_rem(degree);
+                assert_eq!(value.checked_root(degree), Some(root));
+                assert_eq!(root, value.root(degree));
+                assert_eq!(rem, value - root.pow(U::from(degree)));
+            });
+            assert_eq!(U::from(5).checked_root(0), None);
+        });
+    }
 }
@@ -63,6 +63,58 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
         Some(Self::from(1) << exp)
     }
+
+    /// Returns the smallest power of two greater than or equal to `self`,
+    /// or zero if it does not fit the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(3_U64.wrapping_next_power_of_two(), 4_U64);
+    /// assert_eq!(U64::MAX.wrapping_next_power_of_two(), 0_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn wrapping_next_power_of_two(self) -> Self {
+        self.checked_next_power_of_two().unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the largest power of two less than or equal to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn prev_power_of_two(self) -> Self {
+        self.checked_prev_power_of_two()
+            .expect("prev_power_of_two of zero")
+    }
+
+    /// Returns the largest power of two less than or equal to `self`, or
+    /// [`None`] if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(0_U64.checked_prev_power_of_two(), None);
+    /// assert_eq!(1_U64.checked_prev_power_of_two(), Some(1_U64));
+    /// assert_eq!(6_U64.checked_prev_power_of_two(), Some(4_U64));
+    /// assert_eq!(U64::MAX.checked_prev_power_of_two(), Some(1_U64 << 63));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_prev_power_of_two(self) -> Option<Self> {
+        let exp = self.bit_len().checked_sub(1)?;
+        Some(Self::from(1) << exp)
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
@@ -75,9 +127,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// overflow.
     #[inline]
     #[must_use]
+    #[track_caller]
     pub fn next_multiple_of(self, rhs: Self) -> Self {
-        self.checked_next_multiple_of(rhs).unwrap();
-        todo!()
+        self.checked_next_multiple_of(rhs).unwrap()
     }
 
     /// Calculates the smallest value greater than or equal to `self` that is a
@@ -120,3 +172,191 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         q.checked_mul(rhs)
     }
 }
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns `true` if `self` is an integer multiple of `rhs`.
+    ///
+    /// Like [`u64::is_multiple_of`], zero is only a multiple of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U64};
+    /// # uint!{
+    /// assert!(24_U64.is_multiple_of(8_U64));
+    /// assert!(!23_U64.is_multiple_of(8_U64));
+    /// assert!(0_U64.is_multiple_of(0_U64));
+    /// assert!(!1_U64.is_multiple_of(0_U64));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_multiple_of(self, rhs: Self) -> bool {
+        self.is_divisible_by(rhs)
+    }
+
+    /// Returns `true` if `rhs` divides `self`.
+    ///
+    /// This is the same as [`Uint::is_multiple_of`], but avoids computing the
+    /// quotient where possible: powers of two are checked using the trailing
+    /// zeros and single limb divisors only compute the remainder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert!(1_000_000_U256.is_divisible_by(64_U256));
+    /// assert!(1_000_000_U256.is_divisible_by(15625_U256));
+    /// assert!(!1_000_000_U256.is_divisible_by(3_U256));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_divisible_by(&self, rhs: Self) -> bool {
+        if rhs == Self::ZERO {
+            return *self == Self::ZERO;
+        }
+        let twos = rhs.trailing_zeros();
+        if !self.divisible_by_pow2(twos) {
+            return false;
+        }
+        if rhs.bit_len() <= 64 {
+            return self.rem_limb(rhs.limbs[0] >> twos) == 0;
+        }
+        *self % rhs == Self::ZERO
+    }
+
+    /// Returns `true` if $2^{\mathtt{k}}$ divides `self`.
+    ///
+    /// For `k >= BITS` only zero is divisible.
+    #[inline]
+    #[must_use]
+    pub fn divisible_by_pow2(&self, k: usize) -> bool {
+        *self == Self::ZERO || self.trailing_zeros() >= k
+    }
+
+    /// Returns `true` if $10^{\mathtt{k}}$ divides `self`.
+    ///
+    /// If $10^{\mathtt{k}}$ does not fit `Self` only zero is divisible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U256};
+    /// # uint!{
+    /// assert!(1_000_000_U256.divisible_by_pow10(6));
+    /// assert!(!1_000_000_U256.divisible_by_pow10(7));
+    /// assert!(0_U256.divisible_by_pow10(100));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn divisible_by_pow10(&self, k: usize) -> bool {
+        // 5^27 is the largest power of five that fits a limb.
+        const MAX_EXP: usize = 27;
+        // This also rejects all non-zero values if `k >= BITS`.
+        if !self.divisible_by_pow2(k) {
+            return false;
+        }
+        if *self == Self::ZERO {
+            return true;
+        }
+        #[allow(clippy::cast_possible_truncation)] // Intentional
+        if k <= MAX_EXP {
+            return self.rem_limb(5_u64.pow(k as u32)) == 0;
+        }
+        // Cheap rejection of most values before the full division.
+        #[allow(clippy::cast_possible_truncation)] // Intentional
+        if self.rem_limb(5_u64.pow(MAX_EXP as u32)) != 0 {
+            return false;
+        }
+        // `k < BITS` so the conversion does not overflow.
+        Self::from(5)
+            .checked_pow(Self::from(k))
+            .map_or(false, |power| *self % power == Self::ZERO)
+    }
+
+    /// Computes `self % d` for a single non-zero limb.
+    #[must_use]
+    pub(crate) fn rem_limb(&self, d: u64) -> u64 {
+        self.div_rem_limb(d).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_power_of_two() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U)| {
+                assert_eq!(n.is_power_of_two(), n != U::ZERO && n & (n - U::from(1)) == U::ZERO);
+                if let Some(p) = n.checked_next_power_of_two() {
+                    assert!(p.is_power_of_two() && p >= n);
+                    assert!(p == U::from(1) || p >> 1_usize < n);
+                    assert_eq!(n.next_power_of_two(), p);
+                    assert_eq!(n.wrapping_next_power_of_two(), p);
+                } else {
+                    assert!(BITS == 0 || n > U::from(1) << (BITS - 1));
+                    assert_eq!(n.wrapping_next_power_of_two(), U::ZERO);
+                }
+                if let Some(p) = n.checked_prev_power_of_two() {
+                    assert!(p.is_power_of_two() && p <= n && n >> 1_usize < p);
+                    assert_eq!(n.prev_power_of_two(), p);
+                } else {
+                    assert_eq!(n, U::ZERO);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_next_multiple_of() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U, shift: usize)| {
+                let d = (d >> (shift % (64 * LIMBS))).max(U::from(1));
+                match n.checked_next_multiple_of(d) {
+                    Some(m) => {
+                        assert_eq!(n.next_multiple_of(d), m);
+                        assert!(m >= n && m - n < d);
+                        assert!(m.is_multiple_of(d));
+                    }
+                    None => assert!(n > U::MAX - (U::MAX % d)),
+                }
+                assert_eq!(n.is_multiple_of(d), n % d == U::ZERO);
+            });
+        });
+    }
+
+    #[test]
+    fn test_is_divisible_by() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let wide = |x: U| Uint::<8192, 128>::from(x);
+            proptest!(|(n: U, d: U, shift: usize, limb in 1_u64.., k in 0_usize..80)| {
+                let d = d >> (shift % (64 * LIMBS + 2));
+                let expected = if d == U::ZERO { n == U::ZERO } else { n % d == U::ZERO };
+                assert_eq!(n.is_divisible_by(d), expected);
+                // Also test values that are actually divisible.
+                if d != U::ZERO {
+                    let m = n - n % d;
+                    assert!(m.is_divisible_by(d));
+                }
+                assert_eq!(Uint::from(n.rem_limb(limb)), wide(n) % Uint::from(limb));
+                let two = Uint::from(2).pow(Uint::from(k));
+                assert_eq!(n.divisible_by_pow2(k), wide(n) % two == Uint::ZERO);
+                let ten = Uint::from(10).pow(Uint::from(k));
+                assert_eq!(n.divisible_by_pow10(k), wide(n) % ten == Uint::ZERO);
+            });
+        });
+    }
+}
@@ -3,6 +3,9 @@
 use crate::{base_convert::BaseConvertError, Uint};
 use core::{fmt, str::FromStr};
 
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
 /// Error for [`from_str_radix`](Uint::from_str_radix).
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ParseError {
@@ -44,13 +47,270 @@ impl fmt::Display for ParseError {
     }
 }
 
+/// Options for [`Uint::from_str_with`].
+///
+/// The default only accepts decimal digits. Each option enables more syntax:
+///
+/// ```
+/// # use ruint::{aliases::U256, ParseOptions};
+/// let options = ParseOptions::new()
+///     .prefixes(true)
+///     .separators(true)
+///     .plus_sign(true);
+/// let value = U256::from_str_with("+0xdead_beef", &options).unwrap();
+/// assert_eq!(value, U256::from(0xdead_beef_u64));
+/// assert!(U256::from_str_with("0xdeadbeef", &ParseOptions::new()).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    radix:      u64,
+    prefixes:   bool,
+    separators: bool,
+    plus_sign:  bool,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// Options accepting only decimal digits.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            radix:      10,
+            prefixes:   false,
+            separators: false,
+            plus_sign:  false,
+        }
+    }
+
+    /// Sets the radix used when there is no prefix, 10 by default. Digits are
+    /// `0`—`9` and `a`—`z` in either case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is less than 2 or larger than 36.
+    #[inline]
+    #[must_use]
+    pub const fn radix(self, radix: u64) -> Self {
+        assert!(radix >= 2 && radix <= 36, "radix must be 2 to 36");
+        Self { radix, ..self }
+    }
+
+    /// Accepts a `0x`, `0o` or `0b` prefix in either case, which overrides
+    /// the radix.
+    #[inline]
+    #[must_use]
+    pub const fn prefixes(self, prefixes: bool) -> Self {
+        Self { prefixes, ..self }
+    }
+
+    /// Accepts `_` separators between two digits, e.g. `1_000_000`.
+    #[inline]
+    #[must_use]
+    pub const fn separators(self, separators: bool) -> Self {
+        Self { separators, ..self }
+    }
+
+    /// Accepts a leading `+`.
+    #[inline]
+    #[must_use]
+    pub const fn plus_sign(self, plus_sign: bool) -> Self {
+        Self { plus_sign, ..self }
+    }
+}
+
+/// Parse error with the byte offset in the input where the problem was found.
+///
+/// Returned by [`from_str_with`](Uint::from_str_with),
+/// [`from_str_scientific`](Uint::from_str_scientific) and
+/// [`from_decimal_str_scaled`](Uint::from_decimal_str_scaled).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseErrorAt {
+    /// Byte offset of the problem.
+    pub offset: usize,
+
+    /// The problem.
+    pub kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseErrorAt`].
+///
+/// More kinds may be added as new syntax is supported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// There are no digits. The offset is where they were expected.
+    Empty,
+
+    /// Invalid digit, or a sign or prefix that is not enabled.
+    InvalidDigit(char),
+
+    /// A `_` that is not between two digits.
+    InvalidSeparator,
+
+    /// The value is too large. The offset is the start of the digits.
+    Overflow,
+
+    /// The value has a fractional part, or more fractional digits than the
+    /// scale allows.
+    NotAnInteger,
+
+    /// A suffix that is not one of the supported SI or binary suffixes.
+    UnknownSuffix,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseErrorAt {}
+
+impl fmt::Display for ParseErrorAt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => f.write_str("no digits")?,
+            ParseErrorKind::InvalidDigit(c) => write!(f, "invalid digit {c:?}")?,
+            ParseErrorKind::InvalidSeparator => f.write_str("separator not between two digits")?,
+            ParseErrorKind::Overflow => {
+                f.write_str("the value is too large to fit the target type")?;
+            }
+            ParseErrorKind::NotAnInteger => f.write_str("the value is not an integer")?,
+            ParseErrorKind::UnknownSuffix => f.write_str(
+                "unknown suffix, expected one of k, M, G, T, P, E, Ki, Mi, Gi, Ti, Pi or Ei",
+            )?,
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+/// The digits used by [`Uint::from_str_radix_with`] and
+/// [`Uint::to_string_radix_with`].
+///
+/// The digit with value `i` is the `i`-th character, so an alphabet with `n`
+/// characters supports bases up to `n`.
+///
+/// ```
+/// # use ruint::{aliases::U64, Alphabet};
+/// const DNA: Alphabet = Alphabet::new("ACGT").case_insensitive();
+/// let value = U64::from_str_radix_with("gattaca", 4, &DNA).unwrap();
+/// assert_eq!(value.to_string_radix_with(4, &DNA), "GATTACA");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alphabet<'a> {
+    digits:           &'a [u8],
+    case_insensitive: bool,
+}
+
+impl<'a> Alphabet<'a> {
+    /// `0`—`9`, `a`—`z`, case insensitive.
+    pub const BASE36: Alphabet<'static> =
+        Alphabet::new("0123456789abcdefghijklmnopqrstuvwxyz").case_insensitive();
+
+    /// The standard base64 alphabet `A`—`Z`, `a`—`z`, `0`—`9`, `+`, `/`.
+    pub const BASE64: Alphabet<'static> =
+        Alphabet::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
+
+    /// The URL safe base64 alphabet, with `-` and `_` for the last two digits.
+    pub const BASE64_URL: Alphabet<'static> =
+        Alphabet::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+
+    /// The Bitcoin base58 alphabet, which leaves out `0`, `O`, `I` and `l`.
+    pub const BASE58: Alphabet<'static> =
+        Alphabet::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    /// Creates an alphabet from its digits, in order of value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than 2 or more than 64 digits, or if the
+    /// digits are not distinct ASCII characters.
+    #[must_use]
+    pub const fn new(digits: &'a str) -> Self {
+        let digits = digits.as_bytes();
+        assert!(
+            digits.len() >= 2 && digits.len() <= 64,
+            "alphabet must have 2 to 64 digits"
+        );
+        let alphabet = Self {
+            digits,
+            case_insensitive: false,
+        };
+        alphabet.validate();
+        alphabet
+    }
+
+    /// Makes the alphabet accept upper and lower case versions of its letters
+    /// when parsing. Formatting uses the digits as given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two digits only differ by case.
+    #[must_use]
+    pub const fn case_insensitive(self) -> Self {
+        let alphabet = Self {
+            case_insensitive: true,
+            ..self
+        };
+        alphabet.validate();
+        alphabet
+    }
+
+    /// Returns the number of digits, which is the largest supported base.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // At most 64 digits.
+    pub const fn max_radix(&self) -> u64 {
+        self.digits.len() as u64
+    }
+
+    const fn validate(&self) {
+        let mut i = 0;
+        while i < self.digits.len() {
+            assert!(self.digits[i].is_ascii(), "alphabet digits must be ASCII");
+            let mut j = 0;
+            while j < i {
+                assert!(
+                    !self.matches(self.digits[j], self.digits[i]),
+                    "alphabet digits must be distinct"
+                );
+                j += 1;
+            }
+            i += 1;
+        }
+    }
+
+    const fn matches(&self, digit: u8, c: u8) -> bool {
+        digit == c || (self.case_insensitive && digit.eq_ignore_ascii_case(&c))
+    }
+
+    /// Returns the value of `c`, if it is a digit.
+    #[cfg(feature = "alloc")]
+    fn value(&self, c: char) -> Option<u64> {
+        let c = u8::try_from(c).ok()?;
+        let position = self
+            .digits
+            .iter()
+            .position(|&digit| self.matches(digit, c))?;
+        Some(position as u64)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::cast_possible_truncation)] // Digits are less than `max_radix`.
+    fn char(&self, value: u64) -> char {
+        char::from(self.digits[value as usize])
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Parse a string into a [`Uint`].
     ///
-    /// For bases 2 to 36, the case-agnostic alphabet 0—1, a—b is used and `_`
-    /// are ignored. For bases 37 to 64, the case-sensitive alphabet a—z, A—Z,
+    /// For bases 2 to 36, the case-agnostic alphabet 0—9, a—z is used and `_`
+    /// are ignored. For bases 37 to 64, the case-sensitive alphabet A—Z, a—z,
     /// 0—9, {+-}, {/,_} is used. That is, for base 64 it is compatible with
-    /// all the common base64 variants.
+    /// all the common base64 variants. Use
+    /// [`from_str_radix_with`](Self::from_str_radix_with) for other alphabets.
     ///
     /// # Errors
     ///
@@ -84,7 +344,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 // The Base-64 alphabets
                 match c {
                     'A'..='Z' => u64::from(c) - u64::from('A'),
-                    'a'..='f' => u64::from(c) - u64::from('a') + 26,
+                    'a'..='z' => u64::from(c) - u64::from('a') + 26,
                     '0'..='9' => u64::from(c) - u64::from('0') + 52,
                     '+' | '-' => 62,
                     '/' | ',' | '_' => 63,
@@ -102,6 +362,160 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Parse a string of digits from `alphabet` into a [`Uint`].
+    ///
+    /// Unlike [`from_str_radix`](Self::from_str_radix), no characters are
+    /// ignored. Large inputs are converted by divide and conquer.
+    ///
+    /// ```
+    /// # use ruint::{aliases::U256, Alphabet};
+    /// let value = U256::from_str_radix_with("3yQ", 58, &Alphabet::BASE58).unwrap();
+    /// assert_eq!(value, U256::from(9_999));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseError::InvalidRadix`] if the radix is less than 2 or larger
+    ///   than the alphabet.
+    /// * [`ParseError::InvalidDigit`] if the string contains a character that
+    ///   is not a digit less than the radix.
+    /// * [`ParseError::BaseConvertError`] if the value does not fit.
+    pub fn from_str_radix_with(
+        src: &str,
+        radix: u64,
+        alphabet: &Alphabet<'_>,
+    ) -> Result<Self, ParseError> {
+        if radix < 2 || radix > alphabet.max_radix() {
+            return Err(ParseError::InvalidRadix(radix));
+        }
+        let digits = src
+            .chars()
+            .map(|c| {
+                alphabet
+                    .value(c)
+                    .filter(|&digit| digit < radix)
+                    .ok_or(ParseError::InvalidDigit(c))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_base_be_split(radix, &digits)?)
+    }
+
+    /// Formats the number in base `radix`, the inverse of
+    /// [`from_str_radix`](Self::from_str_radix).
+    ///
+    /// Bases up to 36 use the lower case [`Alphabet::BASE36`] and larger
+    /// bases [`Alphabet::BASE64`]. Large values are converted by divide and
+    /// conquer.
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// let id = U64::from(1_234_567_890);
+    /// assert_eq!(id.to_string_radix(36), "kf12oi");
+    /// assert_eq!(U64::from_str_radix("KF12OI", 36), Ok(id));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is less than 2 or larger than 64.
+    #[must_use]
+    pub fn to_string_radix(&self, radix: u64) -> String {
+        let alphabet = if radix <= 36 {
+            Alphabet::BASE36
+        } else {
+            Alphabet::BASE64
+        };
+        self.to_string_radix_with(radix, &alphabet)
+    }
+
+    /// Formats the number in base `radix` with digits from `alphabet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is less than 2 or larger than the alphabet.
+    #[must_use]
+    pub fn to_string_radix_with(&self, radix: u64, alphabet: &Alphabet<'_>) -> String {
+        assert!(
+            (2..=alphabet.max_radix()).contains(&radix),
+            "radix {radix} is not supported by the alphabet"
+        );
+        let digits = self.to_base_be_split(radix);
+        if digits.is_empty() {
+            return alphabet.char(0).into();
+        }
+        digits
+            .into_iter()
+            .map(|digit| alphabet.char(digit))
+            .collect()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Parse a string into a [`Uint`], accepting the syntax enabled in
+    /// `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseErrorAt`] with the byte offset of the first problem.
+    /// Syntax errors take precedence over [`ParseErrorKind::Overflow`].
+    pub fn from_str_with(src: &str, options: &ParseOptions) -> Result<Self, ParseErrorAt> {
+        let mut start = 0;
+        if options.plus_sign && src.starts_with('+') {
+            start += 1;
+        }
+        let mut radix = options.radix;
+        if options.prefixes {
+            let prefix_radix = match src.get(start..start + 2) {
+                Some("0x" | "0X") => 16,
+                Some("0o" | "0O") => 8,
+                Some("0b" | "0B") => 2,
+                _ => 0,
+            };
+            if prefix_radix != 0 {
+                radix = prefix_radix;
+                start += 2;
+            }
+        }
+
+        let mut error = None;
+        let mut count = 0;
+        // Offset of a separator that has not been followed by a digit yet.
+        let mut separator = None;
+        let mut digits = src[start..].char_indices().filter_map(|(index, c)| {
+            let offset = start + index;
+            if error.is_some() {
+                return None;
+            }
+            if c == '_' && options.separators {
+                if count == 0 || separator.is_some() {
+                    error = Some((offset, ParseErrorKind::InvalidSeparator));
+                }
+                separator = Some(offset);
+                return None;
+            }
+            #[allow(clippy::cast_possible_truncation)] // Radix is at most 36.
+            let Some(digit) = c.to_digit(radix as u32) else {
+                error = Some((offset, ParseErrorKind::InvalidDigit(c)));
+                return None;
+            };
+            count += 1;
+            separator = None;
+            Some(u64::from(digit))
+        });
+        let result = Self::from_base_be(radix, digits.by_ref());
+        digits.for_each(drop);
+
+        let trailing = separator.map(|offset| (offset, ParseErrorKind::InvalidSeparator));
+        let empty = (count == 0).then_some((start, ParseErrorKind::Empty));
+        let overflow = result.is_err().then_some((start, ParseErrorKind::Overflow));
+        match error.or(trailing).or(empty).or(overflow) {
+            Some((offset, kind)) => Err(ParseErrorAt { offset, kind }),
+            None => Ok(result.unwrap_or_default()),
+        }
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> FromStr for Uint<BITS, LIMBS> {
     type Err = ParseError;
 
@@ -124,8 +538,192 @@ impl<const BITS: usize, const LIMBS: usize> FromStr for Uint<BITS, LIMBS> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{const_for, nlimbs};
     use proptest::{prop_assert_eq, proptest};
 
+    #[allow(unused_imports)]
+    use alloc::string::String;
+
+    #[test]
+    fn test_from_str_with() {
+        let options = ParseOptions::new()
+            .prefixes(true)
+            .separators(true)
+            .plus_sign(true);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let decimal = value.to_string();
+                assert_eq!(U::from_str_with(&decimal, &ParseOptions::new()), Ok(value));
+                assert_eq!(U::from_str_with(&format!("+{value:#X}"), &options), Ok(value));
+                assert_eq!(U::from_str_with(&format!("{value:#o}"), &options), Ok(value));
+                let hex = ParseOptions::new().radix(16);
+                assert_eq!(U::from_str_with(&format!("{value:x}"), &hex), Ok(value));
+
+                let mut grouped = String::new();
+                for (i, c) in decimal.chars().enumerate() {
+                    if i > 0 && (decimal.len() - i) % 3 == 0 {
+                        grouped.push('_');
+                    }
+                    grouped.push(c);
+                }
+                assert_eq!(U::from_str_with(&grouped, &options), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_from_str_with_errors() {
+        type U = Uint<64, 1>;
+        let options = ParseOptions::new()
+            .prefixes(true)
+            .separators(true)
+            .plus_sign(true);
+        let plain = ParseOptions::new();
+        let error = |src, options| U::from_str_with(src, options).unwrap_err();
+        let at = |offset, kind| ParseErrorAt { offset, kind };
+
+        assert_eq!(error("", &options), at(0, ParseErrorKind::Empty));
+        assert_eq!(error("+0x", &options), at(3, ParseErrorKind::Empty));
+        assert_eq!(
+            error("+1", &plain),
+            at(0, ParseErrorKind::InvalidDigit('+'))
+        );
+        assert_eq!(
+            error("0x10", &plain),
+            at(1, ParseErrorKind::InvalidDigit('x'))
+        );
+        assert_eq!(
+            error("1_000", &plain),
+            at(1, ParseErrorKind::InvalidDigit('_'))
+        );
+        assert_eq!(
+            error("_1", &options),
+            at(0, ParseErrorKind::InvalidSeparator)
+        );
+        assert_eq!(
+            error("0x_1", &options),
+            at(2, ParseErrorKind::InvalidSeparator)
+        );
+        assert_eq!(
+            error("1__0", &options),
+            at(2, ParseErrorKind::InvalidSeparator)
+        );
+        assert_eq!(
+            error("10_", &options),
+            at(2, ParseErrorKind::InvalidSeparator)
+        );
+        assert_eq!(
+            error("0b102", &options),
+            at(4, ParseErrorKind::InvalidDigit('2'))
+        );
+        assert_eq!(
+            error("12 34", &options),
+            at(2, ParseErrorKind::InvalidDigit(' '))
+        );
+        assert_eq!(
+            error("1ü", &options),
+            at(1, ParseErrorKind::InvalidDigit('ü'))
+        );
+        assert_eq!(
+            error("++1", &options),
+            at(1, ParseErrorKind::InvalidDigit('+'))
+        );
+
+        let large = "+0x1_0000_0000_0000_0000";
+        assert_eq!(error(large, &options), at(3, ParseErrorKind::Overflow));
+        // Syntax errors are reported even after an overflow.
+        let large = "0x1_0000_0000_0000_0000_";
+        assert_eq!(
+            error(large, &options),
+            at(23, ParseErrorKind::InvalidSeparator)
+        );
+        assert_eq!(
+            error("0x1ffffffffffffffffg", &options).to_string(),
+            "invalid digit 'g' at byte 19"
+        );
+    }
+
+    #[test]
+    fn test_radix_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, radix in 2_u64..=64)| {
+                let string = value.to_string_radix(radix);
+                assert_eq!(U::from_str_radix(&string, radix), Ok(value));
+                for alphabet in [Alphabet::BASE36, Alphabet::BASE64, Alphabet::BASE58] {
+                    let radix = radix.min(alphabet.max_radix());
+                    let string = value.to_string_radix_with(radix, &alphabet);
+                    assert_eq!(U::from_str_radix_with(&string, radix, &alphabet), Ok(value));
+                }
+            });
+        });
+        proptest!(|(value: Uint<4096, 64>)| {
+            let string = value.to_string_radix(10);
+            assert_eq!(string, value.to_string());
+            assert_eq!(Uint::from_str_radix_with(&string, 10, &Alphabet::BASE36), Ok(value));
+        });
+    }
+
+    #[test]
+    fn test_radix() {
+        type U = Uint<64, 1>;
+        assert_eq!(U::ZERO.to_string_radix(36), "0");
+        assert_eq!(Uint::<0, 0>::ZERO.to_string_radix(2), "0");
+        assert_eq!(U::from(255).to_string_radix(16), "ff");
+        assert_eq!(U::from(63).to_string_radix(64), "/");
+        assert_eq!(
+            U::from(62).to_string_radix_with(64, &Alphabet::BASE64_URL),
+            "-"
+        );
+        assert_eq!(
+            U::from_str_radix_with("Zz", 36, &Alphabet::BASE36),
+            Ok(U::from(1295))
+        );
+        assert_eq!(
+            U::from_str_radix_with("", 10, &Alphabet::BASE36),
+            Ok(U::ZERO)
+        );
+        assert_eq!(
+            U::from_str_radix_with("1_0", 10, &Alphabet::BASE36),
+            Err(ParseError::InvalidDigit('_'))
+        );
+        assert_eq!(
+            U::from_str_radix_with("19", 9, &Alphabet::BASE36),
+            Err(ParseError::InvalidDigit('9'))
+        );
+        assert_eq!(
+            U::from_str_radix_with("0", 1, &Alphabet::BASE36),
+            Err(ParseError::InvalidRadix(1))
+        );
+        assert_eq!(
+            U::from_str_radix_with("0", 59, &Alphabet::BASE58),
+            Err(ParseError::InvalidRadix(59))
+        );
+        assert_eq!(
+            U::from_str_radix_with("1", 58, &Alphabet::BASE58),
+            Ok(U::ZERO)
+        );
+        assert_eq!(
+            U::from_str_radix_with(&"z".repeat(13), 36, &Alphabet::BASE36),
+            Err(ParseError::BaseConvertError(BaseConvertError::Overflow))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "alphabet digits must be distinct")]
+    fn test_alphabet_case_collision() {
+        let _ = Alphabet::new("aA").case_insensitive();
+    }
+
+    #[test]
+    #[should_panic(expected = "radix 11 is not supported by the alphabet")]
+    fn test_to_string_radix_too_large() {
+        let _ = Uint::<64, 1>::ZERO.to_string_radix_with(11, &Alphabet::new("0123456789"));
+    }
+
     #[test]
     fn test_parse() {
         proptest!(|(value: u128)| {
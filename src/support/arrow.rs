@@ -0,0 +1,227 @@
+//! Support for the [`arrow`](https://crates.io/crates/arrow) crates.
+//!
+//! Conversions are provided between [`Uint`] and [`i256`], and between [`Int`]
+//! and [`i256`], of any size. They fail if the value does not fit. Note that
+//! `i256` is signed, so a `U256` only converts if it is less than `2^255`.
+//!
+//! [`decimal256_array`] builds a [`Decimal256Array`] from a slice of [`Uint`]
+//! with a single allocation, for example to export to Parquet.
+#![cfg(feature = "arrow")]
+#![cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+
+use crate::{FromIntError, FromUintError, Int, ToIntError, ToUintError, Uint};
+use arrow_array::Decimal256Array;
+use arrow_buffer::{i256, ScalarBuffer};
+use arrow_schema::ArrowError;
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Intentional
+const fn to_limbs(value: i256) -> [u64; 4] {
+    let (lo, hi) = value.to_parts();
+    let hi = hi as u128;
+    [lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64]
+}
+
+/// Truncates the limbs to 256 bits, returning whether any were dropped.
+#[allow(clippy::cast_possible_wrap)] // Intentional
+fn from_limbs(limbs: &[u64]) -> (i256, bool) {
+    let limb = |i| u128::from(limbs.get(i).copied().unwrap_or_default());
+    let value = i256::from_parts(limb(1) << 64 | limb(0), (limb(3) << 64 | limb(2)) as i128);
+    let truncated = limbs.iter().skip(4).any(|&limb| limb != 0);
+    (value, truncated)
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<i256> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: i256) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            let (abs, _) = Self::overflowing_from_limbs_slice(&to_limbs(value.wrapping_neg()));
+            return Err(ToUintError::ValueNegative(BITS, abs.wrapping_neg()));
+        }
+        match Self::overflowing_from_limbs_slice(&to_limbs(value)) {
+            (n, false) => Ok(n),
+            (n, true) => Err(ToUintError::ValueTooLarge(BITS, n)),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&i256> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &i256) -> Result<Self, Self::Error> {
+        Self::try_from(*value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Uint<BITS, LIMBS>> for i256 {
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Uint<BITS, LIMBS>> for i256 {
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        match from_limbs(value.as_limbs()) {
+            (n, false) if !n.is_negative() => Ok(n),
+            (n, _) => Err(FromUintError::Overflow(BITS, n, Self::MAX)),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<i256> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    fn try_from(value: i256) -> Result<Self, Self::Error> {
+        // `wrapping_abs` of `i256::MIN` is `2^255` when read as unsigned.
+        Self::try_from_sign_and_abs_limbs(value.is_negative(), &to_limbs(value.wrapping_abs()))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&i256> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    fn try_from(value: &i256) -> Result<Self, Self::Error> {
+        Self::try_from(*value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Int<BITS, LIMBS>> for i256 {
+    type Error = FromIntError<Self>;
+
+    #[inline]
+    fn try_from(value: Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Int<BITS, LIMBS>> for i256 {
+    type Error = FromIntError<Self>;
+
+    #[inline]
+    fn try_from(value: &Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let (negative, abs) = value.into_sign_and_abs();
+        let (abs, truncated) = from_limbs(abs.as_limbs());
+        let n = if negative { abs.wrapping_neg() } else { abs };
+        if !truncated && n.is_negative() == negative {
+            Ok(n)
+        } else if negative {
+            Err(FromIntError::Underflow(BITS, n, Self::MIN))
+        } else {
+            Err(FromIntError::Overflow(BITS, n, Self::MAX))
+        }
+    }
+}
+
+/// Builds a [`Decimal256Array`] without nulls from `values`.
+///
+/// The values are the unscaled integers, i.e. with `scale` 2 the value `1234`
+/// represents `12.34`.
+///
+/// ```
+/// # use ruint::{aliases::U256, support::arrow::decimal256_array};
+/// let array = decimal256_array(&[U256::from(1234), U256::from(5)], 10, 2).unwrap();
+/// assert_eq!(array.value_as_string(0), "12.34");
+/// assert_eq!(array.value_as_string(1), "0.05");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `precision` or `scale` are invalid for `Decimal256`, or
+/// if a value has more than `precision` decimal digits.
+pub fn decimal256_array<const BITS: usize, const LIMBS: usize>(
+    values: &[Uint<BITS, LIMBS>],
+    precision: u8,
+    scale: i8,
+) -> Result<Decimal256Array, ArrowError> {
+    let values = values
+        .iter()
+        .map(i256::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ArrowError::InvalidArgumentError(err.to_string()))?;
+    let array = Decimal256Array::new(ScalarBuffer::from(values), None)
+        .with_precision_and_scale(precision, scale)?;
+    array.validate_decimal_precision(precision)?;
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{I256, U256},
+        const_for, nlimbs,
+    };
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES if (BITS < 256) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big = i256::try_from(value).unwrap();
+                assert_eq!(U::try_from(big), Ok(value));
+
+                let value = I::from_raw(value);
+                let big = i256::try_from(value).unwrap();
+                assert_eq!(I::try_from(big), Ok(value));
+            });
+        });
+        proptest!(|(value: U256)| {
+            let value = I256::from_raw(value);
+            let big = i256::try_from(value).unwrap();
+            assert_eq!(big.to_string(), value.to_string());
+            assert_eq!(I256::try_from(big), Ok(value));
+        });
+    }
+
+    #[test]
+    fn test_bounds() {
+        let max = U256::from(1) << 255;
+        assert_eq!(i256::try_from(max - U256::from(1)), Ok(i256::MAX));
+        assert!(i256::try_from(max).is_err());
+        assert!(i256::try_from(U256::MAX).is_err());
+        assert_eq!(i256::try_from(I256::MIN), Ok(i256::MIN));
+        assert_eq!(I256::try_from(i256::MIN), Ok(I256::MIN));
+        assert_eq!(U256::try_from(i256::MAX), Ok(max - U256::from(1)));
+        assert_eq!(
+            U256::try_from(i256::MINUS_ONE),
+            Err(ToUintError::ValueNegative(256, U256::MAX))
+        );
+        assert!(Uint::<8, 1>::try_from(i256::from_i128(256)).is_err());
+        assert!(Int::<8, 1>::try_from(i256::from_i128(-129)).is_err());
+        assert!(i256::try_from(Uint::<512, 8>::MAX).is_err());
+        assert!(i256::try_from(Int::<512, 8>::MIN).is_err());
+    }
+
+    #[test]
+    fn test_decimal256_array() {
+        let values = [
+            U256::ZERO,
+            U256::from(42),
+            U256::from(10).pow(U256::from(75)),
+        ];
+        let array = decimal256_array(&values, 76, 0).unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.precision(), 76);
+        for (value, converted) in values.iter().zip(array.values().iter()) {
+            assert_eq!(U256::try_from(converted), Ok(*value));
+        }
+
+        // Too many digits for the precision.
+        assert!(decimal256_array(&values, 10, 0).is_err());
+        // Too large for `i256`.
+        assert!(decimal256_array(&[U256::MAX], 76, 0).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! Support for the [`bitvec`](https://crates.io/crates/bitvec) crate.
+//!
+//! Bit `i` of a [`BitSlice`] corresponds to the bit with value `2^i`,
+//! whatever its [`BitStore`] and [`BitOrder`]. The order only affects the
+//! in-memory layout.
+#![cfg(feature = "bitvec")]
+#![cfg_attr(docsrs, doc(cfg(feature = "bitvec")))]
+
+use crate::{from::ToUintError, Uint};
+use bitvec::{
+    order::{BitOrder, Lsb0},
+    slice::BitSlice,
+    store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use bitvec::vec::BitVec;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Views the number as a [`BitSlice`] of length `BITS` without copying.
+    ///
+    /// The limbs are little-endian and [`Lsb0`] numbers the bits of each
+    /// limb from the least significant, so index `i` is bit `i` on all
+    /// targets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// let x = uint!(0b1101_U4);
+    /// assert_eq!(x.as_bitslice().count_ones(), 3);
+    /// assert!(x.as_bitslice()[2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_bitslice(&self) -> &BitSlice<u64, Lsb0> {
+        &BitSlice::from_slice(self.as_limbs())[..BITS]
+    }
+
+    /// Views the number as a mutable [`BitSlice`] of length `BITS` without
+    /// copying. See [`as_bitslice`](Self::as_bitslice).
+    #[must_use]
+    #[inline]
+    pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<u64, Lsb0> {
+        // SAFETY: The slice ends at `BITS`, so the unused bits of the top limb
+        // stay zero.
+        unsafe { &mut BitSlice::from_slice_mut(self.as_limbs_mut())[..BITS] }
+    }
+}
+
+impl<T: BitStore, O: BitOrder, const BITS: usize, const LIMBS: usize> TryFrom<&BitSlice<T, O>>
+    for Uint<BITS, LIMBS>
+{
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(bits: &BitSlice<T, O>) -> Result<Self, Self::Error> {
+        let mut result = Self::ZERO;
+        let mut overflow = false;
+        for index in bits.iter_ones() {
+            if index < BITS {
+                result.set_bit(index, true);
+            } else {
+                overflow = true;
+            }
+        }
+        if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, result))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BitStore, O: BitOrder, const BITS: usize, const LIMBS: usize> TryFrom<BitVec<T, O>>
+    for Uint<BITS, LIMBS>
+{
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(bits: BitVec<T, O>) -> Result<Self, Self::Error> {
+        Self::try_from(bits.as_bitslice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BitStore, O: BitOrder, const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>>
+    for BitVec<T, O>
+{
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BitStore, O: BitOrder, const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>>
+    for BitVec<T, O>
+{
+    #[inline]
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        value.as_bitslice().iter().by_vals().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use bitvec::order::Msb0;
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bits = value.as_bitslice();
+                assert_eq!(bits.len(), BITS);
+                assert!(bits.iter().by_vals().eq(value.iter_bits_le()));
+                assert_eq!(U::try_from(bits), Ok(value));
+
+                let lsb: BitVec<u8, Lsb0> = value.into();
+                let msb: BitVec<u16, Msb0> = value.into();
+                assert_eq!(lsb, msb);
+                assert_eq!(U::try_from(lsb), Ok(value));
+                assert_eq!(U::try_from(msb), Ok(value));
+
+                let mut longer: BitVec<u32, Msb0> = value.into();
+                longer.push(true);
+                assert_eq!(U::try_from(longer), Err(ToUintError::ValueTooLarge(BITS, value)));
+            });
+        });
+    }
+
+    #[test]
+    fn test_mut_bitslice() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(mut value: U)| {
+                let expected = value ^ U::from(1);
+                let bits = value.as_mut_bitslice();
+                let first = bits[0];
+                bits.set(0, !first);
+                assert_eq!(value, expected);
+                value.as_mut_bitslice().fill(true);
+                assert_eq!(value, U::MAX);
+            });
+        });
+    }
+}
@@ -0,0 +1,180 @@
+//! Support for the [`bnum`](https://crates.io/crates/bnum) crate.
+//!
+//! Conversions are provided between [`Uint`] and [`BUint`], and between
+//! [`Int`] and [`BInt`], of any size. They fail if the value does not fit.
+#![cfg(feature = "bnum")]
+#![cfg_attr(docsrs, doc(cfg(feature = "bnum")))]
+
+use crate::{FromIntError, FromUintError, Int, ToIntError, ToUintError, Uint};
+use bnum::{BInt, BUint};
+
+/// Truncates the limbs to `N` digits, returning whether any were dropped.
+fn to_digits<const N: usize>(limbs: &[u64]) -> (BUint<N>, bool) {
+    let mut digits = [0; N];
+    let len = limbs.len().min(N);
+    digits[..len].copy_from_slice(&limbs[..len]);
+    let truncated = limbs[len..].iter().any(|&limb| limb != 0);
+    (BUint::from_digits(digits), truncated)
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<BUint<N>>
+    for Uint<BITS, LIMBS>
+{
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: BUint<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<&BUint<N>>
+    for Uint<BITS, LIMBS>
+{
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &BUint<N>) -> Result<Self, Self::Error> {
+        match Self::overflowing_from_limbs_slice(value.digits()) {
+            (n, false) => Ok(n),
+            (n, true) => Err(ToUintError::ValueTooLarge(BITS, n)),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<Uint<BITS, LIMBS>>
+    for BUint<N>
+{
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<&Uint<BITS, LIMBS>>
+    for BUint<N>
+{
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        match to_digits(value.as_limbs()) {
+            (n, false) => Ok(n),
+            (n, true) => Err(FromUintError::Overflow(BITS, n, Self::MAX)),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<BInt<N>> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    fn try_from(value: BInt<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<&BInt<N>> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    fn try_from(value: &BInt<N>) -> Result<Self, Self::Error> {
+        Self::try_from_sign_and_abs_limbs(value.is_negative(), value.unsigned_abs().digits())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<Int<BITS, LIMBS>> for BInt<N> {
+    type Error = FromIntError<Self>;
+
+    #[inline]
+    fn try_from(value: Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const N: usize> TryFrom<&Int<BITS, LIMBS>> for BInt<N> {
+    type Error = FromIntError<Self>;
+
+    #[inline]
+    fn try_from(value: &Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let (negative, abs) = value.into_sign_and_abs();
+        let (abs, truncated) = to_digits(abs.as_limbs());
+        let n = Self::from_bits(if negative { abs.wrapping_neg() } else { abs });
+        if !truncated && n.is_negative() == negative {
+            Ok(n)
+        } else if negative {
+            Err(FromIntError::Underflow(BITS, n, Self::MIN))
+        } else {
+            Err(FromIntError::Overflow(BITS, n, Self::MAX))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{I128, U128},
+        const_for, nlimbs,
+    };
+    use bnum::types::{I128 as BI128, U128 as BU128};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big: BUint<{ LIMBS + 1 }> = value.try_into().unwrap();
+                assert_eq!(U::try_from(big), Ok(value));
+
+                let value = I::from_raw(value);
+                let big: BInt<{ LIMBS + 1 }> = value.try_into().unwrap();
+                assert_eq!(I::try_from(big), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_primitive() {
+        proptest!(|(value: u128, signed: i128)| {
+            assert_eq!(BU128::try_from(U128::from(value)), Ok(BU128::from(value)));
+            assert_eq!(U128::try_from(BU128::from(value)), Ok(U128::from(value)));
+            assert_eq!(BI128::try_from(I128::from(signed)), Ok(BI128::from(signed)));
+            assert_eq!(I128::try_from(BI128::from(signed)), Ok(I128::from(signed)));
+        });
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = BUint::<2>::from(u128::MAX);
+        assert_eq!(
+            Uint::<72, 2>::try_from(big),
+            Err(ToUintError::ValueTooLarge(72, Uint::MAX))
+        );
+        assert_eq!(
+            BUint::<1>::try_from(Uint::<128, 2>::MAX),
+            Err(FromUintError::Overflow(128, BUint::MAX, BUint::MAX))
+        );
+        assert_eq!(
+            Int::<72, 2>::try_from(BInt::<2>::MIN),
+            Err(ToIntError::ValueTooSmall(72, Int::ZERO))
+        );
+        assert_eq!(
+            BInt::<1>::try_from(Int::<128, 2>::MIN),
+            Err(FromIntError::Underflow(128, BInt::ZERO, BInt::MIN))
+        );
+        assert_eq!(
+            BInt::<1>::try_from(Int::<128, 2>::from(1_i128 << 63)),
+            Err(FromIntError::Overflow(128, BInt::MIN, BInt::MAX))
+        );
+        assert_eq!(
+            BInt::<1>::try_from(Int::<128, 2>::from(i64::MIN)),
+            Ok(BInt::MIN)
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Support for the [`bson`](https://crates.io/crates/bson) crate.
+//!
+//! The default `serde` representation of a [`Uint`] is a hex string in BSON.
+//! This module provides two native representations instead, as conversions and
+//! as modules for `#[serde(with = ...)]`:
+//!
+//! * [`binary`] stores the value as [`Uint::BYTES`] big-endian bytes with the
+//!   generic subtype. It is exact for every value and sorts correctly.
+//! * [`decimal128`] stores the value as a [`Decimal128`], so MongoDB can do
+//!   arithmetic and numeric comparisons on it. Values with more than 34
+//!   significant digits do not fit and fail with [`ConversionError::Overflow`]
+//!   instead of being rounded.
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! # use serde::{Deserialize, Serialize};
+//! # use bson::Bson;
+//! #[derive(Serialize, Deserialize)]
+//! struct Balance {
+//!     #[serde(with = "ruint::support::bson::binary")]
+//!     hash:   U256,
+//!     #[serde(with = "ruint::support::bson::decimal128")]
+//!     amount: U256,
+//! }
+//!
+//! let balance = Balance {
+//!     hash:   U256::MAX,
+//!     amount: U256::from(1000),
+//! };
+//! let document = bson::to_document(&balance).unwrap();
+//! assert!(matches!(document.get("hash"), Some(Bson::Binary(_))));
+//! assert_eq!(document.get("amount").unwrap().to_string(), "1000");
+//! ```
+
+#![cfg(feature = "bson")]
+#![cfg_attr(docsrs, doc(cfg(feature = "bson")))]
+
+use crate::Uint;
+use bson::{spec::BinarySubtype, Binary, Decimal128};
+use thiserror::Error;
+
+/// Maximum number of digits in a [`Decimal128`] coefficient.
+const MAX_DIGITS: usize = 34;
+
+/// Largest [`Decimal128`] coefficient, `10^34 - 1`.
+const MAX_COEFFICIENT: u128 = 9_999_999_999_999_999_999_999_999_999_999_999;
+
+/// Offset of the stored [`Decimal128`] exponent.
+const EXPONENT_BIAS: i32 = 6176;
+
+/// Largest [`Decimal128`] exponent.
+const MAX_EXPONENT: i32 = 6111;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Error)]
+pub enum ConversionError {
+    #[error("The value is too large for the target type")]
+    Overflow,
+
+    #[error("The Decimal128 value is not a non-negative integer")]
+    NotAnInteger,
+
+    #[error("Unexpected binary subtype {0:?}")]
+    InvalidSubtype(BinarySubtype),
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Binary {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self {
+            subtype: BinarySubtype::Generic,
+            bytes:   value.to_be_bytes_vec(),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Binary> for Uint<BITS, LIMBS> {
+    type Error = ConversionError;
+
+    /// Accepts shorter inputs, but not a different subtype.
+    fn try_from(value: &Binary) -> Result<Self, Self::Error> {
+        if value.subtype != BinarySubtype::Generic {
+            return Err(ConversionError::InvalidSubtype(value.subtype));
+        }
+        Self::try_from_be_slice(&value.bytes).ok_or(ConversionError::Overflow)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Uint<BITS, LIMBS>> for Decimal128 {
+    type Error = ConversionError;
+
+    /// Uses a zero exponent when possible. Larger values are exact as long as
+    /// the digits past the 34th are zeros.
+    fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let digits: Vec<u64> = value.to_base_le(10).collect();
+        let (zeros, digits) = digits.split_at(digits.len().saturating_sub(MAX_DIGITS));
+        if zeros.iter().any(|&digit| digit != 0) {
+            return Err(ConversionError::Overflow);
+        }
+        let exponent = i32::try_from(zeros.len())
+            .ok()
+            .filter(|&exponent| exponent <= MAX_EXPONENT)
+            .ok_or(ConversionError::Overflow)?;
+        let coefficient = digits.iter().rev().fold(0_u128, |coefficient, &digit| {
+            coefficient * 10 + u128::from(digit)
+        });
+        #[allow(clippy::cast_sign_loss)] // The biased exponent is positive.
+        let bits = ((exponent + EXPONENT_BIAS) as u128) << 113 | coefficient;
+        Ok(Self::from_bytes(bits.to_le_bytes()))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Decimal128> for Uint<BITS, LIMBS> {
+    type Error = ConversionError;
+
+    /// Accepts any exponent as long as the value is an integer.
+    fn try_from(value: Decimal128) -> Result<Self, Self::Error> {
+        // See the IEEE 754-2008 binary integer decimal encoding.
+        let bits = u128::from_le_bytes(value.bytes());
+        let negative = bits >> 127 == 1;
+        if (bits >> 123) & 0b1111 == 0b1111 {
+            // Infinity or NaN.
+            return Err(ConversionError::NotAnInteger);
+        }
+        // With the `11` prefix the coefficient would be at least `2^113`, which
+        // is out of range and read as zero, like any other coefficient that is.
+        let (exponent, coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+            ((bits >> 111) & 0x3fff, 0)
+        } else {
+            ((bits >> 113) & 0x3fff, bits & ((1 << 113) - 1))
+        };
+        let mut coefficient = if coefficient > MAX_COEFFICIENT {
+            0
+        } else {
+            coefficient
+        };
+        if coefficient == 0 {
+            return Ok(Self::ZERO);
+        }
+        if negative {
+            return Err(ConversionError::NotAnInteger);
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)] // 14 bits
+        let mut exponent = exponent as i32 - EXPONENT_BIAS;
+        while exponent < 0 {
+            if coefficient % 10 != 0 {
+                return Err(ConversionError::NotAnInteger);
+            }
+            coefficient /= 10;
+            exponent += 1;
+        }
+        let coefficient = Self::try_from(coefficient).map_err(|_| ConversionError::Overflow)?;
+        // Stops at the first overflow.
+        let ten = Self::try_from(10_u64).ok();
+        (0..exponent)
+            .try_fold(coefficient, |value, _| value.checked_mul(ten?))
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+/// Stores a [`Uint`] as big-endian BSON binary, for `#[serde(with = ...)]`.
+pub mod binary {
+    use super::{Binary, Uint};
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a [`Uint`] as [`Uint::BYTES`] big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Binary::from(*value).serialize(serializer)
+    }
+
+    /// Deserializes a [`Uint`] from big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not generic binary, or if the value
+    /// does not fit in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        let binary = Binary::deserialize(deserializer)?;
+        Uint::try_from(&binary).map_err(D::Error::custom)
+    }
+}
+
+/// Stores a [`Uint`] as a BSON [`Decimal128`], for `#[serde(with = ...)]`.
+pub mod decimal128 {
+    use super::{Decimal128, Uint};
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a [`Uint`] as a [`Decimal128`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value has more than 34 significant digits, or
+    /// if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Decimal128::try_from(*value)
+            .map_err(<S::Error as ser::Error>::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserializes a [`Uint`] from a [`Decimal128`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a non-negative integer
+    /// [`Decimal128`], or if the value does not fit in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        let decimal = Decimal128::deserialize(deserializer)?;
+        Uint::try_from(decimal).map_err(<D::Error as de::Error>::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use bson::{doc, Bson};
+    use proptest::proptest;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row<const BITS: usize, const LIMBS: usize> {
+        #[serde(with = "binary")]
+        hash:   Uint<BITS, LIMBS>,
+        #[serde(with = "decimal128")]
+        amount: Uint<BITS, LIMBS>,
+    }
+
+    fn decimal(s: &str) -> Decimal128 {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let binary = Binary::from(value);
+                assert_eq!(binary.bytes.len(), U::BYTES);
+                assert_eq!(U::try_from(&binary), Ok(value));
+
+                match Decimal128::try_from(value) {
+                    Ok(decimal) => {
+                        if value.to_string().len() <= MAX_DIGITS {
+                            assert_eq!(decimal.to_string(), value.to_string());
+                        }
+                        assert_eq!(U::try_from(decimal), Ok(value));
+                    }
+                    Err(err) => {
+                        assert_eq!(err, ConversionError::Overflow);
+                        assert!(value.to_string().len() > MAX_DIGITS);
+                    }
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_decimal128() {
+        // Larger values keep 34 digits in the coefficient.
+        let shifted = |exponent| decimal(&format!("1{}E+{exponent}", "0".repeat(33)));
+        let max = U256::from(MAX_COEFFICIENT);
+        assert_eq!(Decimal128::try_from(max), Ok(decimal(&max.to_string())));
+        assert_eq!(Decimal128::try_from(max + U256::from(1)), Ok(shifted(1)));
+        assert_eq!(
+            Decimal128::try_from(max + U256::from(2)),
+            Err(ConversionError::Overflow)
+        );
+        assert_eq!(
+            Decimal128::try_from(U256::from(10).pow(U256::from(76))),
+            Ok(shifted(43))
+        );
+        assert_eq!(
+            Decimal128::try_from(U256::MAX),
+            Err(ConversionError::Overflow)
+        );
+
+        let parse = |s| U256::try_from(decimal(s));
+        assert_eq!(parse("12.00"), Ok(U256::from(12)));
+        assert_eq!(parse("5E+2"), Ok(U256::from(500)));
+        assert_eq!(parse("0E-10"), Ok(U256::ZERO));
+        assert_eq!(parse("-0"), Ok(U256::ZERO));
+        assert_eq!(parse("0E+6111"), Ok(U256::ZERO));
+        assert_eq!(parse("1.5"), Err(ConversionError::NotAnInteger));
+        assert_eq!(parse("-1"), Err(ConversionError::NotAnInteger));
+        assert_eq!(parse("NaN"), Err(ConversionError::NotAnInteger));
+        assert_eq!(parse("Infinity"), Err(ConversionError::NotAnInteger));
+        assert_eq!(parse("1E+78"), Err(ConversionError::Overflow));
+        assert_eq!(
+            Uint::<8, 1>::try_from(decimal("256")),
+            Err(ConversionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_binary() {
+        let binary = Binary {
+            subtype: BinarySubtype::Generic,
+            bytes:   vec![1, 2],
+        };
+        assert_eq!(Uint::<16, 1>::try_from(&binary), Ok(Uint::from(0x0102)));
+        assert_eq!(
+            Uint::<8, 1>::try_from(&binary),
+            Err(ConversionError::Overflow)
+        );
+        let binary = Binary {
+            subtype: BinarySubtype::Uuid,
+            bytes:   vec![1],
+        };
+        assert_eq!(
+            Uint::<16, 1>::try_from(&binary),
+            Err(ConversionError::InvalidSubtype(BinarySubtype::Uuid))
+        );
+    }
+
+    #[test]
+    fn test_document() {
+        let row = Row {
+            hash:   U256::MAX,
+            amount: U256::from(42),
+        };
+        let document = bson::to_document(&row).unwrap();
+        assert_eq!(document, doc! {
+            "hash": Bson::Binary(Binary::from(U256::MAX)),
+            "amount": Bson::Decimal128(decimal("42")),
+        });
+        assert_eq!(bson::from_document::<Row<256, 4>>(document).unwrap(), row);
+
+        let row = Row {
+            hash:   U256::ZERO,
+            amount: U256::MAX,
+        };
+        assert!(bson::to_document(&row).is_err());
+        let document = doc! { "hash": Binary::from(U256::ZERO), "amount": decimal("-1") };
+        assert!(bson::from_document::<Row<256, 4>>(document).is_err());
+    }
+}
@@ -2,41 +2,45 @@
 #![cfg(feature = "bytemuck")]
 #![cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
 
-use crate::Uint;
-use bytemuck::{Pod, Zeroable};
+use crate::{Bits, Int, Uint};
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
 
 // Implement Zeroable for all `Uint` types.
 unsafe impl<const BITS: usize, const LIMBS: usize> Zeroable for Uint<{ BITS }, { LIMBS }> {}
+unsafe impl<const BITS: usize, const LIMBS: usize> Zeroable for Int<{ BITS }, { LIMBS }> {}
+unsafe impl<const BITS: usize, const LIMBS: usize> Zeroable for Bits<{ BITS }, { LIMBS }> {}
 
 // Implement the `Pod` trait for `Uint` types with a size that is a multiple of
-// 64, up to 1024. Note that implementors must have a size that is divisible by
+// 64, up to 4096. Note that implementors must have a size that is divisible by
 // 64, and using `Uint` sizes not divisible by 64 would violate Pod's
-// guarantees potentially leading to undefined behavior.
+// guarantees potentially leading to undefined behavior. For the same reason
+// only these sizes can transparently wrap their limbs.
 macro_rules! impl_pod {
-    ($(($bits:expr, $limbs:expr)),+ $(,)?) => {
+    ($($limbs:literal),+ $(,)?) => {
         $(
-            unsafe impl Pod for Uint<{$bits}, $limbs> {}
+            unsafe impl Pod for Uint<{ 64 * $limbs }, $limbs> {}
+            unsafe impl Pod for Int<{ 64 * $limbs }, $limbs> {}
+            unsafe impl Pod for Bits<{ 64 * $limbs }, $limbs> {}
+            unsafe impl TransparentWrapper<[u64; $limbs]> for Uint<{ 64 * $limbs }, $limbs> {}
         )+
     };
 }
 
 impl_pod! {
-    (64, 1),
-    (128, 2),
-    (192, 3),
-    (256, 4),
-    (320, 5),
-    (384, 6),
-    (448, 7),
-    (512, 8),
-    (576, 9),
-    (640, 10),
-    (704, 11),
-    (768, 12),
-    (832, 13),
-    (896, 14),
-    (960, 15),
-    (1024, 16),
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+}
+
+// `Int` and `Bits` are transparent wrappers around `Uint`.
+unsafe impl<const BITS: usize, const LIMBS: usize> TransparentWrapper<Uint<BITS, LIMBS>>
+    for Int<BITS, LIMBS>
+{
+}
+unsafe impl<const BITS: usize, const LIMBS: usize> TransparentWrapper<Uint<BITS, LIMBS>>
+    for Bits<BITS, LIMBS>
+{
 }
 
 #[cfg(test)]
@@ -62,6 +66,26 @@ mod tests {
         test_pod::<896, 14>();
         test_pod::<960, 15>();
         test_pod::<1024, 16>();
+        test_pod::<2048, 32>();
+        test_pod::<4096, 64>();
+    }
+
+    #[test]
+    fn test_cast_slice() {
+        use crate::aliases::{B256, I256, U256};
+        use bytemuck::TransparentWrapper;
+
+        let limbs = [[1, 0, 0, 0], [0, 0, 0, 1 << 63]];
+        let values: &[U256] = TransparentWrapper::wrap_slice(&limbs[..]);
+        assert_eq!(values, [U256::from(1), U256::from(1) << 255_usize]);
+        assert_eq!(bytemuck::cast_slice::<_, u64>(values), limbs.concat());
+
+        let signed: &[I256] = bytemuck::cast_slice(values);
+        assert_eq!(signed[1], I256::MIN);
+        assert_eq!(TransparentWrapper::peel_slice(signed), values);
+
+        let bits = B256::from(values[1]);
+        assert_eq!(*TransparentWrapper::peel_ref(&bits), values[1]);
     }
 
     fn test_pod<const BITS: usize, const LIMBS: usize>()
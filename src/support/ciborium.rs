@@ -0,0 +1,186 @@
+//! Support for CBOR bignums with the [`ciborium`](https://crates.io/crates/ciborium) crate.
+//!
+//! The default `serde` representation of a [`Uint`] is a
+//! fixed-width byte string in binary formats. CBOR has a standard
+//! representation for integers instead, which COSE and CWT tooling expects:
+//! values that fit in a `u64` are unsigned integers (major type 0), and larger
+//! values are positive bignums, tag 2 with the minimal big-endian bytes as
+//! content ([RFC 8949 §3.4.3]).
+//!
+//! Opt in to this representation per field with `#[serde(with = ...)]`:
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Claims {
+//!     #[serde(with = "ruint::support::ciborium")]
+//!     amount: U256,
+//! }
+//!
+//! let claims = Claims { amount: U256::MAX };
+//! let mut cbor = Vec::new();
+//! ciborium::into_writer(&claims, &mut cbor).unwrap();
+//! let decoded: Claims = ciborium::from_reader(&cbor[..]).unwrap();
+//! assert_eq!(decoded.amount, U256::MAX);
+//! ```
+//!
+//! Deserialization accepts unsigned integers and tag 2 bignums, with or
+//! without leading zero bytes.
+//!
+//! [RFC 8949 §3.4.3]: https://www.rfc-editor.org/rfc/rfc8949.html#section-3.4.3
+
+#![cfg(feature = "ciborium")]
+#![cfg_attr(docsrs, doc(cfg(feature = "ciborium")))]
+
+use crate::Uint;
+use ciborium::Value;
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+#[allow(unused_imports)]
+use alloc::{boxed::Box, format};
+
+/// CBOR tag for positive bignums.
+const BIGNUM_POSITIVE: u64 = 2;
+
+/// Serializes a [`Uint`] as a CBOR unsigned integer or tag 2 bignum.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match u64::try_from(value) {
+        Ok(value) => serializer.serialize_u64(value),
+        Err(_) => Value::Tag(
+            BIGNUM_POSITIVE,
+            Box::new(Value::Bytes(value.to_be_bytes_trimmed_vec())),
+        )
+        .serialize(serializer),
+    }
+}
+
+/// Deserializes a [`Uint`] from a CBOR unsigned integer or tag 2 bignum.
+///
+/// # Errors
+///
+/// Returns an error if the input is not an unsigned integer or positive
+/// bignum, or if the value does not fit in `BITS` bits.
+pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+    deserializer: D,
+) -> Result<Uint<BITS, LIMBS>, D::Error> {
+    let too_large = || D::Error::custom(format!("value too large for Uint<{BITS}>"));
+    match Value::deserialize(deserializer)? {
+        Value::Integer(integer) => {
+            let value = u128::try_from(integer).map_err(|_| {
+                D::Error::invalid_value(
+                    Unexpected::Other("negative integer"),
+                    &"an unsigned integer",
+                )
+            })?;
+            Uint::try_from(value).map_err(|_| too_large())
+        }
+        Value::Tag(BIGNUM_POSITIVE, content) => match *content {
+            Value::Bytes(bytes) => {
+                let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+                Uint::try_from_be_slice(&bytes[start..]).ok_or_else(too_large)
+            }
+            _ => Err(D::Error::invalid_type(
+                Unexpected::Other("tagged non-bytes"),
+                &"a bignum byte string",
+            )),
+        },
+        _ => Err(D::Error::invalid_type(
+            Unexpected::Other("non-integer"),
+            &"an unsigned integer or tag 2 bignum",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[allow(unused_imports)]
+    use alloc::vec::Vec;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper<const BITS: usize, const LIMBS: usize>(
+        #[serde(with = "super")] Uint<BITS, LIMBS>,
+    );
+
+    fn encode<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut cbor = Vec::new();
+        ciborium::into_writer(value, &mut cbor).unwrap();
+        cbor
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let cbor = encode(&Wrapper(value));
+                let decoded: Wrapper<BITS, LIMBS> = ciborium::from_reader(&cbor[..]).unwrap();
+                assert_eq!(decoded.0, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_canonical() {
+        // Values that fit in a `u64` are plain integers.
+        assert_eq!(encode(&Wrapper(Uint::<256, 4>::from(23))), [0x17]);
+        assert_eq!(
+            encode(&Wrapper(Uint::<256, 4>::from(u64::MAX))),
+            encode(&u64::MAX)
+        );
+        // Larger values are minimal tag 2 bignums.
+        assert_eq!(encode(&Wrapper(Uint::<256, 4>::from(1_u128 << 64))), [
+            0xc2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0
+        ]);
+        let mut expected = vec![0xc2, 0x58, 0x20];
+        expected.extend_from_slice(&[0xff; 32]);
+        assert_eq!(encode(&Wrapper(Uint::<256, 4>::MAX)), expected);
+    }
+
+    #[test]
+    fn test_deserialize_bignum() {
+        // Leading zero bytes are accepted.
+        let cbor = [0xc2, 0x43, 0x00, 0x01, 0x02];
+        let decoded: Wrapper<16, 1> = ciborium::from_reader(&cbor[..]).unwrap();
+        assert_eq!(decoded.0, Uint::from(0x0102));
+
+        // A small bignum that ciborium decodes as an integer.
+        let cbor = [0xc2, 0x41, 0x2a];
+        let decoded: Wrapper<8, 1> = ciborium::from_reader(&cbor[..]).unwrap();
+        assert_eq!(decoded.0, Uint::from(42));
+
+        let mut cbor = vec![0xc2, 0x58, 0x21, 0x00];
+        cbor.extend_from_slice(&[0xff; 32]);
+        let decoded: Wrapper<256, 4> = ciborium::from_reader(&cbor[..]).unwrap();
+        assert_eq!(decoded.0, Uint::MAX);
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        // Too large.
+        let cbor = encode(&Wrapper(Uint::<256, 4>::MAX));
+        assert!(ciborium::from_reader::<Wrapper<255, 4>, _>(&cbor[..]).is_err());
+        let cbor = encode(&256_u64);
+        assert!(ciborium::from_reader::<Wrapper<8, 1>, _>(&cbor[..]).is_err());
+        // Negative integer and negative bignum.
+        assert!(ciborium::from_reader::<Wrapper<64, 1>, _>(&[0x20][..]).is_err());
+        let cbor = [0xc3, 0x41, 0x01];
+        assert!(ciborium::from_reader::<Wrapper<64, 1>, _>(&cbor[..]).is_err());
+        // Text.
+        assert!(ciborium::from_reader::<Wrapper<64, 1>, _>(&[0x61, 0x31][..]).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! Support for the [`clap`](https://crates.io/crates/clap) crate.
+//!
+//! Implements [`ValueParserFactory`] for [`Uint`], so it can be used directly
+//! as an argument type with both the builder and the derive API. The value
+//! parser accepts:
+//!
+//! * `0x`, `0o` and `0b` prefixed numbers, e.g. `0xff`, with `_` separators.
+//! * Anything accepted by [`Uint::from_str_scientific`]: decimal numbers with
+//!   an optional exponent and SI or binary suffix, e.g. `30M`, `2.5e6` or `3
+//!   Gi`, as long as the value is an integer.
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! # use clap::Parser;
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[arg(long)]
+//!     gas_limit: U256,
+//!     #[arg(long)]
+//!     value:     U256,
+//! }
+//!
+//! let args = Args::parse_from(["node", "--gas-limit", "30M", "--value", "0xff"]);
+//! assert_eq!(args.gas_limit, U256::from(30_000_000));
+//! assert_eq!(args.value, U256::from(255));
+//! ```
+
+#![cfg(feature = "clap")]
+#![cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+
+use crate::{ParseOptions, Uint};
+use clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command, Error,
+};
+use std::ffi::OsStr;
+
+/// Parses a [`Uint`] argument, see the [module documentation](self).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UintValueParser<const BITS: usize, const LIMBS: usize>;
+
+impl<const BITS: usize, const LIMBS: usize> UintValueParser<BITS, LIMBS> {
+    /// Parses `src`, returning a description of the problem on failure.
+    fn parse(src: &str) -> Result<Uint<BITS, LIMBS>, String> {
+        let src = src.trim();
+        let prefixed = matches!(src.get(..2), Some("0x" | "0X" | "0o" | "0O" | "0b" | "0B"));
+        let parsed = if prefixed {
+            let options = ParseOptions::new().prefixes(true).separators(true);
+            Uint::from_str_with(src, &options)
+        } else {
+            Uint::from_str_scientific(src)
+        };
+        parsed.map_err(|err| err.to_string())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TypedValueParser for UintValueParser<BITS, LIMBS> {
+    type Value = Uint<BITS, LIMBS>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let error = |reason: &str| {
+            let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+            let value = value.to_string_lossy();
+            let message = format!("invalid value '{value}' for '{arg}': {reason}\n");
+            Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+        };
+        let value = value.to_str().ok_or_else(|| error("invalid UTF-8"))?;
+        Self::parse(value).map_err(|reason| error(&reason))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ValueParserFactory for Uint<BITS, LIMBS> {
+    type Parser = UintValueParser<BITS, LIMBS>;
+
+    fn value_parser() -> Self::Parser {
+        UintValueParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use clap::{value_parser, Parser};
+    use proptest::proptest;
+
+    fn parse(src: &str) -> Result<U256, String> {
+        UintValueParser::parse(src)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type P = UintValueParser<BITS, LIMBS>;
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                assert_eq!(P::parse(&value.to_string()), Ok(value));
+                assert_eq!(P::parse(&format!("{value:#x}")), Ok(value));
+                assert_eq!(P::parse(&format!("{value:#b}")), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_suffixes() {
+        assert_eq!(parse("30M"), Ok(U256::from(30_000_000)));
+        assert_eq!(parse("10k"), Ok(U256::from(10_000)));
+        assert_eq!(parse("3 Gi"), Ok(U256::from(3_u64 << 30)));
+        assert_eq!(parse("2.5e6"), Ok(U256::from(2_500_000)));
+        assert_eq!(parse("1.5k"), Ok(U256::from(1_500)));
+        assert_eq!(parse("1_000E"), Ok(U256::from(10).pow(U256::from(21))));
+        assert_eq!(parse(" 42 "), Ok(U256::from(42)));
+        assert_eq!(parse("0xE"), Ok(U256::from(14)));
+        assert_eq!(UintValueParser::<8, 1>::parse("0k"), Ok(Uint::ZERO));
+        assert_eq!(
+            UintValueParser::<8, 1>::parse("2k"),
+            Err("the value is too large to fit the target type at byte 0".into())
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse(""), Err("no digits at byte 0".into()));
+        assert_eq!(parse("0x"), Err("no digits at byte 2".into()));
+        assert_eq!(parse("0x!"), Err("invalid digit '!' at byte 2".into()));
+        assert_eq!(
+            parse("1.5"),
+            Err("the value is not an integer at byte 0".into())
+        );
+        assert!(parse("5m").unwrap_err().starts_with("unknown suffix"));
+        assert!(parse(&format!("{}1", U256::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_command() {
+        #[derive(Parser)]
+        struct Args {
+            #[arg(long)]
+            amount: U256,
+            #[arg(long)]
+            limit:  Option<Uint<64, 1>>,
+        }
+
+        let args = Args::try_parse_from(["test", "--amount", "1k", "--limit", "0b101"]).unwrap();
+        assert_eq!(args.amount, U256::from(1000));
+        assert_eq!(args.limit, Some(Uint::from(5)));
+
+        let err = Args::try_parse_from(["test", "--amount", "1x"])
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        assert!(err
+            .to_string()
+            .contains("invalid value '1x' for '--amount <AMOUNT>': unknown suffix"));
+
+        let command = Command::new("test").arg(Arg::new("n").value_parser(value_parser!(U256)));
+        let matches = command.try_get_matches_from(["test", "0o17"]).unwrap();
+        assert_eq!(matches.get_one::<U256>("n"), Some(&U256::from(15)));
+    }
+}
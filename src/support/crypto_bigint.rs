@@ -0,0 +1,169 @@
+//! Support for the [`crypto-bigint`](https://crates.io/crates/crypto-bigint) crate.
+//!
+//! The conversions are checked both ways, as the number of bits of
+//! [`crypto_bigint::Uint<L>`] depends on the target's word size.
+#![cfg(feature = "crypto-bigint")]
+#![cfg_attr(docsrs, doc(cfg(feature = "crypto-bigint")))]
+
+use crate::{
+    from::{FromUintError, ToUintError},
+    NonZeroUint, Uint,
+};
+use crypto_bigint::{Limb, NonZero, Uint as CUint, Word};
+
+/// Number of [`Word`]s in a `u64` limb: one on 64-bit targets, two on
+/// 32-bit targets.
+const WORDS_PER_LIMB: usize = 64 / Limb::BITS;
+
+impl<const BITS: usize, const LIMBS: usize, const L: usize> TryFrom<CUint<L>>
+    for Uint<BITS, LIMBS>
+{
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: CUint<L>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const L: usize> TryFrom<&CUint<L>>
+    for Uint<BITS, LIMBS>
+{
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    #[allow(clippy::useless_conversion, clippy::modulo_one)] // Depend on the word size
+    fn try_from(value: &CUint<L>) -> Result<Self, Self::Error> {
+        let mut limbs = [0; LIMBS];
+        let mut overflow = false;
+        for (i, &word) in value.as_words().iter().enumerate() {
+            let shift = (i % WORDS_PER_LIMB) * Limb::BITS;
+            match limbs.get_mut(i / WORDS_PER_LIMB) {
+                Some(limb) => *limb |= u64::from(word) << shift,
+                None => overflow |= word != 0,
+            }
+        }
+        if LIMBS > 0 {
+            overflow |= limbs[LIMBS - 1] > Self::MASK;
+            limbs[LIMBS - 1] &= Self::MASK;
+        }
+        let n = Self::from_limbs(limbs);
+        if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, n))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const L: usize> TryFrom<Uint<BITS, LIMBS>>
+    for CUint<L>
+{
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const L: usize> TryFrom<&Uint<BITS, LIMBS>>
+    for CUint<L>
+{
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::modulo_one)] // Intentional
+    fn try_from(value: &Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let limbs = value.as_limbs();
+        let mut words: [Word; L] = [0; L];
+        for (i, word) in words.iter_mut().enumerate() {
+            if let Some(limb) = limbs.get(i / WORDS_PER_LIMB) {
+                *word = (limb >> ((i % WORDS_PER_LIMB) * Limb::BITS)) as Word;
+            }
+        }
+        let n = Self::from_words(words);
+        if value.bit_len() > Self::BITS {
+            Err(FromUintError::Overflow(BITS, n, Self::MAX))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const L: usize> TryFrom<NonZero<CUint<L>>>
+    for NonZeroUint<BITS, LIMBS>
+{
+    type Error = ToUintError<Uint<BITS, LIMBS>>;
+
+    #[inline]
+    fn try_from(value: NonZero<CUint<L>>) -> Result<Self, Self::Error> {
+        let n = Uint::try_from(value.as_ref())?;
+        // SAFETY: The conversion is lossless and `value` is non-zero.
+        Ok(unsafe { Self::new_unchecked(n) })
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const L: usize> TryFrom<NonZeroUint<BITS, LIMBS>>
+    for NonZero<CUint<L>>
+{
+    type Error = FromUintError<CUint<L>>;
+
+    #[inline]
+    fn try_from(value: NonZeroUint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let n = CUint::try_from(value.get())?;
+        Ok(Self::new(n).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use crypto_bigint::{U128, U256, U64};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                // At least one limb and large enough on all targets.
+                let big: CUint<{ 2 * LIMBS + 1 }> = value.try_into().unwrap();
+                assert_eq!(U::try_from(big), Ok(value));
+
+                if let Some(value) = NonZeroUint::new(value) {
+                    let big: NonZero<CUint<{ 2 * LIMBS + 1 }>> = value.try_into().unwrap();
+                    assert_eq!(NonZeroUint::try_from(big), Ok(value));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_overflow() {
+        let value = U128::from_u128(0x0123_4567_89ab_cdef_0011_2233_4455_6677);
+        let n = Uint::<128, 2>::try_from(value).unwrap();
+        assert_eq!(
+            n,
+            Uint::from(0x0123_4567_89ab_cdef_0011_2233_4455_6677_u128)
+        );
+        assert_eq!(
+            Uint::<72, 2>::try_from(value),
+            Err(ToUintError::ValueTooLarge(
+                72,
+                Uint::from(0xef_0011_2233_4455_6677_u128)
+            ))
+        );
+        assert_eq!(
+            U64::try_from(n),
+            Err(FromUintError::Overflow(
+                128,
+                U64::from_u64(0x0011_2233_4455_6677),
+                U64::MAX
+            ))
+        );
+        assert_eq!(U256::try_from(n), Ok(U256::from_u128(n.to())));
+    }
+}
@@ -0,0 +1,217 @@
+//! Support for the [`diesel`](https://crates.io/crates/diesel) crate.
+//!
+//! Implements [`ToSql`] and [`FromSql`] for the PostgreSQL `NUMERIC` and
+//! `BYTEA` types, together with [`AsExpression`] and [`FromSqlRow`], so
+//! [`Uint`] can be used directly in `table!` columns and `Queryable` structs.
+//!
+//! * `NUMERIC` stores the value as a decimal integer. Reading fails for
+//!   negative, fractional or too large values.
+//! * `BYTEA` stores the value as [`Uint::BYTES`] big-endian bytes. Reading
+//!   accepts shorter inputs.
+//!
+//! [`AsExpression`]: diesel::expression::AsExpression
+//! [`FromSqlRow`]: diesel::deserialize::FromSqlRow
+
+#![cfg(feature = "diesel")]
+#![cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+
+use crate::{utils::trim_end_vec, Uint};
+use diesel::{
+    deserialize::{self, FromSql},
+    pg::{data_types::PgNumeric, Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{Binary, Numeric},
+};
+use std::{io::Write, num::TryFromIntError};
+use thiserror::Error;
+
+#[allow(dead_code)]
+mod foreign_derives {
+    use super::{Binary, Numeric, Uint};
+    use diesel::{deserialize::FromSqlRow, expression::AsExpression};
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Numeric)]
+    #[diesel(sql_type = Binary)]
+    struct UintProxy<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Error)]
+pub enum FromSqlError {
+    #[error("The value is too large for the Uint type")]
+    Overflow,
+
+    #[error("The NUMERIC value is not a non-negative integer")]
+    NotAnInteger,
+
+    #[error("Invalid NUMERIC digit {0}")]
+    InvalidDigit(i16),
+}
+
+/// Converts to base 10000 digits without trailing zeros.
+fn to_numeric<const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+) -> Result<PgNumeric, TryFromIntError> {
+    let mut digits: Vec<_> = value.to_base_be(10000).collect();
+    let weight = digits.len().saturating_sub(1).try_into()?;
+    trim_end_vec(&mut digits, &0);
+    #[allow(clippy::cast_possible_truncation)] // 10000 < i16::MAX
+    let digits = digits.into_iter().map(|digit| digit as i16).collect();
+    Ok(PgNumeric::Positive {
+        weight,
+        scale: 0,
+        digits,
+    })
+}
+
+fn from_numeric<const BITS: usize, const LIMBS: usize>(
+    numeric: PgNumeric,
+) -> Result<Uint<BITS, LIMBS>, FromSqlError> {
+    let PgNumeric::Positive { weight, digits, .. } = numeric else {
+        return Err(FromSqlError::NotAnInteger);
+    };
+    if let Some(&digit) = digits.iter().find(|&&digit| !(0..10000).contains(&digit)) {
+        return Err(FromSqlError::InvalidDigit(digit));
+    }
+    // The first digit is multiplied by `10000^weight`, so there are
+    // `weight + 1` integer digits.
+    let integer_digits = usize::try_from(i32::from(weight) + 1).unwrap_or(0);
+    let (integer, fraction) = digits.split_at(integer_digits.min(digits.len()));
+    if fraction.iter().any(|&digit| digit != 0) {
+        return Err(FromSqlError::NotAnInteger);
+    }
+    #[allow(clippy::cast_sign_loss)] // Digits are checked
+    let integer = integer.iter().map(|&digit| digit as u64);
+    let zeros = std::iter::repeat(0).take(integer_digits - integer.len());
+    Uint::from_base_be(10000, integer.chain(zeros)).map_err(|_| FromSqlError::Overflow)
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Numeric, Pg> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let numeric = to_numeric(self)?;
+        ToSql::<Numeric, Pg>::to_sql(&numeric, &mut out.reborrow())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Numeric, Pg> for Uint<BITS, LIMBS> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let numeric = PgNumeric::from_sql(value)?;
+        Ok(from_numeric(numeric)?)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSql<Binary, Pg> for Uint<BITS, LIMBS> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&self.to_be_bytes_vec())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromSql<Binary, Pg> for Uint<BITS, LIMBS> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::try_from_be_slice(value.as_bytes()).ok_or(FromSqlError::Overflow)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use diesel::{
+        debug_query, deserialize::Queryable, expression::AsExpression, prelude::*,
+        sql_types::Nullable,
+    };
+    use proptest::proptest;
+
+    diesel::table! {
+        balances (id) {
+            id -> Int4,
+            amount -> Numeric,
+            hash -> Bytea,
+        }
+    }
+
+    fn numeric(weight: i16, digits: &[i16]) -> PgNumeric {
+        PgNumeric::Positive {
+            weight,
+            scale: 0,
+            digits: digits.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_numeric_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let numeric = to_numeric(&value).unwrap();
+                assert_eq!(from_numeric(numeric), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_numeric_digits() {
+        assert_eq!(to_numeric(&U256::ZERO).unwrap(), numeric(0, &[]));
+        assert_eq!(
+            to_numeric(&U256::from(1_2345_0000_u64)).unwrap(),
+            numeric(2, &[1, 2345])
+        );
+        // Trailing zeros and zero fractional digits are accepted.
+        assert_eq!(from_numeric(numeric(1, &[1, 0, 0])), Ok(U256::from(1_0000)));
+        assert_eq!(from_numeric(numeric(-1, &[0])), Ok(U256::ZERO));
+    }
+
+    #[test]
+    fn test_numeric_errors() {
+        assert_eq!(
+            from_numeric::<256, 4>(numeric(0, &[1, 5])),
+            Err(FromSqlError::NotAnInteger)
+        );
+        assert_eq!(
+            from_numeric::<256, 4>(numeric(-1, &[5])),
+            Err(FromSqlError::NotAnInteger)
+        );
+        assert_eq!(
+            from_numeric::<256, 4>(PgNumeric::NaN),
+            Err(FromSqlError::NotAnInteger)
+        );
+        assert_eq!(
+            from_numeric::<256, 4>(PgNumeric::Negative {
+                weight: 0,
+                scale:  0,
+                digits: vec![1],
+            }),
+            Err(FromSqlError::NotAnInteger)
+        );
+        assert_eq!(
+            from_numeric::<256, 4>(numeric(0, &[10000])),
+            Err(FromSqlError::InvalidDigit(10000))
+        );
+        assert_eq!(
+            from_numeric::<8, 1>(numeric(0, &[256])),
+            Err(FromSqlError::Overflow)
+        );
+        assert_eq!(
+            from_numeric::<256, 4>(numeric(20, &[1])),
+            Err(FromSqlError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_expressions() {
+        fn queryable<T: Queryable<Numeric, Pg> + Queryable<Binary, Pg>>() {}
+        fn nullable<T: AsExpression<Nullable<Numeric>> + AsExpression<Nullable<Binary>>>() {}
+        queryable::<U256>();
+        nullable::<U256>();
+
+        let value = U256::from(42);
+        let query = balances::table
+            .filter(balances::amount.eq(value))
+            .filter(balances::hash.eq(&value))
+            .select((balances::amount, balances::hash));
+        let sql = debug_query::<Pg, _>(&query).to_string();
+        assert!(sql.contains("\"balances\".\"amount\" = $1"), "{sql}");
+    }
+}
@@ -0,0 +1,174 @@
+//! Support for the [`ethnum`](https://crates.io/crates/ethnum) crate.
+//!
+//! Conversions are provided between [`Uint`] and [`U256`], and between
+//! [`Int`] and [`I256`], of any size. They fail if the value does not fit.
+#![cfg(feature = "ethnum")]
+#![cfg_attr(docsrs, doc(cfg(feature = "ethnum")))]
+
+use crate::{FromIntError, FromUintError, Int, ToIntError, ToUintError, Uint};
+use ethnum::{I256, U256};
+
+#[allow(clippy::cast_possible_truncation)] // Intentional
+const fn to_limbs(value: U256) -> [u64; 4] {
+    let (hi, lo) = value.into_words();
+    [lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64]
+}
+
+/// Truncates the limbs to 256 bits, returning whether any were dropped.
+fn from_limbs(limbs: &[u64]) -> (U256, bool) {
+    let limb = |i| u128::from(limbs.get(i).copied().unwrap_or_default());
+    let value = U256::from_words(limb(3) << 64 | limb(2), limb(1) << 64 | limb(0));
+    let truncated = limbs.iter().skip(4).any(|&limb| limb != 0);
+    (value, truncated)
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<U256> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        match Self::overflowing_from_limbs_slice(&to_limbs(value)) {
+            (n, false) => Ok(n),
+            (n, true) => Err(ToUintError::ValueTooLarge(BITS, n)),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&U256> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &U256) -> Result<Self, Self::Error> {
+        Self::try_from(*value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Uint<BITS, LIMBS>> for U256 {
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Uint<BITS, LIMBS>> for U256 {
+    type Error = FromUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        match from_limbs(value.as_limbs()) {
+            (n, false) => Ok(n),
+            (n, true) => Err(FromUintError::Overflow(BITS, n, Self::MAX)),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<I256> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    fn try_from(value: I256) -> Result<Self, Self::Error> {
+        Self::try_from_sign_and_abs_limbs(value.is_negative(), &to_limbs(value.unsigned_abs()))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&I256> for Int<BITS, LIMBS> {
+    type Error = ToIntError<Self>;
+
+    #[inline]
+    fn try_from(value: &I256) -> Result<Self, Self::Error> {
+        Self::try_from(*value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Int<BITS, LIMBS>> for I256 {
+    type Error = FromIntError<Self>;
+
+    #[inline]
+    fn try_from(value: Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Int<BITS, LIMBS>> for I256 {
+    type Error = FromIntError<Self>;
+
+    #[inline]
+    fn try_from(value: &Int<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let (negative, abs) = value.into_sign_and_abs();
+        let (abs, truncated) = from_limbs(abs.as_limbs());
+        let n = if negative { abs.wrapping_neg() } else { abs }.as_i256();
+        if !truncated && n.is_negative() == negative {
+            Ok(n)
+        } else if negative {
+            Err(FromIntError::Underflow(BITS, n, Self::MIN))
+        } else {
+            Err(FromIntError::Overflow(BITS, n, Self::MAX))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES if (BITS < 256) {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            type I = Int<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big = U256::try_from(value).unwrap();
+                assert_eq!(U::try_from(big), Ok(value));
+
+                let value = I::from_raw(value);
+                let big = I256::try_from(value).unwrap();
+                assert_eq!(I::try_from(big), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_256() {
+        type U = Uint<256, 4>;
+        type I = Int<256, 4>;
+        proptest!(|(value: U)| {
+            let big = U256::try_from(value).unwrap();
+            assert_eq!(big.to_string(), value.to_string());
+            assert_eq!(U::try_from(big), Ok(value));
+
+            let value = I::from_raw(value);
+            let big = I256::try_from(value).unwrap();
+            assert_eq!(big.to_string(), value.to_string());
+            assert_eq!(I::try_from(big), Ok(value));
+        });
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(
+            Uint::<255, 4>::try_from(U256::MAX),
+            Err(ToUintError::ValueTooLarge(255, Uint::MAX))
+        );
+        assert_eq!(
+            U256::try_from(Uint::<512, 8>::MAX),
+            Err(FromUintError::Overflow(512, U256::MAX, U256::MAX))
+        );
+        assert_eq!(
+            Int::<255, 4>::try_from(I256::MIN),
+            Err(ToIntError::ValueTooSmall(255, Int::ZERO))
+        );
+        assert_eq!(
+            I256::try_from(Int::<512, 8>::MIN),
+            Err(FromIntError::Underflow(512, I256::ZERO, I256::MIN))
+        );
+        assert_eq!(
+            I256::try_from(Int::<512, 8>::from_raw(Uint::from(1) << 255_usize)),
+            Err(FromIntError::Overflow(512, I256::MIN, I256::MAX))
+        );
+    }
+}
@@ -6,40 +6,53 @@ mod alloy_rlp;
 mod arbitrary;
 mod ark_ff;
 mod ark_ff_04;
+pub mod arrow;
+mod bitvec;
 mod bn_rs;
+mod bnum;
+pub mod bson;
 mod bytemuck;
+pub mod ciborium;
+pub mod clap;
+mod crypto_bigint;
+pub mod diesel;
+mod ethnum;
 mod fastrlp;
 mod num_bigint;
+mod num_integer;
 mod num_traits;
 pub mod postgres;
 mod primitive_types;
-mod proptest;
+pub mod proptest;
+pub mod prost;
 mod pyo3;
 mod quickcheck;
-mod rand;
+pub mod rand;
+pub mod redis;
+pub mod rkyv;
 mod rlp;
+mod rug;
 pub mod scale;
-mod serde;
+pub mod schemars;
+pub mod sea_orm;
+pub mod serde;
 pub mod sqlx;
 pub mod ssz;
+mod subtle;
+pub mod utoipa;
 mod valuable;
-mod zeroize;
+pub mod zerocopy;
+pub mod zeroize;
 
 // FEATURE: Support for many more traits and crates.
 // * https://crates.io/crates/der
-// * https://crates.io/crates/bitvec
 
 // * open-fastrlp
 
 // Big int types:
-// * https://crates.io/crates/crypto-bigint
-// * https://crates.io/crates/rug
 // * https://crates.io/crates/bigdecimal
 // * https://crates.io/crates/rust_decimal
 
 // * wasm-bindgen `JsValue` bigint: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html#method.bigint_from_str
 //   or from_f64.
 // * Neon `JsBigInt` once it lands: https://github.com/neon-bindings/neon/pull/861
-
-// More databases:
-// * https://crates.io/crates/diesel
@@ -6,6 +6,9 @@
 use crate::{from::ToUintError, Uint};
 use num_bigint::{BigInt, BigUint, Sign};
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 impl<const BITS: usize, const LIMBS: usize> TryFrom<BigUint> for Uint<BITS, LIMBS> {
     type Error = ToUintError<Self>;
 
@@ -19,7 +22,19 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<&BigUint> for Uint<BITS, LIM
     type Error = ToUintError<Self>;
 
     fn try_from(value: &BigUint) -> Result<Self, Self::Error> {
-        let (n, overflow) = Self::overflowing_from_limbs_slice(value.to_u64_digits().as_slice());
+        let mut limbs = [0; LIMBS];
+        let mut overflow = false;
+        for (i, digit) in value.iter_u64_digits().enumerate() {
+            match limbs.get_mut(i) {
+                Some(limb) => *limb = digit,
+                None => overflow = true,
+            }
+        }
+        if LIMBS > 0 {
+            overflow |= limbs[LIMBS - 1] > Self::MASK;
+            limbs[LIMBS - 1] &= Self::MASK;
+        }
+        let n = Self::from_limbs(limbs);
         if overflow {
             Err(ToUintError::ValueTooLarge(BITS, n))
         } else {
@@ -35,8 +50,16 @@ impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for BigUint
 }
 
 impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for BigUint {
+    #[allow(clippy::cast_possible_truncation)] // Intentional
     fn from(value: &Uint<BITS, LIMBS>) -> Self {
-        Self::from_bytes_le(&value.as_le_bytes())
+        // `BigUint` stores `u32` or `u64` digits depending on the target, but
+        // only exposes a `u32` constructor. It strips the leading zeros.
+        let digits = value
+            .as_limbs()
+            .iter()
+            .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+            .collect::<Vec<_>>();
+        Self::new(digits)
     }
 }
 
@@ -52,14 +75,11 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<&BigInt> for Uint<BITS, LIMB
     type Error = ToUintError<Self>;
 
     fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
-        let (sign, digits) = value.to_u64_digits();
-        let (n, overflow) = Self::overflowing_from_limbs_slice(digits.as_slice());
-        if sign == Sign::Minus {
-            Err(ToUintError::ValueNegative(BITS, n))
-        } else if overflow {
-            Err(ToUintError::ValueTooLarge(BITS, n))
-        } else {
-            Ok(n)
+        match (value.sign(), Self::try_from(value.magnitude())) {
+            (Sign::Minus, Ok(n) | Err(ToUintError::ValueTooLarge(_, n))) => {
+                Err(ToUintError::ValueNegative(BITS, n))
+            }
+            (_, result) => result,
         }
     }
 }
@@ -72,7 +92,7 @@ impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for BigInt {
 
 impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for BigInt {
     fn from(value: &Uint<BITS, LIMBS>) -> Self {
-        Self::from_bytes_le(Sign::Plus, &value.as_le_bytes())
+        Self::from_biguint(Sign::Plus, value.into())
     }
 }
 
@@ -96,6 +116,24 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_errors() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big = BigUint::from(value);
+                assert_eq!(big.bits(), value.bit_len() as u64);
+                let larger = &big + (BigUint::from(1_u8) << BITS);
+                assert_eq!(U::try_from(larger), Err(ToUintError::ValueTooLarge(BITS, value)));
+                if value != U::ZERO {
+                    let negative = -BigInt::from(value);
+                    assert_eq!(U::try_from(negative), Err(ToUintError::ValueNegative(BITS, value)));
+                }
+            });
+        });
+    }
+
     #[test]
     #[allow(clippy::unreadable_literal)]
     fn test_roundtrip_bigint() {
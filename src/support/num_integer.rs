@@ -0,0 +1,160 @@
+//! Support for the [`num-integer`](https://crates.io/crates/num-integer) crate.
+#![cfg(feature = "num-integer")]
+#![cfg_attr(docsrs, doc(cfg(feature = "num-integer")))]
+// This is a particularly big risk with these traits. Make sure
+// to call functions on the `Uint::` type.
+#![deny(unconditional_recursion)]
+use crate::Uint;
+use num_integer::{Integer, Roots};
+
+impl<const BITS: usize, const LIMBS: usize> Integer for Uint<BITS, LIMBS> {
+    // For unsigned numbers floored and truncated division coincide.
+    #[inline(always)]
+    #[track_caller]
+    fn div_floor(&self, other: &Self) -> Self {
+        *self / *other
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn mod_floor(&self, other: &Self) -> Self {
+        *self % *other
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn div_ceil(&self, other: &Self) -> Self {
+        <Self>::div_ceil(*self, *other)
+    }
+
+    #[inline(always)]
+    fn gcd(&self, other: &Self) -> Self {
+        <Self>::gcd(*self, *other)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in `Self`.
+    #[inline]
+    #[track_caller]
+    fn lcm(&self, other: &Self) -> Self {
+        <Self>::lcm(*self, *other).expect("lcm overflow")
+    }
+
+    #[inline]
+    #[track_caller]
+    fn gcd_lcm(&self, other: &Self) -> (Self, Self) {
+        let gcd = <Self>::gcd(*self, *other);
+        let lcm = (*self)
+            .checked_mul(other.checked_div(gcd).unwrap_or_default())
+            .expect("lcm overflow");
+        (gcd, lcm)
+    }
+
+    #[inline(always)]
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        <Self>::is_multiple_of(*self, *other)
+    }
+
+    #[inline(always)]
+    fn is_even(&self) -> bool {
+        !self.bit(0)
+    }
+
+    #[inline(always)]
+    fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        <Self>::div_rem(*self, *other)
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn div_mod_floor(&self, other: &Self) -> (Self, Self) {
+        <Self>::div_rem(*self, *other)
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn next_multiple_of(&self, other: &Self) -> Self {
+        <Self>::next_multiple_of(*self, *other)
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn prev_multiple_of(&self, other: &Self) -> Self {
+        *self - *self % *other
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Roots for Uint<BITS, LIMBS> {
+    #[inline(always)]
+    #[track_caller]
+    fn nth_root(&self, n: u32) -> Self {
+        <Self>::root(*self, n as usize)
+    }
+
+    #[inline(always)]
+    fn sqrt(&self) -> Self {
+        <Self>::sqrt(*self)
+    }
+
+    #[inline(always)]
+    fn cbrt(&self) -> Self {
+        <Self>::cbrt(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U64, const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_integer_u64() {
+        proptest!(|(a: u64, b: u64)| {
+            let (ua, ub) = (U64::from(a), U64::from(b));
+            assert_eq!(Integer::gcd(&ua, &ub), U64::from(Integer::gcd(&a, &b)));
+            assert_eq!(Integer::is_multiple_of(&ua, &ub), Integer::is_multiple_of(&a, &b));
+            assert_eq!(ua.divides(&ub), a.divides(&b));
+            assert_eq!(ua.is_even(), a.is_even());
+            assert_eq!(ua.is_odd(), a.is_odd());
+            let gcd = Integer::gcd(&a, &b);
+            if let Some(lcm) = b.checked_div(gcd).map_or(Some(0), |b| a.checked_mul(b)) {
+                assert_eq!(Integer::lcm(&ua, &ub), U64::from(lcm));
+                assert_eq!(ua.gcd_lcm(&ub), (U64::from(gcd), U64::from(lcm)));
+            }
+            if let Some(q) = a.checked_div(b) {
+                let r = a % b;
+                assert_eq!(Integer::div_floor(&ua, &ub), U64::from(q));
+                assert_eq!(ua.mod_floor(&ub), U64::from(r));
+                assert_eq!(Integer::div_ceil(&ua, &ub), U64::from(Integer::div_ceil(&a, &b)));
+                assert_eq!(Integer::div_rem(&ua, &ub), (U64::from(q), U64::from(r)));
+                assert_eq!(ua.div_mod_floor(&ub), Integer::div_rem(&ua, &ub));
+                assert_eq!(ua.prev_multiple_of(&ub), U64::from(a - r));
+                if let Some(next) = a.checked_next_multiple_of(b) {
+                    assert_eq!(Integer::next_multiple_of(&ua, &ub), U64::from(next));
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_roots() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, degree in 1_u32..10)| {
+                assert_eq!(Roots::sqrt(&value), value.root(2));
+                assert_eq!(Roots::cbrt(&value), value.root(3));
+                assert_eq!(value.nth_root(degree), value.root(degree as usize));
+            });
+        });
+    }
+}
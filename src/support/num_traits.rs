@@ -9,7 +9,7 @@ use core::ops::{Shl, Shr};
 use num_traits::{
     bounds::Bounded,
     cast::{FromPrimitive, ToPrimitive},
-    identities::{One, Zero},
+    identities::{ConstOne, ConstZero, One, Zero},
     int::PrimInt,
     ops::{
         bytes::{FromBytes, ToBytes},
@@ -46,6 +46,20 @@ impl<const BITS: usize, const LIMBS: usize> Zero for Uint<BITS, LIMBS> {
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize> ConstZero for Uint<BITS, LIMBS> {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConstOne for Uint<BITS, LIMBS> {
+    const ONE: Self = Self::from_limbs({
+        let mut limbs = [0; LIMBS];
+        if BITS > 0 {
+            limbs[0] = 1;
+        }
+        limbs
+    });
+}
+
 impl<const BITS: usize, const LIMBS: usize> One for Uint<BITS, LIMBS> {
     #[inline(always)]
     fn one() -> Self {
@@ -293,6 +307,54 @@ impl<const BITS: usize, const LIMBS: usize> Pow<Self> for Uint<BITS, LIMBS> {
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize> Pow<&Self> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn pow(self, rhs: &Self) -> Self::Output {
+        <Self>::pow(self, *rhs)
+    }
+}
+
+/// Wrapping power with a primitive exponent, which may not fit in `Self`.
+fn pow_u64<const BITS: usize, const LIMBS: usize>(
+    base: Uint<BITS, LIMBS>,
+    exp: u64,
+) -> Uint<BITS, LIMBS> {
+    if BITS >= 64 {
+        base.pow(Uint::from(exp))
+    } else {
+        // Reducing modulo 2^64 first does not change the result modulo 2^BITS.
+        Uint::<64, 1>::from(base).pow(Uint::from(exp)).wrapping_to()
+    }
+}
+
+macro_rules! impl_pow_primitive {
+    ($($t:ty),*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> Pow<$t> for Uint<BITS, LIMBS> {
+            type Output = Self;
+
+            #[inline(always)]
+            #[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+            fn pow(self, rhs: $t) -> Self::Output {
+                pow_u64(self, rhs as u64)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> Pow<&$t> for Uint<BITS, LIMBS> {
+            type Output = Self;
+
+            #[inline(always)]
+            #[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+            fn pow(self, rhs: &$t) -> Self::Output {
+                pow_u64(self, *rhs as u64)
+            }
+        }
+    )*};
+}
+
+impl_pow_primitive!(u8, u16, u32, u64, usize);
+
 impl<const BITS: usize, const LIMBS: usize> Unsigned for Uint<BITS, LIMBS> {}
 
 impl<const BITS: usize, const LIMBS: usize> ToPrimitive for Uint<BITS, LIMBS> {
@@ -315,6 +377,18 @@ impl<const BITS: usize, const LIMBS: usize> ToPrimitive for Uint<BITS, LIMBS> {
     fn to_u128(&self) -> Option<u128> {
         self.try_into().ok()
     }
+
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.into())
+    }
+
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.into())
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> FromPrimitive for Uint<BITS, LIMBS> {
@@ -337,6 +411,18 @@ impl<const BITS: usize, const LIMBS: usize> FromPrimitive for Uint<BITS, LIMBS>
     fn from_u128(n: u128) -> Option<Self> {
         Self::try_from(n).ok()
     }
+
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> NumCast for Uint<BITS, LIMBS> {
@@ -412,11 +498,10 @@ impl<const BITS: usize, const LIMBS: usize> PrimInt for Uint<BITS, LIMBS> {
         <Self>::shr(self, n as usize)
     }
 
-    /// Note: This is not well-defined when `BITS % 8 != 0`.
+    /// Panics if `BITS` is not a multiple of 8, see [`Uint::swap_bytes`].
+    #[inline(always)]
     fn swap_bytes(self) -> Self {
-        let mut bytes = self.to_be_bytes_vec();
-        bytes.reverse();
-        Self::try_from_be_slice(&bytes).unwrap()
+        <Self>::swap_bytes(self)
     }
 
     #[inline(always)]
@@ -426,51 +511,39 @@ impl<const BITS: usize, const LIMBS: usize> PrimInt for Uint<BITS, LIMBS> {
 
     #[inline(always)]
     fn from_be(x: Self) -> Self {
-        if cfg!(target_endian = "big") {
-            x
-        } else {
-            x.swap_bytes()
-        }
+        <Self>::from_be(x)
     }
 
     #[inline(always)]
     fn from_le(x: Self) -> Self {
-        if cfg!(target_endian = "little") {
-            x
-        } else {
-            x.swap_bytes()
-        }
+        <Self>::from_le(x)
     }
 
     #[inline(always)]
     fn to_be(self) -> Self {
-        if cfg!(target_endian = "big") {
-            self
-        } else {
-            self.swap_bytes()
-        }
+        <Self>::to_be(self)
     }
 
     #[inline(always)]
     fn to_le(self) -> Self {
-        if cfg!(target_endian = "little") {
-            self
-        } else {
-            self.swap_bytes()
-        }
+        <Self>::to_le(self)
     }
 
     #[inline(always)]
     fn pow(self, exp: u32) -> Self {
-        self.pow(Self::from(exp))
+        pow_u64(self, exp.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::aliases::{U256, U64};
+    use crate::{
+        aliases::{U256, U64},
+        const_for, nlimbs,
+    };
     use num_traits::bounds::{LowerBounded, UpperBounded};
+    use proptest::proptest;
 
     macro_rules! assert_impl{
         ($type:ident, $($trait:tt),*) => {
@@ -486,7 +559,7 @@ mod tests {
         // All applicable traits from num-traits (except AsPrimitive).
         assert_impl!(U256, Bounded, LowerBounded, UpperBounded);
         assert_impl!(U256, FromPrimitive, NumCast, ToPrimitive);
-        assert_impl!(U256, One, Zero);
+        assert_impl!(U256, ConstOne, ConstZero, One, Zero);
         assert_impl!(U256, PrimInt);
         assert_impl!(U256, FromBytes, ToBytes);
         assert_impl!(
@@ -513,10 +586,45 @@ mod tests {
             WrappingShr,
             WrappingSub
         );
-        assert_impl!(U256, (Pow<U256>));
+        assert_impl!(U256, (Pow<U256>), (Pow<&'static U256>));
+        assert_impl!(
+            U256,
+            (Pow<u8>),
+            (Pow<u16>),
+            (Pow<u32>),
+            (Pow<u64>),
+            (Pow<usize>)
+        );
+        assert_impl!(U256, (Pow<&'static u32>), (Pow<&'static usize>));
+        assert_impl!(U256, Num);
         assert_impl!(U256, Unsigned);
     }
 
+    #[test]
+    fn test_pow_primitive() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(base: U, exp: u8)| {
+                let expected = (0..exp).fold(U::ONE, |acc, _| acc.wrapping_mul(base));
+                assert_eq!(Pow::pow(base, exp), expected);
+                assert_eq!(Pow::pow(base, &(exp as usize)), expected);
+                assert_eq!(PrimInt::pow(base, exp.into()), expected);
+                let large = Pow::pow(Pow::pow(base, 1_u64 << 32), exp);
+                assert_eq!(Pow::pow(base, (exp as u64) << 32), large);
+            });
+        });
+    }
+
+    #[test]
+    fn test_float() {
+        let n = U256::from(1_u64 << 53);
+        assert_eq!(n.to_f64(), Some(9_007_199_254_740_992.0));
+        assert_eq!(U256::from_f64(9_007_199_254_740_992.0), Some(n));
+        assert_eq!(U64::from_f32(-1.0), None);
+        assert_eq!(U64::from_f64(f64::NAN), None);
+    }
+
     #[test]
     fn test_signed_shl() {
         // Example from num-traits docs.
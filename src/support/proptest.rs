@@ -1,10 +1,14 @@
 //! Support for the [`proptest`](https://crates.io/crates/proptest) crate.
+//!
+//! Besides [`Arbitrary`] implementations this module provides strategies for
+//! targeted property tests.
 
 #![cfg(feature = "proptest")]
 #![cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
 
 use crate::{Bits, Uint};
-use proptest::{arbitrary::Mapped, prelude::*};
+use core::ops::RangeInclusive;
+use proptest::{arbitrary::Mapped, prelude::*, strategy::BoxedStrategy};
 
 impl<const BITS: usize, const LIMBS: usize> Arbitrary for Uint<BITS, LIMBS> {
     // FEATURE: Would be nice to have a value range as parameter
@@ -36,6 +40,83 @@ impl<const BITS: usize, const LIMBS: usize> Arbitrary for Bits<BITS, LIMBS> {
     }
 }
 
+/// Strategy for values in `range`.
+///
+/// Shrinks towards the start of the range.
+///
+/// # Panics
+///
+/// Panics if the range is empty.
+#[inline]
+#[track_caller]
+pub fn uint_in_range<const BITS: usize, const LIMBS: usize>(
+    range: RangeInclusive<Uint<BITS, LIMBS>>,
+) -> impl Strategy<Value = Uint<BITS, LIMBS>> {
+    let (low, high) = range.into_inner();
+    assert!(low <= high, "range must not be empty");
+    // Sample offsets with as many bits as the largest one and reject the ones
+    // past it, which keeps the distribution uniform. At most half are
+    // rejected.
+    let span = high - low;
+    let shift = span.leading_zeros();
+    any::<Uint<BITS, LIMBS>>()
+        .prop_map(move |value| value >> shift)
+        .prop_filter("offset outside of the range", move |offset| *offset <= span)
+        .prop_map(move |offset| low + offset)
+}
+
+/// Strategy for values with exactly `bits` significant bits, i.e. with
+/// [`Uint::bit_len`] equal to `bits`.
+///
+/// # Panics
+///
+/// Panics if `bits > BITS`.
+#[inline]
+#[track_caller]
+pub fn uint_with_bits<const BITS: usize, const LIMBS: usize>(
+    bits: usize,
+) -> impl Strategy<Value = Uint<BITS, LIMBS>> {
+    assert!(bits <= BITS, "bits must be at most BITS");
+    any::<Uint<BITS, LIMBS>>().prop_map(move |value| {
+        if bits == 0 {
+            return Uint::ZERO;
+        }
+        let mut value = value >> (BITS - bits);
+        value.set_bit(bits - 1, true);
+        value
+    })
+}
+
+/// Strategy for edge cases: zero, [`Uint::MAX`], and powers of two together
+/// with their neighbours.
+#[inline]
+pub fn edge_cases<const BITS: usize, const LIMBS: usize>() -> BoxedStrategy<Uint<BITS, LIMBS>> {
+    if BITS == 0 {
+        return Just(Uint::ZERO).boxed();
+    }
+    prop_oneof![
+        Just(Uint::ZERO),
+        Just(Uint::MAX),
+        Just(Uint::MAX - Uint::from(1)),
+        (0..BITS, -1_i8..=1).prop_map(|(exponent, offset)| {
+            let power = Uint::from(1) << exponent;
+            match offset {
+                -1 => power.wrapping_sub(Uint::from(1)),
+                0 => power,
+                _ => power.wrapping_add(Uint::from(1)),
+            }
+        }),
+    ]
+    .boxed()
+}
+
+/// Strategy that mixes [`edge_cases`] with uniformly distributed values in
+/// equal proportion.
+#[inline]
+pub fn edge_biased<const BITS: usize, const LIMBS: usize>() -> BoxedStrategy<Uint<BITS, LIMBS>> {
+    prop_oneof![edge_cases(), any::<Uint<BITS, LIMBS>>()].boxed()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +132,59 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_uint_in_range() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let strategy = (any::<U>(), any::<U>()).prop_flat_map(|(a, b)| {
+                let (low, high) = (a.min(b), a.max(b));
+                (Just(low), Just(high), uint_in_range(low..=high))
+            });
+            proptest!(|((low, high, n) in strategy)| {
+                assert!(low <= n && n <= high);
+            });
+            let (low, high) = (U::MAX >> 1, U::MAX);
+            proptest!(|(n in uint_in_range(low..=high))| {
+                assert!(low <= n && n <= high);
+            });
+            proptest!(|(n in uint_in_range(U::MAX..=U::MAX))| {
+                assert_eq!(n, U::MAX);
+            });
+        });
+    }
+
+    #[test]
+    fn test_uint_with_bits() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for bits in [0, BITS / 2, BITS] {
+                proptest!(|(n in uint_with_bits::<BITS, LIMBS>(bits))| {
+                    assert_eq!(n.bit_len(), bits);
+                });
+            }
+            proptest!(|(n in edge_biased::<BITS, LIMBS>())| {
+                assert!(n <= U::MAX);
+            });
+        });
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n in edge_cases::<BITS, LIMBS>())| {
+                // Every edge case is a power of two or adjacent to one.
+                assert!(
+                    n == U::ZERO || n == U::MAX || n.count_ones() == 1 ||
+                    (n + U::from(1)).count_ones() == 1 ||
+                    (n - U::from(1)).count_ones() == 1 ||
+                    n == U::MAX - U::from(1)
+                );
+            });
+        });
+    }
 }
@@ -0,0 +1,278 @@
+//! Support for the [`prost`](https://crates.io/crates/prost) crate.
+//!
+//! Protocol Buffers have no integer type wider than 64 bits, so a [`Uint`] is
+//! stored in a `bytes` field as a big-endian number. There are two
+//! representations:
+//!
+//! * [`fixed`]: exactly [`Uint::BYTES`] bytes, including leading zeros.
+//! * [`minimal`]: no leading zeros, so zero is the empty byte string.
+//!
+//! Both modules mirror the functions in [`prost::encoding`] and can be used
+//! to hand-write [`Message`] implementations. For a standalone value, the
+//! [`Fixed`] and [`Minimal`] newtypes are messages with the value in field
+//! `1`, compatible with `google.protobuf.BytesValue`:
+//!
+//! ```
+//! # use ruint::{aliases::U256, support::prost::Minimal};
+//! # use prost::Message;
+//! let encoded = Minimal(U256::from(0x1234)).encode_to_vec();
+//! assert_eq!(encoded, [0x0a, 0x02, 0x12, 0x34]);
+//! assert_eq!(Minimal::decode(&encoded[..]).unwrap().0, U256::from(0x1234));
+//! ```
+//!
+//! Like proto3 scalars, the newtypes omit the field when the value is zero and
+//! decode a missing field as zero.
+
+#![cfg(feature = "prost")]
+#![cfg_attr(docsrs, doc(cfg(feature = "prost")))]
+
+use crate::Uint;
+use prost::{
+    bytes::{Buf, BufMut},
+    encoding::{
+        check_wire_type, decode_varint, encode_key, encode_varint, encoded_len_varint, key_len,
+        skip_field, DecodeContext, WireType,
+    },
+    DecodeError, Message,
+};
+
+/// Reads a length-delimited field whose length satisfies `expected`.
+fn merge_bytes<const BITS: usize, const LIMBS: usize>(
+    wire_type: WireType,
+    buf: &mut impl Buf,
+    expected: impl FnOnce(usize) -> bool,
+) -> Result<Uint<BITS, LIMBS>, DecodeError> {
+    check_wire_type(WireType::LengthDelimited, wire_type)?;
+    let len = decode_varint(buf)?;
+    if len > buf.remaining() as u64 {
+        return Err(DecodeError::new("buffer underflow"));
+    }
+    #[allow(clippy::cast_possible_truncation)] // len <= remaining.
+    let len = len as usize;
+    if !expected(len) {
+        buf.advance(len);
+        return Err(DecodeError::new("invalid Uint length"));
+    }
+    let bytes = buf.copy_to_bytes(len);
+    Uint::try_from_be_slice(&bytes).ok_or_else(|| DecodeError::new("value too large for Uint"))
+}
+
+pub mod fixed {
+    //! Fixed-width big-endian `bytes` fields.
+
+    use super::{
+        encode_key, encode_varint, encoded_len_varint, key_len, merge_bytes, Buf, BufMut,
+        DecodeContext, DecodeError, Uint, WireType,
+    };
+
+    /// Encodes `value` as field `tag` of exactly [`Uint::BYTES`] bytes.
+    pub fn encode<const BITS: usize, const LIMBS: usize>(
+        tag: u32,
+        value: &Uint<BITS, LIMBS>,
+        buf: &mut impl BufMut,
+    ) {
+        encode_key(tag, WireType::LengthDelimited, buf);
+        encode_varint(Uint::<BITS, LIMBS>::BYTES as u64, buf);
+        buf.put_slice(&value.to_be_bytes_vec());
+    }
+
+    /// Decodes a field of exactly [`Uint::BYTES`] bytes into `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is not length-delimited, is not
+    /// [`Uint::BYTES`] long or the value does not fit in `BITS` bits.
+    pub fn merge<const BITS: usize, const LIMBS: usize>(
+        wire_type: WireType,
+        value: &mut Uint<BITS, LIMBS>,
+        buf: &mut impl Buf,
+        _ctx: DecodeContext,
+    ) -> Result<(), DecodeError> {
+        *value = merge_bytes(wire_type, buf, |len| len == Uint::<BITS, LIMBS>::BYTES)?;
+        Ok(())
+    }
+
+    /// Returns the encoded length of field `tag`.
+    #[must_use]
+    pub fn encoded_len<const BITS: usize, const LIMBS: usize>(
+        tag: u32,
+        _value: &Uint<BITS, LIMBS>,
+    ) -> usize {
+        let len = Uint::<BITS, LIMBS>::BYTES;
+        key_len(tag) + encoded_len_varint(len as u64) + len
+    }
+}
+
+pub mod minimal {
+    //! Minimal-width big-endian `bytes` fields.
+
+    use super::{
+        encode_key, encode_varint, encoded_len_varint, key_len, merge_bytes, Buf, BufMut,
+        DecodeContext, DecodeError, Uint, WireType,
+    };
+
+    /// Encodes `value` as field `tag` without leading zeros.
+    pub fn encode<const BITS: usize, const LIMBS: usize>(
+        tag: u32,
+        value: &Uint<BITS, LIMBS>,
+        buf: &mut impl BufMut,
+    ) {
+        encode_key(tag, WireType::LengthDelimited, buf);
+        encode_varint(value.byte_len() as u64, buf);
+        buf.put_slice(&value.to_be_bytes_trimmed_vec());
+    }
+
+    /// Decodes a field of at most [`Uint::BYTES`] bytes into `value`. Leading
+    /// zeros are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is not length-delimited, is longer than
+    /// [`Uint::BYTES`] or the value does not fit in `BITS` bits.
+    pub fn merge<const BITS: usize, const LIMBS: usize>(
+        wire_type: WireType,
+        value: &mut Uint<BITS, LIMBS>,
+        buf: &mut impl Buf,
+        _ctx: DecodeContext,
+    ) -> Result<(), DecodeError> {
+        *value = merge_bytes(wire_type, buf, |len| len <= Uint::<BITS, LIMBS>::BYTES)?;
+        Ok(())
+    }
+
+    /// Returns the encoded length of field `tag`.
+    #[must_use]
+    pub fn encoded_len<const BITS: usize, const LIMBS: usize>(
+        tag: u32,
+        value: &Uint<BITS, LIMBS>,
+    ) -> usize {
+        let len = value.byte_len();
+        key_len(tag) + encoded_len_varint(len as u64) + len
+    }
+}
+
+macro_rules! impl_message {
+    ($(#[$attr:meta])* $name:ident, $module:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
+
+        impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for $name<BITS, LIMBS> {
+            fn from(value: Uint<BITS, LIMBS>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> From<$name<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+            fn from(value: $name<BITS, LIMBS>) -> Self {
+                value.0
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> Message for $name<BITS, LIMBS> {
+            fn encode_raw(&self, buf: &mut impl BufMut) {
+                if self.0 != Uint::ZERO {
+                    $module::encode(1, &self.0, buf);
+                }
+            }
+
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                wire_type: WireType,
+                buf: &mut impl Buf,
+                ctx: DecodeContext,
+            ) -> Result<(), DecodeError> {
+                if tag == 1 {
+                    $module::merge(wire_type, &mut self.0, buf, ctx)
+                        .map_err(|mut error| {
+                            error.push(stringify!($name), "0");
+                            error
+                        })
+                } else {
+                    skip_field(wire_type, tag, buf, ctx)
+                }
+            }
+
+            fn encoded_len(&self) -> usize {
+                if self.0 == Uint::ZERO {
+                    0
+                } else {
+                    $module::encoded_len(1, &self.0)
+                }
+            }
+
+            fn clear(&mut self) {
+                self.0 = Uint::ZERO;
+            }
+        }
+    };
+}
+
+impl_message!(
+    /// A message with a [`Uint`] in field `1`, encoded by [`fixed`].
+    Fixed,
+    fixed
+);
+impl_message!(
+    /// A message with a [`Uint`] in field `1`, encoded by [`minimal`].
+    Minimal,
+    minimal
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let encoded = Fixed(value).encode_to_vec();
+                assert_eq!(encoded.len(), Fixed(value).encoded_len());
+                assert_eq!(Fixed::decode(&encoded[..]).unwrap().0, value);
+
+                let encoded = Minimal(value).encode_to_vec();
+                assert_eq!(encoded.len(), Minimal(value).encoded_len());
+                assert_eq!(Minimal::decode(&encoded[..]).unwrap().0, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_wire_format() {
+        let value = U256::from(0x1234);
+        let mut expected = vec![0x0a, 0x20];
+        expected.extend_from_slice(&value.to_be_bytes::<32>());
+        assert_eq!(Fixed(value).encode_to_vec(), expected);
+        assert_eq!(Minimal(value).encode_to_vec(), [0x0a, 0x02, 0x12, 0x34]);
+        assert!(Fixed(U256::ZERO).encode_to_vec().is_empty());
+        assert!(Minimal(U256::ZERO).encode_to_vec().is_empty());
+        assert_eq!(Minimal::<256, 4>::decode(&[][..]).unwrap().0, U256::ZERO);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // Fixed requires the full width, minimal accepts leading zeros.
+        let encoded = [0x0a, 0x03, 0x00, 0x12, 0x34];
+        assert!(Fixed::<256, 4>::decode(&encoded[..]).is_err());
+        assert_eq!(
+            Minimal::<256, 4>::decode(&encoded[..]).unwrap().0,
+            U256::from(0x1234)
+        );
+        assert!(Minimal::<16, 1>::decode(&encoded[..]).is_err());
+        // Too large.
+        assert!(Minimal::<12, 1>::decode(&[0x0a, 0x02, 0x10, 0x00][..]).is_err());
+        assert!(Fixed::<12, 1>::decode(&[0x0a, 0x02, 0x10, 0x00][..]).is_err());
+        // Wrong wire type.
+        assert!(Minimal::<16, 1>::decode(&[0x08, 0x01][..]).is_err());
+        // Unknown fields are skipped.
+        let encoded = [0x10, 0x01, 0x0a, 0x01, 0x2a];
+        assert_eq!(
+            Minimal::<16, 1>::decode(&encoded[..]).unwrap().0,
+            Uint::from(42)
+        );
+    }
+}
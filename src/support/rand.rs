@@ -3,11 +3,12 @@
 #![cfg(feature = "rand")]
 #![cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 
-// FEATURE: Implement the Uniform distribution.
-
 use crate::Uint;
 use rand::{
-    distributions::{Distribution, Standard, Uniform},
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformSampler},
+        Distribution, Standard, Uniform,
+    },
     Rng,
 };
 
@@ -24,10 +25,96 @@ impl<const BITS: usize, const LIMBS: usize> Distribution<Uint<BITS, LIMBS>> for
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns a uniformly random value less than `bound`.
+    ///
+    /// Uses rejection sampling on the `bound.bit_len()` low bits, so the result
+    /// is unbiased and on average less than two samples are needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn random_below<R: Rng + ?Sized>(rng: &mut R, bound: Self) -> Self {
+        assert!(bound != Self::ZERO, "bound must be non-zero");
+        let bits = bound.bit_len();
+        let limbs = (bits + 63) / 64;
+        let mask = u64::MAX >> (64 * limbs - bits);
+        loop {
+            let mut result = Self::ZERO;
+            for limb in &mut result.limbs[..limbs] {
+                *limb = rng.gen();
+            }
+            result.limbs[limbs - 1] &= mask;
+            if result < bound {
+                return result;
+            }
+        }
+    }
+}
+
+/// Sampler for uniformly distributed [`Uint`]s in a range.
+///
+/// This makes [`Rng::gen_range`] and [`Uniform`] work for [`Uint`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "alloc", derive(Debug))]
+pub struct UniformUint<const BITS: usize, const LIMBS: usize> {
+    low:   Uint<BITS, LIMBS>,
+    /// Number of values in the range, zero if it covers all values.
+    range: Uint<BITS, LIMBS>,
+}
+
+impl<const BITS: usize, const LIMBS: usize> SampleUniform for Uint<BITS, LIMBS> {
+    type Sampler = UniformUint<BITS, LIMBS>;
+}
+
+impl<const BITS: usize, const LIMBS: usize> UniformSampler for UniformUint<BITS, LIMBS> {
+    type X = Uint<BITS, LIMBS>;
+
+    #[track_caller]
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        assert!(low < high, "Uniform::new called with `low >= high`");
+        Self::new_inclusive(low, high - Uint::from(1))
+    }
+
+    #[track_caller]
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        assert!(
+            low <= high,
+            "Uniform::new_inclusive called with `low > high`"
+        );
+        Self {
+            low,
+            range: (high - low).wrapping_add(Uint::from(1)),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        if self.range == Uint::ZERO {
+            return rng.gen();
+        }
+        self.low + Uint::random_below(rng, self.range)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{const_for, nlimbs};
+    use crate::{aliases::U8, const_for, nlimbs};
+    use proptest::proptest;
+    use rand::{rngs::StdRng, SeedableRng};
 
     #[test]
     fn test_rand() {
@@ -39,4 +126,55 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_random_below() {
+        let mut rng = rand::thread_rng();
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(bound: U, shift: usize)| {
+                let mut rng = rand::thread_rng();
+                let bound = (bound >> (shift % (64 * LIMBS))).max(U::from(1));
+                assert!(U::random_below(&mut rng, bound) < bound);
+            });
+            assert_eq!(U::random_below(&mut rng, U::from(1)), U::ZERO);
+        });
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = rand::thread_rng();
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let mut rng = rand::thread_rng();
+                let (low, high) = (a.min(b), a.max(b));
+                let x = rng.gen_range(low..=high);
+                assert!(low <= x && x <= high);
+                if low < high {
+                    let x = rng.gen_range(low..high);
+                    assert!(low <= x && x < high);
+                }
+            });
+            let _: U = rng.gen_range(U::ZERO..=U::MAX);
+        });
+    }
+
+    #[test]
+    fn test_uniform_unbiased() {
+        // With bound 129 `random % bound` maps two byte values to each of the
+        // residues below 127 and one to 127 and 128, so those two would get
+        // half the samples of the others.
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut counts = [0_u32; 129];
+        let distribution = Uniform::new(U8::ZERO, U8::from(129));
+        for _ in 0..129_000 {
+            counts[usize::try_from(distribution.sample(&mut rng)).unwrap()] += 1;
+        }
+        for count in counts {
+            assert!((850..1_150).contains(&count), "{counts:?}");
+        }
+    }
 }
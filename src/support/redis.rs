@@ -0,0 +1,139 @@
+//! Support for the [`redis`](https://crates.io/crates/redis) crate.
+//!
+//! Implements [`ToRedisArgs`] and [`FromRedisValue`] for [`Uint`] and [`Bits`].
+//!
+//! * [`Uint`] is stored as a decimal string. Redis treats it as an integer, so
+//!   it can be used as the argument of `INCRBY` and similar commands as long as
+//!   it fits in an `i64`. Reading accepts integer replies and decimal strings.
+//! * [`Bits`] is stored as [`Uint::BYTES`] big-endian bytes. Reading accepts
+//!   shorter inputs.
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! # use redis::{FromRedisValue, Value};
+//! let cmd = redis::pipe()
+//!     .incr("balance", U256::from(5))
+//!     .get_packed_pipeline();
+//! assert!(cmd.ends_with(b"$7\r\nbalance\r\n$1\r\n5\r\n"));
+//!
+//! let value = U256::from_redis_value(&Value::Int(42)).unwrap();
+//! assert_eq!(value, U256::from(42));
+//! ```
+
+#![cfg(feature = "redis")]
+#![cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+
+use crate::{Bits, Uint};
+use redis::{
+    ErrorKind, FromRedisValue, NumericBehavior, RedisError, RedisResult, RedisWrite, ToRedisArgs,
+    Value,
+};
+
+fn type_error(value: &Value, expected: &str) -> RedisError {
+    RedisError::from((
+        ErrorKind::TypeError,
+        "Response was of incompatible type",
+        format!("{expected} (response was {value:?})"),
+    ))
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToRedisArgs for Uint<BITS, LIMBS> {
+    fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+        out.write_arg(self.to_string().as_bytes());
+    }
+
+    fn describe_numeric_behavior(&self) -> NumericBehavior {
+        NumericBehavior::NumberIsInteger
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromRedisValue for Uint<BITS, LIMBS> {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        let parsed = match value {
+            Value::Int(n) => Self::try_from(*n).ok(),
+            Value::BigNumber(n) => Self::from_str_radix(&n.to_string(), 10).ok(),
+            _ => Self::from_str_radix(&String::from_redis_value(value)?, 10).ok(),
+        };
+        parsed.ok_or_else(|| type_error(value, &format!("Not a valid Uint<{BITS}>")))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToRedisArgs for Bits<BITS, LIMBS> {
+    fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+        out.write_arg(&self.as_uint().to_be_bytes_vec());
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FromRedisValue for Bits<BITS, LIMBS> {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        let Value::BulkString(bytes) = value else {
+            return Err(type_error(value, "Response type not bytes compatible"));
+        };
+        Uint::try_from_be_slice(bytes)
+            .map(Self::from)
+            .ok_or_else(|| type_error(value, &format!("Too many bytes for Bits<{BITS}>")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let args = value.to_redis_args();
+                assert_eq!(args, vec![value.to_string().into_bytes()]);
+                let reply = Value::BulkString(args[0].clone());
+                assert_eq!(U::from_redis_value(&reply).unwrap(), value);
+
+                let bits = Bits::from(value);
+                let args = bits.to_redis_args();
+                assert_eq!(args[0].len(), U::BYTES);
+                let reply = Value::BulkString(args[0].clone());
+                assert_eq!(Bits::from_redis_value(&reply).unwrap(), bits);
+            });
+        });
+    }
+
+    #[test]
+    fn test_replies() {
+        let parse = U256::from_redis_value;
+        assert_eq!(parse(&Value::Int(42)).unwrap(), U256::from(42));
+        assert_eq!(
+            parse(&Value::SimpleString("1000".into())).unwrap(),
+            U256::from(1000)
+        );
+        let big = U256::MAX.to_string().parse().unwrap();
+        assert_eq!(parse(&Value::BigNumber(big)).unwrap(), U256::MAX);
+        assert!(parse(&Value::Int(-1)).is_err());
+        assert!(parse(&Value::BulkString(b"0x10".to_vec())).is_err());
+        assert!(parse(&Value::BulkString(format!("{}0", U256::MAX).into_bytes())).is_err());
+        assert!(parse(&Value::Nil).is_err());
+        assert_eq!(Option::<U256>::from_redis_value(&Value::Nil).unwrap(), None);
+
+        let bits = Bits::<16, 1>::from_redis_value(&Value::BulkString(vec![1])).unwrap();
+        assert_eq!(bits, Bits::from(Uint::from(1)));
+        assert!(Bits::<16, 1>::from_redis_value(&Value::BulkString(vec![1, 2, 3])).is_err());
+        assert!(Bits::<16, 1>::from_redis_value(&Value::Int(1)).is_err());
+    }
+
+    #[test]
+    fn test_numeric() {
+        let value = U256::from(7);
+        assert_eq!(
+            value.describe_numeric_behavior(),
+            NumericBehavior::NumberIsInteger
+        );
+        let cmd = redis::cmd("INCRBY")
+            .arg("count")
+            .arg(value)
+            .get_packed_command();
+        assert_eq!(cmd, b"*3\r\n$6\r\nINCRBY\r\n$5\r\ncount\r\n$1\r\n7\r\n");
+    }
+}
@@ -0,0 +1,162 @@
+//! Support for the [`rkyv`](https://crates.io/crates/rkyv) crate.
+//!
+//! A [`Uint`] is archived as an [`ArchivedUint`], a fixed array of
+//! little-endian limbs with the least significant limb first. The layout does
+//! not depend on the endianness of the target or on rkyv's format features.
+//! Validation rejects values with bits set above `BITS`.
+
+#![cfg(feature = "rkyv")]
+#![cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+
+use crate::Uint;
+use core::{error::Error, fmt};
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{fail, Fallible, Source},
+    rend::u64_le,
+    traits::NoUndef,
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+/// An archived [`Uint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArchivedUint<const BITS: usize, const LIMBS: usize>([u64_le; LIMBS]);
+
+// SAFETY: `u64_le` is portable, and so is an array of it.
+unsafe impl<const BITS: usize, const LIMBS: usize> Portable for ArchivedUint<BITS, LIMBS> {}
+
+// SAFETY: `u64_le` has no padding, and neither does an array of it.
+unsafe impl<const BITS: usize, const LIMBS: usize> NoUndef for ArchivedUint<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> ArchivedUint<BITS, LIMBS> {
+    /// Returns the little-endian limbs.
+    #[inline]
+    #[must_use]
+    pub const fn as_limbs(&self) -> &[u64_le; LIMBS] {
+        &self.0
+    }
+
+    /// Converts to a native [`Uint`].
+    #[inline]
+    #[must_use]
+    pub fn to_native(&self) -> Uint<BITS, LIMBS> {
+        Uint::from_limbs(self.0.map(u64_le::to_native))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Archive for Uint<BITS, LIMBS> {
+    type Archived = ArchivedUint<BITS, LIMBS>;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        out.write(ArchivedUint(self.limbs.map(u64_le::from_native)));
+    }
+}
+
+impl<S: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> Serialize<S>
+    for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, const BITS: usize, const LIMBS: usize> Deserialize<Uint<BITS, LIMBS>, D>
+    for ArchivedUint<BITS, LIMBS>
+{
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        Ok(self.to_native())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<ArchivedUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from(value: ArchivedUint<BITS, LIMBS>) -> Self {
+        value.to_native()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PartialEq<Uint<BITS, LIMBS>>
+    for ArchivedUint<BITS, LIMBS>
+{
+    #[inline]
+    fn eq(&self, other: &Uint<BITS, LIMBS>) -> bool {
+        self.to_native() == *other
+    }
+}
+
+/// Error returned when validating an [`ArchivedUint`] with bits set above
+/// `BITS`.
+#[derive(Debug)]
+struct OverflowError {
+    bits: usize,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Archived value is too large for Uint<{}>", self.bits)
+    }
+}
+
+impl Error for OverflowError {}
+
+// SAFETY: Every bit pattern is a valid `[u64_le; LIMBS]`, and the check
+// rejects values that are not a valid `Uint<BITS, LIMBS>`.
+unsafe impl<C, const BITS: usize, const LIMBS: usize> CheckBytes<C> for ArchivedUint<BITS, LIMBS>
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: The caller guarantees `value` is aligned and points to
+        // enough bytes, all of which are valid for `u64_le`.
+        let limbs = unsafe { &(*value).0 };
+        if LIMBS > 0 && limbs[LIMBS - 1].to_native() > Uint::<BITS, LIMBS>::MASK {
+            fail!(OverflowError { bits: BITS });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+    use rkyv::{rancor, util::AlignedVec};
+
+    #[test]
+    fn test_roundtrip() {
+        // rkyv can not access a zero-sized root in an empty buffer.
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bytes = rkyv::to_bytes::<rancor::Error>(&value).unwrap();
+                let archived = rkyv::access::<ArchivedUint<BITS, LIMBS>, rancor::Error>(&bytes).unwrap();
+                assert_eq!(*archived, value);
+                assert_eq!(rkyv::deserialize::<U, rancor::Error>(archived).unwrap(), value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_little_endian() {
+        let value = U256::from(0x0102_0304_0506_0708_u64) << 64_usize;
+        let bytes = rkyv::to_bytes::<rancor::Error>(&value).unwrap();
+        assert_eq!(&bytes[..], value.to_le_bytes::<32>());
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut bytes = AlignedVec::<8>::new();
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert!(rkyv::access::<ArchivedUint<64, 1>, rancor::Error>(&bytes).is_ok());
+        assert!(rkyv::access::<ArchivedUint<63, 1>, rancor::Error>(&bytes).is_err());
+    }
+}
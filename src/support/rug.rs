@@ -0,0 +1,82 @@
+//! Support for the [`rug`](https://crates.io/crates/rug) crate.
+//!
+//! Conversions copy the limbs using GMP's `mpz_import` and `mpz_export`.
+#![cfg(feature = "rug")]
+#![cfg_attr(docsrs, doc(cfg(feature = "rug")))]
+
+use crate::{from::ToUintError, Uint};
+use core::cmp::Ordering;
+use rug::{integer::Order, Integer};
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Integer> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Integer) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Integer> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[allow(clippy::cast_possible_truncation)] // BITS is small
+    fn try_from(value: &Integer) -> Result<Self, Self::Error> {
+        let overflow = value.significant_bits() as usize > BITS;
+        let mut limbs = [0; LIMBS];
+        if overflow {
+            let truncated = Integer::from(value.as_abs().keep_bits_ref(BITS as u32));
+            truncated.write_digits(&mut limbs, Order::Lsf);
+        } else {
+            value.write_digits(&mut limbs, Order::Lsf);
+        }
+        let result = Self::from_limbs(limbs);
+        if value.cmp0() == Ordering::Less {
+            Err(ToUintError::ValueNegative(BITS, result))
+        } else if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, result))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Integer {
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for Integer {
+    #[inline]
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        Self::from_digits(value.as_limbs(), Order::Lsf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big = Integer::from(value);
+                assert_eq!(big.to_string_radix(16), format!("{value:x}"));
+                assert_eq!(U::try_from(&big), Ok(value));
+
+                let larger = big.clone() + (Integer::from(1) << BITS);
+                assert_eq!(U::try_from(larger), Err(ToUintError::ValueTooLarge(BITS, value)));
+                if value != U::ZERO {
+                    assert_eq!(U::try_from(-big), Err(ToUintError::ValueNegative(BITS, value)));
+                }
+            });
+        });
+    }
+}
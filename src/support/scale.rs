@@ -1,4 +1,5 @@
-//! Support for the [`parity-scale-codec`](https://crates.io/crates/parity-scale-codec) crate.
+//! Support for the [`parity-scale-codec`](https://crates.io/crates/parity-scale-codec) crate,
+//! and, with the `scale-info` feature, the [`scale-info`](https://crates.io/crates/scale-info) crate.
 
 #![cfg(feature = "parity-scale-codec")]
 #![cfg_attr(docsrs, doc(cfg(feature = "parity-scale-codec")))]
@@ -8,6 +9,8 @@ use parity_scale_codec::{
     Compact, CompactAs, Decode, Encode, EncodeAsRef, EncodeLike, Error, HasCompact, Input,
     MaxEncodedLen, Output,
 };
+#[cfg(feature = "scale-info")]
+use scale_info::{meta_type, Type, TypeDefArray, TypeInfo};
 
 #[allow(unused_imports)]
 use alloc::vec::Vec;
@@ -16,30 +19,65 @@ use alloc::vec::Vec;
 // https://docs.substrate.io/reference/scale-codec/#fn-1
 const COMPACT_BITS_LIMIT: usize = 536;
 
+/// Uints are encoded as exactly [`Uint::BYTES`] little-endian bytes without a
+/// length prefix. This matches the fixed-width encoding of the
+/// `primitive-types` integers used by Substrate.
 impl<const BITS: usize, const LIMBS: usize> Encode for Uint<BITS, LIMBS> {
-    /// u32 prefix for compact encoding + bytes needed for LE bytes
-    /// representation
     fn size_hint(&self) -> usize {
-        core::mem::size_of::<u32>() + Self::BYTES
+        Self::BYTES
     }
 
-    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
-        self.as_le_bytes().using_encoded(f)
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(&self.as_le_bytes());
+    }
+
+    fn encoded_size(&self) -> usize {
+        Self::BYTES
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize> EncodeLike for Uint<BITS, LIMBS> {}
+
 impl<const BITS: usize, const LIMBS: usize> MaxEncodedLen for Uint<BITS, LIMBS> {
     fn max_encoded_len() -> usize {
-        core::mem::size_of::<Self>()
+        Self::BYTES
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Decode for Uint<BITS, LIMBS> {
     fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
-        Decode::decode(input).and_then(|b: Vec<_>| {
-            Self::try_from_le_slice(&b)
-                .ok_or_else(|| Error::from("value is larger than fits the Uint"))
-        })
+        let mut limbs = [0; LIMBS];
+        let mut remaining = Self::BYTES;
+        for limb in &mut limbs {
+            let mut bytes = [0; 8];
+            let len = remaining.min(8);
+            input.read(&mut bytes[..len])?;
+            *limb = u64::from_le_bytes(bytes);
+            remaining -= len;
+        }
+        if LIMBS > 0 && limbs[LIMBS - 1] > Self::MASK {
+            return Err(Error::from("value is larger than fits the Uint"));
+        }
+        Ok(Self::from_limbs(limbs))
+    }
+
+    fn skip<I: Input>(input: &mut I) -> Result<(), Error> {
+        Self::decode(input).map(|_| ())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(Self::BYTES)
+    }
+}
+
+/// Uints are described as a `[u8; BYTES]` array, matching their encoding.
+#[cfg(feature = "scale-info")]
+impl<const BITS: usize, const LIMBS: usize> TypeInfo for Uint<BITS, LIMBS> {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        #[allow(clippy::cast_possible_truncation)] // BYTES is small.
+        TypeDefArray::new(Self::BYTES as u32, meta_type::<u8>()).into()
     }
 }
 
@@ -282,10 +320,31 @@ mod tests {
                 let serialized = Encode::encode(&value);
                 let deserialized = <Uint::<BITS, LIMBS> as Decode>::decode(&mut serialized.as_slice()).unwrap();
                 assert_eq!(value, deserialized);
+                assert_eq!(serialized.len(), Uint::<BITS, LIMBS>::max_encoded_len());
+                assert_eq!(serialized, value.to_le_bytes_vec());
             });
         });
     }
 
+    #[test]
+    fn test_scale_fixed_width() {
+        let value = U256::from(0x0102_0304_0506_0708_u64) << 64_usize;
+        assert_eq!(value.encode(), value.as_limbs().encode());
+        assert_eq!(<U256 as Decode>::encoded_fixed_size(), Some(32));
+
+        let mut input = &[0xff_u8; 2][..];
+        assert!(<Uint<9, 1> as Decode>::decode(&mut input).is_err());
+        let mut input = &[0xff_u8; 1][..];
+        assert!(<Uint<9, 1> as Decode>::decode(&mut input).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "scale-info")]
+    fn test_scale_info() {
+        assert_eq!(U256::type_info(), <[u8; 32]>::type_info());
+        assert_eq!(Uint::<9, 1>::type_info(), <[u8; 2]>::type_info());
+    }
+
     #[test]
     fn test_scale_compact() {
         const_for!(BITS in [1, 2, 3, 7, 8, 9, 15, 16, 17, 29, 30, 31, 32, 33, 63, 64, 65, 127, 128, 129, 256, 384, 512, 535] {
@@ -0,0 +1,195 @@
+//! Support for the [`schemars`](https://crates.io/crates/schemars) crate.
+//!
+//! Implements [`JsonSchema`] for [`Uint`] and [`Bits`], describing their
+//! human readable `serde` representation so that structs containing them can
+//! derive [`JsonSchema`].
+//!
+//! The schema depends on the [`Contract`] of the generator. For
+//! deserialization, the default, it allows non-negative integers and the
+//! decimal, `0x`, `0o` and `0b` prefixed strings accepted by [`Deserialize`].
+//! For serialization it is a string matching exactly the output of
+//! [`Serialize`]: minimal `0x` prefixed lower case hex for [`Uint`], and
+//! zero-padded hex for [`Bits`].
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! # use schemars::{generate::SchemaSettings, schema_for, JsonSchema};
+//! #[derive(JsonSchema)]
+//! struct Config {
+//!     max_fee: U256,
+//! }
+//!
+//! let schema = schema_for!(Config);
+//! assert_eq!(
+//!     schema.as_value()["properties"]["max_fee"]["anyOf"][1]["type"],
+//!     "integer"
+//! );
+//!
+//! let schema = SchemaSettings::default()
+//!     .for_serialize()
+//!     .into_generator()
+//!     .into_root_schema_for::<Config>();
+//! assert_eq!(
+//!     schema.as_value()["properties"]["max_fee"]["pattern"],
+//!     "^0x(0|[1-9a-f][0-9a-f]{0,63})$"
+//! );
+//! ```
+//!
+//! The serialization patterns limit the number of digits but not the value of
+//! the leading digit, so they can admit values slightly out of range when
+//! `BITS` is not a multiple of four.
+//!
+//! [`Contract`]: schemars::generate::Contract
+//! [`Serialize`]: serde::Serialize
+//! [`Deserialize`]: serde::Deserialize
+
+#![cfg(feature = "schemars")]
+#![cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+
+use crate::{nbytes, Bits, Uint};
+use alloc::{borrow::Cow, format};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// Pattern for all strings accepted by `Deserialize`.
+const DESERIALIZE_PATTERN: &str = "^(0[xX][0-9a-fA-F_]+|0[oO][0-7_]+|0[bB][01_]+|[0-9_]+)$";
+
+/// Builds the schema, using a string matching `pattern` when serializing.
+fn schema(generator: &SchemaGenerator, pattern: &str) -> Schema {
+    if generator.contract().is_deserialize() {
+        json_schema!({
+            "anyOf": [
+                { "type": "string", "pattern": DESERIALIZE_PATTERN },
+                { "type": "integer", "minimum": 0 },
+            ]
+        })
+    } else {
+        json_schema!({
+            "type": "string",
+            "pattern": pattern,
+        })
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> JsonSchema for Uint<BITS, LIMBS> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Uint{BITS}").into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("ruint::Uint<{BITS}>").into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let pattern = match (BITS + 3) / 4 {
+            0 => "^0x0$".into(),
+            digits => format!("^0x(0|[1-9a-f][0-9a-f]{{0,{}}})$", digits - 1),
+        };
+        schema(generator, &pattern)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> JsonSchema for Bits<BITS, LIMBS> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Bits{BITS}").into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("ruint::Bits<{BITS}>").into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let pattern = match nbytes(BITS) * 2 {
+            0 => "^0x0$".into(),
+            digits => format!("^0x[0-9a-f]{{{digits}}}$"),
+        };
+        schema(generator, &pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+    use schemars::{generate::SchemaSettings, schema_for};
+
+    fn serialize_schema<T: JsonSchema>() -> Schema {
+        SchemaSettings::default()
+            .for_serialize()
+            .into_generator()
+            .into_root_schema_for::<T>()
+    }
+
+    #[test]
+    fn test_serialize_schema() {
+        let schema = serialize_schema::<U256>();
+        assert_eq!(schema.as_value()["type"], "string");
+        assert_eq!(
+            schema.as_value()["pattern"],
+            "^0x(0|[1-9a-f][0-9a-f]{0,63})$"
+        );
+        assert_eq!(
+            serialize_schema::<Bits<256, 4>>().as_value()["pattern"],
+            "^0x[0-9a-f]{64}$"
+        );
+        assert_eq!(
+            serialize_schema::<Uint<0, 0>>().as_value()["pattern"],
+            "^0x0$"
+        );
+        assert_eq!(
+            serialize_schema::<Bits<0, 0>>().as_value()["pattern"],
+            "^0x0$"
+        );
+    }
+
+    #[test]
+    fn test_schema_matches_serialize() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            let digits = (BITS + 3) / 4;
+            let pattern = format!("^0x(0|[1-9a-f][0-9a-f]{{0,{}}})$", digits - 1);
+            assert_eq!(serialize_schema::<Uint<BITS, LIMBS>>().as_value()["pattern"], pattern);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let json = serde_json::to_string(&value).unwrap();
+                let hex = json.trim_matches('"').strip_prefix("0x").unwrap();
+                assert!(hex.len() <= digits);
+                assert!(hex == "0" || !hex.starts_with('0'));
+                assert!(hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+
+                let json = serde_json::to_string(&Bits::from(value)).unwrap();
+                assert_eq!(json.len(), 4 + nbytes(BITS) * 2);
+            });
+        });
+    }
+
+    #[test]
+    fn test_deserialize_schema() {
+        // Deserialization is the default contract.
+        let schema = schema_for!(U256);
+        let any_of = schema.as_value()["anyOf"].as_array().unwrap();
+        assert_eq!(any_of[0]["pattern"], DESERIALIZE_PATTERN);
+        assert_eq!(any_of[1]["type"], "integer");
+    }
+
+    #[test]
+    fn test_inline() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Config {
+            a: U256,
+            b: Bits<256, 4>,
+        }
+        let schema = serialize_schema::<Config>();
+        assert!(schema.as_value().get("$defs").is_none());
+        assert_eq!(schema.as_value()["properties"]["a"]["type"], "string");
+        assert_eq!(schema.as_value()["properties"]["b"]["type"], "string");
+    }
+}
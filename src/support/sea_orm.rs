@@ -0,0 +1,243 @@
+//! Support for the [`sea-orm`](https://crates.io/crates/sea-orm) crate.
+//!
+//! Implements [`TryGetable`], [`ValueType`], [`Nullable`] and `Into<Value>` so
+//! [`Uint`] can be used directly as an entity field. There are two
+//! representations:
+//!
+//! * [`Uint`] is stored as [`Uint::BYTES`] big-endian bytes in a binary column,
+//!   `BINARY(n)` in MySQL, `bytea` in PostgreSQL and `BLOB` in SQLite. Reading
+//!   accepts shorter inputs.
+//! * [`Decimal`] is stored as a `DECIMAL` column, with as many digits as
+//!   [`Uint::MAX`] and no scale. Reading accepts any scale as long as the value
+//!   is an integer. Note that MySQL supports at most 65 digits, about 215 bits.
+//!
+//! ```
+//! # use ruint::{aliases::U256, support::sea_orm::Decimal};
+//! # use sea_orm::entity::prelude::*;
+//! #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+//! #[sea_orm(table_name = "balances")]
+//! pub struct Model {
+//!     #[sea_orm(primary_key)]
+//!     pub id:     i32,
+//!     pub hash:   U256,
+//!     pub amount: Decimal<256, 4>,
+//!     pub limit:  Option<Decimal<256, 4>>,
+//! }
+//! # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+//! # pub enum Relation {}
+//! # impl ActiveModelBehavior for ActiveModel {}
+//! ```
+
+#![cfg(feature = "sea-orm")]
+#![cfg_attr(docsrs, doc(cfg(feature = "sea-orm")))]
+
+use crate::Uint;
+use bigdecimal::{
+    num_bigint::{BigInt, Sign},
+    BigDecimal,
+};
+use sea_orm::{
+    sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr},
+    ColIdx, DbErr, QueryResult, TryGetError, TryGetable,
+};
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Value {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::Bytes(Some(Box::new(value.to_be_bytes_vec())))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Nullable for Uint<BITS, LIMBS> {
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ValueType for Uint<BITS, LIMBS> {
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        match value {
+            Value::Bytes(Some(bytes)) => Self::try_from_be_slice(&bytes).ok_or(ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("Uint<{BITS}>")
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        #[allow(clippy::cast_possible_truncation)] // Never that many bytes.
+        ColumnType::Binary(Self::BYTES as u32)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryGetable for Uint<BITS, LIMBS> {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let bytes = Vec::<u8>::try_get_by(res, index)?;
+        Self::try_from_be_slice(&bytes)
+            .ok_or_else(|| DbErr::Type(format!("value too large for Uint<{BITS}>")).into())
+    }
+}
+
+/// A [`Uint`] stored in a `DECIMAL` column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Decimal<BITS, LIMBS> {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Decimal<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    fn from(value: Decimal<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Decimal<BITS, LIMBS>> for BigDecimal {
+    fn from(value: Decimal<BITS, LIMBS>) -> Self {
+        let digits = BigInt::from_bytes_be(Sign::Plus, &value.0.to_be_bytes_trimmed_vec());
+        Self::new(digits, 0)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&BigDecimal> for Decimal<BITS, LIMBS> {
+    type Error = DbErr;
+
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        let error = |reason: &str| DbErr::Type(format!("{value} {reason} for Uint<{BITS}>"));
+        // The value is `digits * 10^-scale`, normalized so integers have no
+        // positive scale.
+        let (digits, scale) = value.normalized().into_bigint_and_exponent();
+        let (sign, bytes) = digits.to_bytes_be();
+        match sign {
+            Sign::Minus => return Err(error("is negative")),
+            Sign::NoSign => return Ok(Self(Uint::ZERO)),
+            Sign::Plus if scale > 0 => return Err(error("is not an integer")),
+            Sign::Plus => {}
+        }
+        let mantissa = Uint::try_from_be_slice(&bytes).ok_or_else(|| error("is too large"))?;
+        // Stops at the first overflow, so a large exponent is fine.
+        let ten = <Uint<BITS, LIMBS> as TryFrom<u64>>::try_from(10).ok();
+        (0..scale.unsigned_abs())
+            .try_fold(mantissa, |value, _| value.checked_mul(ten?))
+            .map(Self)
+            .ok_or_else(|| error("is too large"))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Decimal<BITS, LIMBS>> for Value {
+    fn from(value: Decimal<BITS, LIMBS>) -> Self {
+        Self::BigDecimal(Some(Box::new(value.into())))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Nullable for Decimal<BITS, LIMBS> {
+    fn null() -> Value {
+        Value::BigDecimal(None)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ValueType for Decimal<BITS, LIMBS> {
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        match value {
+            Value::BigDecimal(Some(value)) => (&*value).try_into().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("Decimal<{BITS}>")
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::BigDecimal
+    }
+
+    fn column_type() -> ColumnType {
+        #[allow(clippy::cast_possible_truncation)] // Never that many digits.
+        let precision = Uint::<BITS, LIMBS>::MAX.to_string().len() as u32;
+        ColumnType::Decimal(Some((precision, 0)))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryGetable for Decimal<BITS, LIMBS> {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let value = BigDecimal::try_get_by(res, index)?;
+        Ok(<Self as TryFrom<_>>::try_from(&value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+    use sea_orm::{DatabaseBackend, FromQueryResult, MockDatabase, Statement};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_value_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(Value::from(value).unwrap::<U>(), value);
+                let decimal = Decimal(value);
+                assert_eq!(Value::from(decimal).unwrap::<Decimal<BITS, LIMBS>>(), decimal);
+                assert_eq!(BigDecimal::from(decimal).to_string(), value.to_string());
+            });
+        });
+    }
+
+    #[test]
+    fn test_decimal() {
+        type D = Decimal<256, 4>;
+        let parse = |s: &str| <D as TryFrom<_>>::try_from(&BigDecimal::from_str(s).unwrap());
+        assert_eq!(parse("1200").unwrap(), Decimal(U256::from(1200)));
+        assert_eq!(parse("1200.000").unwrap(), Decimal(U256::from(1200)));
+        assert_eq!(parse("12e2").unwrap(), Decimal(U256::from(1200)));
+        assert_eq!(parse("0e-5").unwrap(), Decimal(U256::ZERO));
+        assert_eq!(parse("0e500").unwrap(), Decimal(U256::ZERO));
+        assert_eq!(parse(&U256::MAX.to_string()).unwrap(), Decimal(U256::MAX));
+        assert!(parse("1.5").is_err());
+        assert!(parse("-1").is_err());
+        assert!(parse("1e78").is_err());
+        assert!(parse(&format!("{}0", U256::MAX)).is_err());
+
+        assert_eq!(D::column_type(), ColumnType::Decimal(Some((78, 0))));
+        assert_eq!(U256::column_type(), ColumnType::Binary(32));
+        assert_eq!(Value::from(None::<U256>), Value::Bytes(None));
+    }
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct Row {
+        hash:   U256,
+        amount: Decimal<256, 4>,
+        limit:  Option<Decimal<256, 4>>,
+    }
+
+    #[test]
+    fn test_query_result() {
+        let row = Row {
+            hash:   U256::MAX,
+            amount: Decimal(U256::from(42)),
+            limit:  None,
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[std::collections::BTreeMap::from([
+                ("hash", Value::from(row.hash)),
+                ("amount", Value::from(row.amount)),
+                ("limit", Decimal::<256, 4>::null()),
+            ])]])
+            .into_connection();
+        let statement = Statement::from_string(DatabaseBackend::Postgres, "SELECT");
+        let result = futures_executor::block_on(Row::find_by_statement(statement).one(&db));
+        assert_eq!(result.unwrap(), Some(row));
+    }
+}
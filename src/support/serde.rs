@@ -1,15 +1,30 @@
 //! Support for the [`serde`](https://crates.io/crates/serde) crate.
+//!
+//! Implements [`Serialize`] and [`Deserialize`] for [`Uint`] and [`Bits`], and
+//! provides adapters for use with `#[serde(with = ...)]` that select a
+//! different representation per field:
+//!
+//! * [`strict`]: the default, but only accepting numbers, decimal strings and
+//!   `0x` prefixed hex strings.
+//! * [`canonical`]: the default, but only accepting the serialized form, a
+//!   lower case `0x` prefixed hex string without leading zeros.
+//! * [`fixed_hex`]: zero-padded `0x` prefixed hex strings.
+//! * [`quantity`]: minimal `0x` prefixed hex strings, as in Ethereum JSON-RPC.
+//! * [`decimal`]: decimal strings.
+//! * [`base64`]: base64 encoded big-endian bytes.
+//! * [`limbs`]: arrays of `u64` limbs.
+//! * [`compact`]: minimal-length big-endian bytes.
 
 #![cfg(feature = "serde")]
 #![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 
-use crate::{nbytes, Bits, Uint};
+use crate::{nbytes, Bits, ToUintError, Uint};
 use core::{
     fmt::{Formatter, Result as FmtResult, Write},
     str,
 };
 use serde::{
-    de::{Error, Unexpected, Visitor},
+    de::{Error, Expected, Unexpected, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -112,25 +127,57 @@ impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Bits<BITS,
     }
 }
 
+/// Error for a value that does not fit in `bits` bits.
+fn too_large<E: Error>(bits: usize) -> E {
+    E::custom(format_args!("value too large for Uint<{bits}>"))
+}
+
+/// Converts a primitive number, rejecting negative and too large values.
+fn visit_number<T, E, const BITS: usize, const LIMBS: usize>(
+    v: T,
+    unexpected: Unexpected,
+    expected: &dyn Expected,
+) -> Result<Uint<BITS, LIMBS>, E>
+where
+    T: TryInto<Uint<BITS, LIMBS>, Error = ToUintError<Uint<BITS, LIMBS>>>,
+    E: Error,
+{
+    v.try_into().map_err(|err| match err {
+        ToUintError::ValueTooLarge(..) => too_large(BITS),
+        _ => E::invalid_value(unexpected, expected),
+    })
+}
+
 /// Serde Visitor for human readable formats.
 ///
 /// Accepts either a primitive number, a decimal or a hexadecimal string.
 struct HrVisitor<const BITS: usize, const LIMBS: usize>;
 
-impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for HrVisitor<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for HrVisitor<BITS, LIMBS> {
     type Value = Uint<BITS, LIMBS>;
 
     fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        write!(formatter, "a {} byte hex string", nbytes(BITS))
+        write!(
+            formatter,
+            "a number, or a decimal or 0x, 0o or 0b prefixed string of at most {BITS} bits"
+        )
     }
 
     fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
-        Uint::try_from(v).map_err(|_| Error::invalid_value(Unexpected::Unsigned(v), &self))
+        visit_number(v, Unexpected::Unsigned(v), &self)
     }
 
     fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
         // `Unexpected::Unsigned` cannot contain a `u128`
-        Uint::try_from(v).map_err(Error::custom)
+        visit_number(v, Unexpected::Other("u128"), &self)
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        visit_number(v, Unexpected::Signed(v), &self)
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+        visit_number(v, Unexpected::Other("i128"), &self)
     }
 
     fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
@@ -153,7 +200,7 @@ impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for HrVisitor<BITS
 /// Serde Visitor for non-human readable formats
 struct ByteVisitor<const BITS: usize, const LIMBS: usize>;
 
-impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for ByteVisitor<BITS, LIMBS> {
+impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for ByteVisitor<BITS, LIMBS> {
     type Value = Uint<BITS, LIMBS>;
 
     fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
@@ -173,6 +220,743 @@ impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for ByteVisitor<BI
     }
 }
 
+/// Reasons a string in a specific format fails to parse.
+enum ParseFailure {
+    Invalid,
+    TooLarge,
+}
+
+/// Parses a non-empty string of digits in `radix`. Unlike
+/// [`Uint::from_str_radix`] this rejects signs, whitespace and `_`.
+fn parse_digits<const BITS: usize, const LIMBS: usize>(
+    digits: &str,
+    radix: u32,
+) -> Result<Uint<BITS, LIMBS>, ParseFailure> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(ParseFailure::Invalid);
+    }
+    Uint::from_str_radix(digits, u64::from(radix)).map_err(|_| ParseFailure::TooLarge)
+}
+
+/// Serde Visitor for strings in a specific format.
+struct StrVisitor<const BITS: usize, const LIMBS: usize> {
+    expecting: &'static str,
+    parse:     fn(&str) -> Result<Uint<BITS, LIMBS>, ParseFailure>,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for StrVisitor<BITS, LIMBS> {
+    type Value = Uint<BITS, LIMBS>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(formatter, "{} of at most {BITS} bits", self.expecting)
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        (self.parse)(value).map_err(|failure| match failure {
+            ParseFailure::Invalid => Error::invalid_value(Unexpected::Str(value), &self),
+            ParseFailure::TooLarge => too_large(BITS),
+        })
+    }
+}
+
+pub mod strict {
+    //! Human readable representation that rejects ambiguous input.
+    //!
+    //! Serialization is the same as the default. Deserialization from human
+    //! readable formats accepts numbers, decimal strings and `0x` prefixed hex
+    //! strings, and nothing else. In particular it rejects empty strings, a
+    //! bare `0x`, the `0o` and `0b` prefixes, `_` separators and negative
+    //! numbers, which the default accepts or reports less precisely. Binary
+    //! formats use the default representation.
+    //!
+    //! Unlike [`canonical`](super::canonical) it accepts numbers, decimal
+    //! strings, upper case hex digits and leading zeros, so several inputs map
+    //! to the same value.
+    //!
+    //! ```
+    //! # use ruint::aliases::U256;
+    //! # use serde::Deserialize;
+    //! #[derive(Deserialize)]
+    //! struct Transfer {
+    //!     #[serde(with = "ruint::support::serde::strict")]
+    //!     amount: U256,
+    //! }
+    //!
+    //! for json in [
+    //!     r#"{"amount": 255}"#,
+    //!     r#"{"amount": "255"}"#,
+    //!     r#"{"amount": "0xff"}"#,
+    //! ] {
+    //!     let transfer: Transfer = serde_json::from_str(json).unwrap();
+    //!     assert_eq!(transfer.amount, U256::from(255));
+    //! }
+    //! assert!(serde_json::from_str::<Transfer>(r#"{"amount": "0b11111111"}"#).is_err());
+    //! ```
+
+    use super::{parse_digits, too_large, visit_number, ByteVisitor, ParseFailure};
+    use crate::Uint;
+    use core::fmt::{Formatter, Result as FmtResult};
+    use serde::{
+        de::{Error, Unexpected, Visitor},
+        Deserializer, Serialize, Serializer,
+    };
+
+    /// Serializes a [`Uint`] using its default representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a [`Uint`] from a number, a decimal string or a `0x`
+    /// prefixed hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is none of these, or if the value does
+    /// not fit in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StrictVisitor)
+        } else {
+            deserializer.deserialize_bytes(ByteVisitor)
+        }
+    }
+
+    struct StrictVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for StrictVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            write!(
+                formatter,
+                "a number, a decimal string or a 0x prefixed hex string of at most {BITS} bits"
+            )
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+            visit_number(v, Unexpected::Unsigned(v), &self)
+        }
+
+        fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+            visit_number(v, Unexpected::Other("u128"), &self)
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+            visit_number(v, Unexpected::Signed(v), &self)
+        }
+
+        fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+            visit_number(v, Unexpected::Other("i128"), &self)
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+            let parsed = match value.strip_prefix("0x") {
+                Some(digits) => parse_digits(digits, 16),
+                None => parse_digits(value, 10),
+            };
+            parsed.map_err(|failure| match failure {
+                ParseFailure::Invalid => Error::invalid_value(Unexpected::Str(value), &self),
+                ParseFailure::TooLarge => too_large(BITS),
+            })
+        }
+    }
+}
+
+pub mod compact {
+    //! Compact representation as minimal-length big-endian bytes.
+    //!
+    //! Values are serialized as byte strings without leading zeros, also in
+    //! human readable formats, making zero an empty byte string. This is
+    //! smaller than the default fixed-width representation in binary formats
+    //! like [`postcard`](https://crates.io/crates/postcard) and
+    //! [`bincode`](https://crates.io/crates/bincode), which length-prefix byte
+    //! strings.
+    //!
+    //! ```
+    //! # use ruint::aliases::U256;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Packet {
+    //!     #[serde(with = "ruint::support::serde::compact")]
+    //!     value: U256,
+    //! }
+    //!
+    //! let packet = Packet {
+    //!     value: U256::from(0x1234),
+    //! };
+    //! let bytes = bincode::serialize(&packet).unwrap();
+    //! assert_eq!(bytes, [2, 0, 0, 0, 0, 0, 0, 0, 0x12, 0x34]);
+    //! ```
+    //!
+    //! Deserialization also accepts leading zeros, up to [`Uint::BYTES`] bytes
+    //! in total.
+
+    use super::too_large;
+    use crate::Uint;
+    use core::fmt::{Formatter, Result as FmtResult};
+    use serde::{
+        de::{Error, SeqAccess, Visitor},
+        Deserializer, Serializer,
+    };
+
+    #[allow(unused_imports)]
+    use alloc::vec::Vec;
+
+    /// Serializes a [`Uint`] as minimal-length big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&value.to_be_bytes_trimmed_vec())
+    }
+
+    /// Deserializes a [`Uint`] from big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a byte string or sequence of
+    /// bytes, or if it is longer than [`Uint::BYTES`] or the value does not fit
+    /// in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_bytes(CompactVisitor)
+    }
+
+    struct CompactVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for CompactVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            write!(
+                formatter,
+                "at most {} bytes in big endian order",
+                Uint::<BITS, LIMBS>::BYTES
+            )
+        }
+
+        fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+            if value.len() > Uint::<BITS, LIMBS>::BYTES {
+                return Err(Error::invalid_length(value.len(), &self));
+            }
+            Uint::try_from_be_slice(value).ok_or_else(|| too_large(BITS))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(Uint::<BITS, LIMBS>::BYTES);
+            while let Some(byte) = seq.next_element()? {
+                if bytes.len() == Uint::<BITS, LIMBS>::BYTES {
+                    return Err(Error::invalid_length(bytes.len() + 1, &self));
+                }
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+}
+
+pub mod canonical {
+    //! Canonical representation for consensus-critical code.
+    //!
+    //! Serialization is the same as the default. Deserialization from human
+    //! readable formats only accepts the exact string the serializer produces:
+    //! a `0x` prefixed lower case hex string without leading zeros, with zero
+    //! being `0x0`. Each deviation is reported with a precise error. Binary
+    //! formats use the default representation, which is already canonical.
+    //!
+    //! Unlike [`strict`](super::strict) it rejects numbers, decimal strings,
+    //! upper case hex digits and leading zeros, so each value has exactly one
+    //! accepted input.
+    //!
+    //! ```
+    //! # use ruint::aliases::U256;
+    //! # use serde::Deserialize;
+    //! #[derive(Debug, Deserialize)]
+    //! struct Header {
+    //!     #[serde(with = "ruint::support::serde::canonical")]
+    //!     difficulty: U256,
+    //! }
+    //!
+    //! let header: Header = serde_json::from_str(r#"{"difficulty": "0x1bc1"}"#).unwrap();
+    //! assert_eq!(header.difficulty, U256::from(0x1bc1));
+    //! let error = serde_json::from_str::<Header>(r#"{"difficulty": "0x01bc1"}"#).unwrap_err();
+    //! assert!(error.to_string().contains("without leading zeros"));
+    //! ```
+
+    use super::{too_large, ByteVisitor, ZERO_STR};
+    use crate::Uint;
+    use core::fmt::{Formatter, Result as FmtResult};
+    use serde::{
+        de::{Error, Unexpected, Visitor},
+        Deserializer, Serialize, Serializer,
+    };
+
+    /// Serializes a [`Uint`] using its default representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a [`Uint`] from its canonical representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a string, is missing the `0x`
+    /// prefix, has no digits, has leading zeros, has upper case or invalid
+    /// digits, or if the value does not fit in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(CanonicalVisitor)
+        } else {
+            deserializer.deserialize_bytes(ByteVisitor)
+        }
+    }
+
+    struct CanonicalVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for CanonicalVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            write!(
+                formatter,
+                "a 0x prefixed lower case hex string without leading zeros of at most {BITS} bits"
+            )
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+            let invalid = |expected: &str| E::invalid_value(Unexpected::Str(value), &expected);
+            let Some(digits) = value.strip_prefix("0x") else {
+                return Err(invalid("a 0x prefixed hex string"));
+            };
+            if digits.is_empty() {
+                return Err(invalid("at least one hex digit after 0x"));
+            }
+            if digits.len() > 1 && digits.starts_with('0') {
+                return Err(invalid("a hex string without leading zeros"));
+            }
+            if let Some(c) = digits.chars().find(|c| !matches!(c, '0'..='9' | 'a'..='f')) {
+                return Err(if c.is_ascii_hexdigit() {
+                    invalid("a lower case hex string")
+                } else {
+                    invalid("a string of hex digits")
+                });
+            }
+            if BITS == 0 && value != ZERO_STR {
+                return Err(too_large(BITS));
+            }
+            Uint::from_str_radix(digits, 16).map_err(|_| too_large(BITS))
+        }
+    }
+}
+
+pub mod fixed_hex {
+    //! Fixed-width hex strings.
+    //!
+    //! Values are `0x` prefixed hex strings of exactly `2 * Uint::BYTES`
+    //! digits, including leading zeros, in all formats. Deserialization
+    //! accepts upper and lower case digits, but requires the full width.
+    //!
+    //! ```
+    //! # use ruint::aliases::U64;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Header {
+    //!     #[serde(with = "ruint::support::serde::fixed_hex")]
+    //!     nonce: U64,
+    //! }
+    //!
+    //! let json = serde_json::to_string(&Header {
+    //!     nonce: U64::from(0x42),
+    //! })
+    //! .unwrap();
+    //! assert_eq!(json, r#"{"nonce":"0x0000000000000042"}"#);
+    //! ```
+
+    use super::{nbytes, parse_digits, ParseFailure, StrVisitor};
+    use crate::Uint;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a [`Uint`] as a fixed-width hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_human_full(serializer)
+    }
+
+    /// Deserializes a [`Uint`] from a fixed-width hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a `0x` prefixed hex string of
+    /// exactly `2 * Uint::BYTES` digits, or if the value does not fit in `BITS`
+    /// bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a 0x prefixed fixed-width hex string",
+            parse:     parse::<BITS, LIMBS>,
+        })
+    }
+
+    fn parse<const BITS: usize, const LIMBS: usize>(
+        value: &str,
+    ) -> Result<Uint<BITS, LIMBS>, ParseFailure> {
+        // `Uint<0, 0>` is serialized as `0x0`.
+        let width = if BITS == 0 { 1 } else { 2 * nbytes(BITS) };
+        match value.strip_prefix("0x") {
+            Some(digits) if digits.len() == width => parse_digits(digits, 16),
+            _ => Err(ParseFailure::Invalid),
+        }
+    }
+}
+
+pub mod quantity {
+    //! Minimal hex strings, the `QUANTITY` encoding of Ethereum JSON-RPC.
+    //!
+    //! Values are `0x` prefixed hex strings without leading zeros, with zero
+    //! being `0x0`, in all formats. This is the default representation in human
+    //! readable formats. Deserialization requires the `0x` prefix, but accepts
+    //! upper case digits and leading zeros.
+    //!
+    //! ```
+    //! # use ruint::aliases::U256;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Block {
+    //!     #[serde(with = "ruint::support::serde::quantity")]
+    //!     number: U256,
+    //! }
+    //!
+    //! let block: Block = serde_json::from_str(r#"{"number":"0x10d4f"}"#).unwrap();
+    //! assert_eq!(block.number, U256::from(68943));
+    //! ```
+
+    use super::{parse_digits, ParseFailure, StrVisitor};
+    use crate::Uint;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a [`Uint`] as a minimal hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_human_minimal(serializer)
+    }
+
+    /// Deserializes a [`Uint`] from a `0x` prefixed hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a `0x` prefixed hex string with at
+    /// least one digit, or if the value does not fit in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a 0x prefixed hex string",
+            parse:     parse::<BITS, LIMBS>,
+        })
+    }
+
+    fn parse<const BITS: usize, const LIMBS: usize>(
+        value: &str,
+    ) -> Result<Uint<BITS, LIMBS>, ParseFailure> {
+        let digits = value.strip_prefix("0x").ok_or(ParseFailure::Invalid)?;
+        parse_digits(digits, 16)
+    }
+}
+
+pub mod decimal {
+    //! Decimal strings.
+    //!
+    //! Values are decimal strings without leading zeros in all formats.
+    //! Deserialization accepts leading zeros, but no prefix, sign or
+    //! separators.
+    //!
+    //! ```
+    //! # use ruint::aliases::U256;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Balance {
+    //!     #[serde(with = "ruint::support::serde::decimal")]
+    //!     wei: U256,
+    //! }
+    //!
+    //! let balance = Balance {
+    //!     wei: U256::from(10).pow(U256::from(18)),
+    //! };
+    //! let json = serde_json::to_string(&balance).unwrap();
+    //! assert_eq!(json, r#"{"wei":"1000000000000000000"}"#);
+    //! ```
+
+    use super::{parse_digits, ParseFailure, StrVisitor};
+    use crate::Uint;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a [`Uint`] as a decimal string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a [`Uint`] from a decimal string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a non-empty string of decimal
+    /// digits, or if the value does not fit in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a decimal string",
+            parse:     parse::<BITS, LIMBS>,
+        })
+    }
+
+    fn parse<const BITS: usize, const LIMBS: usize>(
+        value: &str,
+    ) -> Result<Uint<BITS, LIMBS>, ParseFailure> {
+        parse_digits(value, 10)
+    }
+}
+
+pub mod base64 {
+    //! Base64 encoded big-endian bytes.
+    //!
+    //! Values are the [`Uint::BYTES`] big-endian bytes, including leading
+    //! zeros, encoded as a padded string in the standard base64 alphabet of
+    //! [RFC 4648], in all formats. Deserialization also accepts fewer bytes.
+    //!
+    //! ```
+    //! # use ruint::aliases::U64;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Record {
+    //!     #[serde(with = "ruint::support::serde::base64")]
+    //!     id: U64,
+    //! }
+    //!
+    //! let json = serde_json::to_string(&Record {
+    //!     id: U64::from(0xfbff),
+    //! })
+    //! .unwrap();
+    //! assert_eq!(json, r#"{"id":"AAAAAAAA+/8="}"#);
+    //! ```
+    //!
+    //! [RFC 4648]: https://www.rfc-editor.org/rfc/rfc4648#section-4
+
+    use super::{ParseFailure, StrVisitor};
+    use crate::Uint;
+    use serde::{Deserializer, Serializer};
+
+    #[allow(unused_imports)]
+    use alloc::{string::String, vec::Vec};
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// Serializes a [`Uint`] as base64 encoded big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(&value.to_be_bytes_vec()))
+    }
+
+    /// Deserializes a [`Uint`] from base64 encoded big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not padded standard base64, if it
+    /// encodes more than [`Uint::BYTES`] bytes, or if the value does not fit
+    /// in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a base64 string",
+            parse:     parse::<BITS, LIMBS>,
+        })
+    }
+
+    fn parse<const BITS: usize, const LIMBS: usize>(
+        value: &str,
+    ) -> Result<Uint<BITS, LIMBS>, ParseFailure> {
+        let bytes = decode(value).ok_or(ParseFailure::Invalid)?;
+        Uint::try_from_be_slice(&bytes).ok_or(ParseFailure::TooLarge)
+    }
+
+    fn encode(bytes: &[u8]) -> String {
+        let mut result = String::with_capacity((bytes.len() + 2) / 3 * 4);
+        for chunk in bytes.chunks(3) {
+            let mut buffer = [0; 3];
+            buffer[..chunk.len()].copy_from_slice(chunk);
+            let n = u32::from_be_bytes([0, buffer[0], buffer[1], buffer[2]]);
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    result.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+                } else {
+                    result.push('=');
+                }
+            }
+        }
+        result
+    }
+
+    fn decode(value: &str) -> Option<Vec<u8>> {
+        let value = value.as_bytes();
+        if value.len() % 4 != 0 {
+            return None;
+        }
+        let mut result = Vec::with_capacity(value.len() / 4 * 3);
+        let chunks = value.chunks(4);
+        let last = chunks.len().saturating_sub(1);
+        for (index, chunk) in chunks.enumerate() {
+            let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+            if padding > 2 || (padding > 0 && index != last) {
+                return None;
+            }
+            let mut n = 0_u32;
+            for &c in &chunk[..4 - padding] {
+                let digit = ALPHABET.iter().position(|&a| a == c)?;
+                #[allow(clippy::cast_possible_truncation)] // digit < 64
+                let digit = digit as u32;
+                n = n << 6 | digit;
+            }
+            n <<= 6 * padding;
+            let bytes = n.to_be_bytes();
+            result.extend_from_slice(&bytes[1..4 - padding]);
+        }
+        Some(result)
+    }
+}
+
+pub mod limbs {
+    //! Arrays of `u64` limbs.
+    //!
+    //! Values are arrays of exactly `LIMBS` numbers, least significant limb
+    //! first, in all formats. This matches [`Uint::as_limbs`].
+    //!
+    //! ```
+    //! # use ruint::aliases::U128;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize)]
+    //! struct State {
+    //!     #[serde(with = "ruint::support::serde::limbs")]
+    //!     value: U128,
+    //! }
+    //!
+    //! let json = serde_json::to_string(&State {
+    //!     value: U128::from(u64::MAX) + U128::from(3),
+    //! })
+    //! .unwrap();
+    //! assert_eq!(json, r#"{"value":[2,1]}"#);
+    //! ```
+
+    use super::too_large;
+    use crate::Uint;
+    use core::fmt::{Formatter, Result as FmtResult};
+    use serde::{
+        de::{Error, SeqAccess, Visitor},
+        ser::SerializeTuple,
+        Deserializer, Serializer,
+    };
+
+    /// Serializes a [`Uint`] as an array of limbs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(LIMBS)?;
+        for limb in value.as_limbs() {
+            tuple.serialize_element(limb)?;
+        }
+        tuple.end()
+    }
+
+    /// Deserializes a [`Uint`] from an array of limbs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not an array of `LIMBS` numbers, or
+    /// if the value does not fit in `BITS` bits.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_tuple(LIMBS, LimbsVisitor)
+    }
+
+    struct LimbsVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for LimbsVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            write!(
+                formatter,
+                "an array of {LIMBS} limbs of at most {BITS} bits"
+            )
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut limbs = [0; LIMBS];
+            for (i, limb) in limbs.iter_mut().enumerate() {
+                *limb = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(i, &self))?;
+            }
+            Uint::checked_from_limbs_slice(&limbs).ok_or_else(|| too_large(BITS))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +1008,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_human_readable_de_numbers() {
+        let numbers: Vec<Uint<8, 1>> = serde_json::from_str("[0, 255]").unwrap();
+        assert_eq!(numbers, vec![Uint::ZERO, Uint::from(255)]);
+        assert!(serde_json::from_str::<Uint<8, 1>>("-1").is_err());
+        assert!(serde_json::from_str::<Uint<8, 1>>("256").is_err());
+        assert!(serde_json::from_str::<Uint<8, 1>>("1.5").is_err());
+        assert_eq!(
+            serde_json::from_str::<Uint<8, 1>>("256")
+                .unwrap_err()
+                .to_string(),
+            "value too large for Uint<8> at line 1 column 3"
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Strict(#[serde(with = "strict")] Uint<64, 1>);
+
+    #[test]
+    fn test_strict() {
+        let values: Vec<Strict> =
+            serde_json::from_str(r#"[10, "10", "0x10", "0x0010", "0xAbC", 18446744073709551615]"#)
+                .unwrap();
+        assert_eq!(values, vec![
+            Strict(Uint::from(10)),
+            Strict(Uint::from(10)),
+            Strict(Uint::from(16)),
+            Strict(Uint::from(16)),
+            Strict(Uint::from(0xabc)),
+            Strict(Uint::MAX)
+        ]);
+        for json in [
+            r#""""#,
+            r#""0x""#,
+            r#""0X10""#,
+            r#""0o10""#,
+            r#""0b10""#,
+            r#""1_000""#,
+            r#"" 10""#,
+            r#""+10""#,
+            r#""ff""#,
+            "-1",
+            "1.0",
+            "null",
+            r#""0x10000000000000000""#,
+            r#""18446744073709551616""#,
+        ] {
+            assert!(serde_json::from_str::<Strict>(json).is_err(), "{json}");
+        }
+        assert_eq!(
+            serde_json::to_string(&Strict(Uint::from(16))).unwrap(),
+            r#""0x10""#
+        );
+    }
+
+    #[test]
+    fn test_strict_roundtrip() {
+        proptest!(|(value: Uint<64, 1>)| {
+            let serialized = serde_json::to_string(&Strict(value)).unwrap();
+            assert_eq!(serde_json::from_str::<Strict>(&serialized).unwrap().0, value);
+            let serialized = bincode::serialize(&Strict(value)).unwrap();
+            assert_eq!(bincode::deserialize::<Strict>(&serialized).unwrap().0, value);
+        });
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Canonical(#[serde(with = "canonical")] Uint<16, 1>);
+
+    #[test]
+    fn test_canonical() {
+        let values: Vec<Canonical> = serde_json::from_str(r#"["0x0", "0x1", "0xffff"]"#).unwrap();
+        assert_eq!(values, vec![
+            Canonical(Uint::ZERO),
+            Canonical(Uint::from(1)),
+            Canonical(Uint::MAX)
+        ]);
+        for (json, error) in [
+            (r#""10""#, "expected a 0x prefixed hex string"),
+            (r#""0X10""#, "expected a 0x prefixed hex string"),
+            (r#""0x""#, "expected at least one hex digit after 0x"),
+            (r#""0x00""#, "expected a hex string without leading zeros"),
+            (r#""0x010""#, "expected a hex string without leading zeros"),
+            (r#""0xAB""#, "expected a lower case hex string"),
+            (r#""0xag""#, "expected a string of hex digits"),
+            (r#""0x1_0""#, "expected a string of hex digits"),
+            (r#""0x10000""#, "value too large for Uint<16>"),
+            ("16", "expected a 0x prefixed lower case hex string"),
+        ] {
+            let result = serde_json::from_str::<Canonical>(json)
+                .unwrap_err()
+                .to_string();
+            assert!(result.contains(error), "{json}: {result}");
+        }
+    }
+
+    #[test]
+    fn test_canonical_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let mut json = Vec::new();
+                canonical::serialize(&value, &mut serde_json::Serializer::new(&mut json)).unwrap();
+                let mut deserializer = serde_json::Deserializer::from_slice(&json);
+                assert_eq!(canonical::deserialize::<_, BITS, LIMBS>(&mut deserializer).unwrap(), value);
+            });
+        });
+    }
+
     #[test]
     fn test_serde_machine_readable() {
         const_for!(BITS in SIZES {
@@ -263,4 +1155,164 @@ mod tests {
             assert!(deserialized.is_err(), "{BITS} {serialized}");
         });
     }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Compact<const BITS: usize, const LIMBS: usize>(
+        #[serde(with = "compact")] Uint<BITS, LIMBS>,
+    );
+
+    #[test]
+    fn test_compact_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let serialized = bincode::serialize(&Compact(value)).unwrap();
+                assert_eq!(serialized.len(), 8 + value.byte_len());
+                let deserialized: Compact<BITS, LIMBS> = bincode::deserialize(&serialized).unwrap();
+                assert_eq!(deserialized.0, value);
+
+                let serialized = serde_json::to_string(&Compact(value)).unwrap();
+                let deserialized: Compact<BITS, LIMBS> = serde_json::from_str(&serialized).unwrap();
+                assert_eq!(deserialized.0, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_compact_de() {
+        let value: Compact<16, 1> = serde_json::from_str("[]").unwrap();
+        assert_eq!(value.0, Uint::ZERO);
+        let value: Compact<16, 1> = serde_json::from_str("[18, 52]").unwrap();
+        assert_eq!(value.0, Uint::from(0x1234));
+        let value: Compact<16, 1> = serde_json::from_str("[0, 1]").unwrap();
+        assert_eq!(value.0, Uint::from(1));
+        assert!(serde_json::from_str::<Compact<16, 1>>("[0, 0, 1]").is_err());
+        assert!(serde_json::from_str::<Compact<12, 1>>("[16, 0]").is_err());
+        assert!(serde_json::from_str::<Compact<16, 1>>(r#""0x1234""#).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Adapters<const BITS: usize, const LIMBS: usize> {
+        #[serde(with = "fixed_hex")]
+        fixed_hex: Uint<BITS, LIMBS>,
+        #[serde(with = "quantity")]
+        quantity:  Uint<BITS, LIMBS>,
+        #[serde(with = "decimal")]
+        decimal:   Uint<BITS, LIMBS>,
+        #[serde(with = "base64")]
+        base64:    Uint<BITS, LIMBS>,
+        #[serde(with = "limbs")]
+        limbs:     Uint<BITS, LIMBS>,
+    }
+
+    #[test]
+    fn test_adapters_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let adapters = Adapters {
+                    fixed_hex: value,
+                    quantity:  value,
+                    decimal:   value,
+                    base64:    value,
+                    limbs:     value,
+                };
+                let serialized = serde_json::to_string(&adapters).unwrap();
+                let deserialized: Adapters<BITS, LIMBS> = serde_json::from_str(&serialized).unwrap();
+                assert_eq!(deserialized, adapters);
+                let serialized = bincode::serialize(&adapters).unwrap();
+                let deserialized: Adapters<BITS, LIMBS> = bincode::deserialize(&serialized).unwrap();
+                assert_eq!(deserialized, adapters);
+            });
+        });
+    }
+
+    #[test]
+    fn test_adapters_format() {
+        let adapters = Adapters::<72, 2> {
+            fixed_hex: Uint::from(0x1234),
+            quantity:  Uint::from(0x1234),
+            decimal:   Uint::from(0x1234),
+            base64:    Uint::from(0x1234),
+            limbs:     Uint::from_limbs([0x1234, 0x56]),
+        };
+        assert_eq!(
+            serde_json::to_string(&adapters).unwrap(),
+            r#"{"fixed_hex":"0x000000000000001234","quantity":"0x1234","decimal":"4660","base64":"AAAAAAAAABI0","limbs":[4660,86]}"#
+        );
+        let zero = Adapters::<0, 0> {
+            fixed_hex: Uint::ZERO,
+            quantity:  Uint::ZERO,
+            decimal:   Uint::ZERO,
+            base64:    Uint::ZERO,
+            limbs:     Uint::ZERO,
+        };
+        assert_eq!(
+            serde_json::to_string(&zero).unwrap(),
+            r#"{"fixed_hex":"0x0","quantity":"0x0","decimal":"0","base64":"","limbs":[]}"#
+        );
+    }
+
+    #[test]
+    fn test_adapters_de() {
+        #[derive(Debug, Deserialize)]
+        struct Lenient {
+            #[serde(with = "fixed_hex")]
+            fixed_hex: Uint<16, 1>,
+            #[serde(with = "quantity")]
+            quantity:  Uint<16, 1>,
+            #[serde(with = "decimal")]
+            decimal:   Uint<16, 1>,
+            #[serde(with = "base64")]
+            base64:    Uint<16, 1>,
+        }
+        let lenient: Lenient = serde_json::from_str(
+            r#"{"fixed_hex":"0xABCD","quantity":"0x0001","decimal":"0042","base64":"Kg=="}"#,
+        )
+        .unwrap();
+        assert_eq!(lenient.fixed_hex, Uint::from(0xabcd));
+        assert_eq!(lenient.quantity, Uint::from(1));
+        assert_eq!(lenient.decimal, Uint::from(42));
+        assert_eq!(lenient.base64, Uint::from(42));
+    }
+
+    #[test]
+    fn test_adapters_de_errors() {
+        use serde_json::Deserializer as Json;
+
+        for json in [
+            r#""0x123""#,
+            r#""0x12345""#,
+            r#""1234""#,
+            r#""0xgggg""#,
+            "4660",
+        ] {
+            let result = fixed_hex::deserialize::<_, 16, 1>(&mut Json::from_str(json));
+            assert!(result.is_err(), "{json}");
+        }
+        for json in [r#""1234""#, r#""0x""#, r#""0x10000""#, r#""0x1_0""#, "1"] {
+            let result = quantity::deserialize::<_, 16, 1>(&mut Json::from_str(json));
+            assert!(result.is_err(), "{json}");
+        }
+        for json in [r#""""#, r#""-1""#, r#""+1""#, r#""65536""#, r#""0x1""#, "1"] {
+            let result = decimal::deserialize::<_, 16, 1>(&mut Json::from_str(json));
+            assert!(result.is_err(), "{json}");
+        }
+        for json in [
+            r#""Kg""#,
+            r#""K===""#,
+            r#""Kg==Kg==""#,
+            r#""AAAA""#,
+            r#""*g==""#,
+        ] {
+            let result = base64::deserialize::<_, 16, 1>(&mut Json::from_str(json));
+            assert!(result.is_err(), "{json}");
+        }
+        for json in ["[1]", "[1, 256]", "[1, 2, 3]", r#""0x1""#] {
+            let result = limbs::deserialize::<_, 72, 2>(&mut Json::from_str(json));
+            assert!(result.is_err(), "{json}");
+        }
+        let result = limbs::deserialize::<_, 72, 2>(&mut Json::from_str("[1, 255]"));
+        assert_eq!(result.unwrap(), Uint::from_limbs([1, 255]));
+    }
 }
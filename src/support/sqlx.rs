@@ -1,6 +1,19 @@
 //! Support for the [`sqlx`](https://crates.io/crates/sqlx) crate.
 //!
-//! Currently only encodes to/from a big-endian byte array.
+//! By default [`Uint`] is stored as a [`Uint::BYTES`] big-endian byte array,
+//! for any database with a binary type: `BYTEA` in PostgreSQL, `VARBINARY` or
+//! `BLOB` in MySQL and `BLOB` in SQLite. This round-trips every value, and
+//! decoding accepts shorter inputs.
+//!
+//! With the `sqlx-mysql` and `sqlx-sqlite` features the [`Decimal`] wrapper
+//! stores the value as a decimal number instead, which is readable in SQL:
+//!
+//! * MySQL `DECIMAL(65, 0)` holds values below `10^65`, about 215 bits, so
+//!   larger types such as `U256` only round-trip if the values are in range.
+//!   Values with a scale are accepted if the fractional digits are zero.
+//! * SQLite `TEXT` holds decimal strings of any length and round-trips every
+//!   value. `INTEGER` columns can be decoded too. Note that text compares
+//!   lexicographically, so use `BLOB` if ordering matters.
 
 #![cfg(feature = "sqlx")]
 #![cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
@@ -15,10 +28,20 @@ use sqlx_core::{
 };
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[cfg(feature = "sqlx-mysql")]
+use sqlx_core::type_info::TypeInfo;
+#[cfg(feature = "sqlx-mysql")]
+use sqlx_mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+#[cfg(feature = "sqlx-sqlite")]
+use sqlx_sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+#[derive(Clone, PartialEq, Eq, Debug, Error)]
 pub enum DecodeError {
     #[error("Value too large for target type")]
     Overflow,
+
+    #[error("Value is not a non-negative decimal integer: {0:?}")]
+    InvalidDecimal(String),
 }
 
 impl<const BITS: usize, const LIMBS: usize, DB: Database> Type<DB> for Uint<BITS, LIMBS>
@@ -52,3 +75,146 @@ where
         Self::try_from_be_slice(bytes.as_slice()).ok_or_else(|| DecodeError::Overflow.into())
     }
 }
+
+/// A [`Uint`] stored as a decimal number.
+///
+/// See the [module documentation](self) for the supported column types.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Decimal<BITS, LIMBS> {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Decimal<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    fn from(value: Decimal<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decimal<BITS, LIMBS> {
+    /// Parses a decimal integer, allowing a fractional part of only zeros.
+    #[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+    fn parse(value: &str) -> Result<Self, DecodeError> {
+        let invalid = || DecodeError::InvalidDecimal(value.to_owned());
+        let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if integer.is_empty()
+            || !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b == b'0')
+        {
+            return Err(invalid());
+        }
+        Uint::from_str_radix(integer, 10)
+            .map(Self)
+            .map_err(|_| DecodeError::Overflow)
+    }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl<const BITS: usize, const LIMBS: usize> Type<MySql> for Decimal<BITS, LIMBS> {
+    fn type_info() -> MySqlTypeInfo {
+        // Strings are converted to `DECIMAL` on insertion without loss.
+        <str as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.name() == "DECIMAL" || <str as Type<MySql>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl<const BITS: usize, const LIMBS: usize> Encode<'_, MySql> for Decimal<BITS, LIMBS> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <String as Encode<MySql>>::encode(self.0.to_string(), buf)
+    }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl<const BITS: usize, const LIMBS: usize> Decode<'_, MySql> for Decimal<BITS, LIMBS> {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Self::parse(<&str as Decode<MySql>>::decode(value)?)?)
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl<const BITS: usize, const LIMBS: usize> Type<Sqlite> for Decimal<BITS, LIMBS> {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <str as Type<Sqlite>>::compatible(ty) || <i64 as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl<'q, const BITS: usize, const LIMBS: usize> Encode<'q, Sqlite> for Decimal<BITS, LIMBS> {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        <String as Encode<Sqlite>>::encode(self.0.to_string(), buf)
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl<const BITS: usize, const LIMBS: usize> Decode<'_, Sqlite> for Decimal<BITS, LIMBS> {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        // SQLite converts `INTEGER` values to text.
+        Ok(Self::parse(<&str as Decode<Sqlite>>::decode(value)?)?)
+    }
+}
+
+#[cfg(all(test, any(feature = "sqlx-mysql", feature = "sqlx-sqlite")))]
+mod tests {
+    use super::*;
+    use crate::aliases::U256;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(Decimal::parse("0"), Ok(Decimal(U256::ZERO)));
+        assert_eq!(Decimal::parse("1234.000"), Ok(Decimal(U256::from(1234))));
+        assert_eq!(
+            Decimal::parse(&U256::MAX.to_string()),
+            Ok(Decimal(U256::MAX))
+        );
+        assert_eq!(Decimal::<8, 1>::parse("256"), Err(DecodeError::Overflow));
+        for invalid in ["", "-1", "+1", "1.5", ".0", "1e3", "0x10", "1_000"] {
+            assert_eq!(
+                Decimal::<256, 4>::parse(invalid),
+                Err(DecodeError::InvalidDecimal(invalid.to_owned()))
+            );
+        }
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    #[test]
+    fn test_sqlite_roundtrip() {
+        use futures_executor::block_on;
+        use sqlx_core::{connection::Connection, executor::Executor, query::query};
+        use sqlx_sqlite::SqliteConnection;
+
+        block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            conn.execute("CREATE TABLE t (bytes BLOB, text TEXT, int INTEGER)")
+                .await
+                .unwrap();
+            for value in [U256::ZERO, U256::from(42), U256::MAX] {
+                query("INSERT INTO t VALUES (?, ?, 7)")
+                    .bind(value)
+                    .bind(Decimal(value))
+                    .execute(&mut conn)
+                    .await
+                    .unwrap();
+                let (bytes, text, int): (U256, Decimal<256, 4>, Decimal<256, 4>) =
+                    sqlx_core::query_as::query_as("SELECT * FROM t")
+                        .fetch_one(&mut conn)
+                        .await
+                        .unwrap();
+                assert_eq!(bytes, value);
+                assert_eq!(text.0, value);
+                assert_eq!(int.0, U256::from(7));
+                conn.execute("DELETE FROM t").await.unwrap();
+            }
+        });
+    }
+}
@@ -1,6 +1,13 @@
+//! Support for the [`ethereum_ssz`](https://crates.io/crates/ethereum_ssz) and
+//! [`tree_hash`](https://crates.io/crates/tree_hash) crates.
+//!
+//! Values are encoded as the consensus-spec `uintN` basic type: exactly
+//! [`Uint::BYTES`] little-endian bytes.
+
 #![cfg(feature = "ssz")]
 #![cfg_attr(docsrs, doc(cfg(feature = "ssz")))]
 use ssz::{Decode, DecodeError, Encode};
+use tree_hash::{Hash256, PackedEncoding, TreeHash, TreeHashType, HASHSIZE};
 
 use crate::{nbytes, Uint};
 
@@ -32,21 +39,57 @@ impl<const BITS: usize, const LIMBS: usize> Decode for Uint<BITS, LIMBS> {
     }
 
     fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
-        if bytes.len() > nbytes(BITS) {
+        if bytes.len() != nbytes(BITS) {
             return Err(DecodeError::InvalidByteLength {
                 len:      bytes.len(),
                 expected: nbytes(BITS),
             });
         }
-        Ok(Self::from_le_slice(bytes))
+        Self::try_from_le_slice(bytes)
+            .ok_or_else(|| DecodeError::BytesInvalid(format!("value does not fit in {BITS} bits")))
     }
 }
 
+/// Uints hash as the basic type `uintN`. This is only defined for the sizes in
+/// the consensus spec, `N` being one of 8, 16, 32, 64, 128 and 256.
+impl<const BITS: usize, const LIMBS: usize> TreeHash for Uint<BITS, LIMBS> {
+    fn tree_hash_type() -> TreeHashType {
+        TreeHashType::Basic
+    }
+
+    fn tree_hash_packed_encoding(&self) -> PackedEncoding {
+        PackedEncoding::from_slice(&self.as_le_bytes())
+    }
+
+    #[track_caller]
+    fn tree_hash_packing_factor() -> usize {
+        assert_basic_size::<BITS>();
+        HASHSIZE / nbytes(BITS)
+    }
+
+    #[track_caller]
+    fn tree_hash_root(&self) -> Hash256 {
+        assert_basic_size::<BITS>();
+        let mut root = [0; HASHSIZE];
+        root[..nbytes(BITS)].copy_from_slice(&self.as_le_bytes());
+        Hash256::from(root)
+    }
+}
+
+#[track_caller]
+fn assert_basic_size<const BITS: usize>() {
+    assert!(
+        BITS.is_power_of_two() && (8..=256).contains(&BITS),
+        "SSZ uintN is only defined for N in 8, 16, 32, 64, 128 and 256"
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::proptest;
     use ruint::{const_for, nlimbs, Uint};
     use ssz::DecodeError;
+    use tree_hash::TreeHash;
 
     #[test]
     fn test_ssz_human_readable() {
@@ -76,4 +119,52 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_ssz_decode_error_short() {
+        let result = <Uint<64, 1> as ssz::Decode>::from_ssz_bytes(&[1, 2, 3]);
+        assert!(matches!(
+            result,
+            Err(DecodeError::InvalidByteLength {
+                len:      3,
+                expected: 8,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_ssz_decode_error_overflow() {
+        let result = <Uint<12, 1> as ssz::Decode>::from_ssz_bytes(&[0xff, 0x1f]);
+        assert!(matches!(result, Err(DecodeError::BytesInvalid(_))));
+    }
+
+    #[test]
+    fn test_tree_hash_matches_primitives() {
+        proptest!(|(value: u64)| {
+            let uint = Uint::<64, 1>::from(value);
+            assert_eq!(uint.tree_hash_root(), value.tree_hash_root());
+            assert_eq!(uint.tree_hash_packed_encoding(), value.tree_hash_packed_encoding());
+            assert_eq!(Uint::<64, 1>::tree_hash_packing_factor(), u64::tree_hash_packing_factor());
+        });
+        proptest!(|(value: u8)| {
+            let uint = Uint::<8, 1>::from(value);
+            assert_eq!(uint.tree_hash_root(), value.tree_hash_root());
+            assert_eq!(Uint::<8, 1>::tree_hash_packing_factor(), u8::tree_hash_packing_factor());
+        });
+    }
+
+    #[test]
+    fn test_tree_hash_u256() {
+        proptest!(|(value: Uint<256, 4>)| {
+            assert_eq!(value.tree_hash_root().as_bytes(), &value.to_le_bytes::<32>());
+            assert_eq!(Uint::<256, 4>::tree_hash_packing_factor(), 1);
+        });
+        assert_eq!(Uint::<128, 2>::tree_hash_packing_factor(), 2);
+    }
+
+    #[test]
+    #[should_panic = "SSZ uintN is only defined"]
+    fn test_tree_hash_unsupported_size() {
+        let _ = Uint::<24, 1>::ZERO.tree_hash_root();
+    }
 }
@@ -0,0 +1,70 @@
+//! Support for the [`subtle`](https://crates.io/crates/subtle) crate.
+
+#![cfg(feature = "subtle")]
+#![cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+
+use crate::{Bits, Uint};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+impl<const BITS: usize, const LIMBS: usize> ConstantTimeEq for Uint<BITS, LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_limbs().ct_eq(other.as_limbs())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConditionallySelectable for Uint<BITS, LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0; LIMBS];
+        for ((limb, a), b) in limbs.iter_mut().zip(a.as_limbs()).zip(b.as_limbs()) {
+            *limb = u64::conditional_select(a, b, choice);
+        }
+        Self::from_limbs(limbs)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConstantTimeGreater for Uint<BITS, LIMBS> {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        // `self > other` iff `other - self` borrows.
+        let (_, borrow) = other.overflowing_sub(*self);
+        Choice::from(u8::from(borrow))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConstantTimeLess for Uint<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> ConstantTimeEq for Bits<BITS, LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_uint().ct_eq(other.as_uint())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConditionallySelectable for Bits<BITS, LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Uint::conditional_select(a.as_uint(), b.as_uint(), choice).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_subtle() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, choice: bool)| {
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                assert!(bool::from(a.ct_eq(&a)));
+                assert_eq!(bool::from(a.ct_gt(&b)), a > b);
+                assert_eq!(bool::from(a.ct_lt(&b)), a < b);
+                let selected = U::conditional_select(&a, &b, Choice::from(u8::from(choice)));
+                assert_eq!(selected, if choice { b } else { a });
+            });
+        });
+    }
+}
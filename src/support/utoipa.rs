@@ -0,0 +1,132 @@
+//! Support for the [`utoipa`](https://crates.io/crates/utoipa) crate.
+//!
+//! Implements [`PartialSchema`] and [`ToSchema`] for [`Uint`] and [`Bits`],
+//! describing their human readable `serde` representation: a `0x` prefixed
+//! lower case hex string, minimal for [`Uint`] and zero-padded for [`Bits`].
+//! The schemas are registered as components named after the aliases, e.g.
+//! `U256` and `B256`, so derived schemas refer to them by reference:
+//!
+//! ```
+//! # use ruint::aliases::U256;
+//! # use utoipa::{OpenApi, ToSchema};
+//! #[derive(ToSchema)]
+//! struct Balance {
+//!     amount: U256,
+//! }
+//!
+//! #[derive(OpenApi)]
+//! #[openapi(components(schemas(Balance)))]
+//! struct Api;
+//!
+//! let json = serde_json::to_value(Api::openapi()).unwrap();
+//! let schemas = &json["components"]["schemas"];
+//! assert_eq!(
+//!     schemas["Balance"]["properties"]["amount"]["$ref"],
+//!     "#/components/schemas/U256"
+//! );
+//! assert_eq!(schemas["U256"]["pattern"], "^0x(0|[1-9a-f][0-9a-f]{0,63})$");
+//! ```
+//!
+//! The patterns limit the number of digits but not the value of the leading
+//! digit, so they can admit values slightly out of range when `BITS` is not a
+//! multiple of four.
+
+#![cfg(feature = "utoipa")]
+#![cfg_attr(docsrs, doc(cfg(feature = "utoipa")))]
+
+use crate::{nbytes, Bits, Uint};
+use std::borrow::Cow;
+use utoipa::{
+    openapi::{schema::Schema, ObjectBuilder, RefOr, Type},
+    PartialSchema, ToSchema,
+};
+
+/// Builds a string schema matching `pattern`.
+fn schema(description: String, pattern: String, example: &str) -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .description(Some(description))
+        .pattern(Some(pattern))
+        .examples([example])
+        .into()
+}
+
+impl<const BITS: usize, const LIMBS: usize> PartialSchema for Uint<BITS, LIMBS> {
+    fn schema() -> RefOr<Schema> {
+        let pattern = match (BITS + 3) / 4 {
+            0 => "^0x0$".into(),
+            digits => format!("^0x(0|[1-9a-f][0-9a-f]{{0,{}}})$", digits - 1),
+        };
+        let description = format!("{BITS}-bit unsigned integer as minimal 0x prefixed hex");
+        schema(description, pattern, "0x0")
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSchema for Uint<BITS, LIMBS> {
+    fn name() -> Cow<'static, str> {
+        format!("U{BITS}").into()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PartialSchema for Bits<BITS, LIMBS> {
+    fn schema() -> RefOr<Schema> {
+        let digits = nbytes(BITS) * 2;
+        let pattern = match digits {
+            0 => "^0x0$".into(),
+            digits => format!("^0x[0-9a-f]{{{digits}}}$"),
+        };
+        let description = format!("{BITS} bits as zero-padded 0x prefixed hex");
+        let example = format!("0x{:0>digits$}", "0");
+        schema(description, pattern, &example)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToSchema for Bits<BITS, LIMBS> {
+    fn name() -> Cow<'static, str> {
+        format!("B{BITS}").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+    use serde_json::Value;
+
+    fn to_json<T: PartialSchema>() -> Value {
+        serde_json::to_value(T::schema()).unwrap()
+    }
+
+    #[test]
+    fn test_schema() {
+        let json = to_json::<U256>();
+        assert_eq!(json["type"], "string");
+        assert_eq!(json["pattern"], "^0x(0|[1-9a-f][0-9a-f]{0,63})$");
+        assert_eq!(to_json::<Bits<256, 4>>()["pattern"], "^0x[0-9a-f]{64}$");
+        assert_eq!(to_json::<Uint<0, 0>>()["pattern"], "^0x0$");
+        assert_eq!(to_json::<Bits<0, 0>>()["examples"][0], "0x0");
+        assert_eq!(U256::name(), "U256");
+        assert_eq!(Bits::<160, 3>::name(), "B160");
+    }
+
+    #[test]
+    fn test_schema_matches_serialize() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            let digits = (BITS + 3) / 4;
+            let example = &to_json::<Bits<BITS, LIMBS>>()["examples"][0];
+            assert_eq!(example, &serde_json::to_value(Bits::<BITS, LIMBS>::ZERO).unwrap());
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let json = serde_json::to_string(&value).unwrap();
+                let hex = json.trim_matches('"').strip_prefix("0x").unwrap();
+                assert!(hex.len() <= digits);
+                assert!(hex == "0" || !hex.starts_with('0'));
+                assert!(hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+
+                let json = serde_json::to_string(&Bits::from(value)).unwrap();
+                assert_eq!(json.len(), 4 + nbytes(BITS) * 2);
+            });
+        });
+    }
+}
@@ -0,0 +1,211 @@
+//! Support for the [`zerocopy`](https://crates.io/crates/zerocopy) crate.
+//!
+//! [`Uint`] and [`Int`](crate::Int) derive [`IntoBytes`](zerocopy::IntoBytes),
+//! [`Immutable`](zerocopy::Immutable) and
+//! [`KnownLayout`](zerocopy::KnownLayout). They do not implement
+//! [`FromBytes`](zerocopy::FromBytes), because not every bit pattern is a valid
+//! [`Uint`] when `BITS` is not a multiple of 64. Instead, the
+//! `ref_from_bytes` family of methods below check the unused high bits.
+//!
+//! # Layout
+//!
+//! A [`Uint`] is stored as `[u64; LIMBS]` with the least significant limb
+//! first, and each limb in native byte order. It has the alignment of `u64`.
+//! On little-endian targets the bytes are therefore the same as
+//! [`Uint::to_le_bytes`], padded with zeros to a multiple of eight bytes. On
+//! big-endian targets neither little- nor big-endian data can be viewed
+//! without a copy; use [`Uint::from_le_slice`] or [`Uint::from_be_slice`]
+//! instead.
+
+#![cfg(feature = "zerocopy")]
+#![cfg_attr(docsrs, doc(cfg(feature = "zerocopy")))]
+
+use crate::Uint;
+use core::{fmt, mem, slice};
+use zerocopy::{ConvertError, FromBytes};
+
+/// Error for [`Uint::ref_from_bytes`] and related methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The input is not aligned to the alignment of `u64`.
+    Alignment,
+
+    /// The input has the wrong length.
+    Size,
+
+    /// The value has bits set above `BITS`.
+    Overflow,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromBytesError {}
+
+impl fmt::Display for FromBytesError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Alignment => f.write_str("Input is not aligned to 8 bytes"),
+            Self::Size => f.write_str("Input has the wrong length"),
+            Self::Overflow => f.write_str("Value is too large for the target type"),
+        }
+    }
+}
+
+impl<A, S, V> From<ConvertError<A, S, V>> for FromBytesError {
+    #[inline]
+    fn from(value: ConvertError<A, S, V>) -> Self {
+        match value {
+            ConvertError::Alignment(_) => Self::Alignment,
+            ConvertError::Size(_) => Self::Size,
+            ConvertError::Validity(_) => Self::Overflow,
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Interprets `bytes` as a [`Uint`] without copying.
+    ///
+    /// See the [module documentation](crate::support::zerocopy) for the
+    /// expected layout.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromBytesError::Alignment`] if `bytes` is not 8-byte aligned.
+    /// * [`FromBytesError::Size`] if `bytes` is not `8 * LIMBS` bytes long.
+    /// * [`FromBytesError::Overflow`] if bits above `BITS` are set.
+    #[inline]
+    pub fn ref_from_bytes(bytes: &[u8]) -> Result<&Self, FromBytesError> {
+        let limbs = <[u64; LIMBS]>::ref_from_bytes(bytes)?;
+        Self::ref_from_limbs(limbs)
+    }
+
+    /// Interprets the first `8 * LIMBS` bytes of `bytes` as a [`Uint`] without
+    /// copying, and returns it together with the remaining bytes.
+    ///
+    /// # Errors
+    ///
+    /// See [`Uint::ref_from_bytes`], except that longer inputs are accepted.
+    #[inline]
+    pub fn ref_from_prefix(bytes: &[u8]) -> Result<(&Self, &[u8]), FromBytesError> {
+        let (limbs, rest) = <[u64; LIMBS]>::ref_from_prefix(bytes)?;
+        Ok((Self::ref_from_limbs(limbs)?, rest))
+    }
+
+    /// Interprets `bytes` as a slice of [`Uint`]s without copying.
+    ///
+    /// # Errors
+    ///
+    /// See [`Uint::ref_from_bytes`], except that the length must be a multiple
+    /// of `8 * LIMBS`. Any value with bits above `BITS` set results in
+    /// [`FromBytesError::Overflow`].
+    #[inline]
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], FromBytesError> {
+        if LIMBS == 0 {
+            return if bytes.is_empty() {
+                Ok(&[])
+            } else {
+                Err(FromBytesError::Size)
+            };
+        }
+        let limbs = <[u64]>::ref_from_bytes(bytes)?;
+        if limbs.len() % LIMBS != 0 {
+            return Err(FromBytesError::Size);
+        }
+        if limbs.chunks_exact(LIMBS).any(|l| l[LIMBS - 1] > Self::MASK) {
+            return Err(FromBytesError::Overflow);
+        }
+        debug_assert_eq!(mem::size_of::<Self>(), 8 * LIMBS);
+        // SAFETY: `Uint` is `repr(transparent)` over `[u64; LIMBS]` and every
+        // chunk has been checked to be a valid value.
+        Ok(unsafe { slice::from_raw_parts(limbs.as_ptr().cast(), limbs.len() / LIMBS) })
+    }
+
+    const fn ref_from_limbs(limbs: &[u64; LIMBS]) -> Result<&Self, FromBytesError> {
+        if LIMBS > 0 && limbs[LIMBS - 1] > Self::MASK {
+            return Err(FromBytesError::Overflow);
+        }
+        // SAFETY: `Uint` is `repr(transparent)` over `[u64; LIMBS]` and the
+        // value has been checked above.
+        Ok(unsafe { &*(limbs as *const [u64; LIMBS]).cast() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U256, U64},
+        const_for, nlimbs, Int,
+    };
+    use zerocopy::IntoBytes;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let value = U::MAX;
+            let bytes = value.as_bytes();
+            assert_eq!(bytes.len(), 8 * LIMBS);
+            assert_eq!(U::ref_from_bytes(bytes), Ok(&value));
+            assert_eq!(Int::<BITS, LIMBS>::MINUS_ONE.as_bytes(), bytes);
+        });
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_little_endian() {
+        let value = U256::from(0x0102_0304_0506_0708_u64) << 64_usize;
+        assert_eq!(value.as_bytes(), value.to_le_bytes::<32>());
+    }
+
+    #[test]
+    fn test_errors() {
+        let limbs = [1_u64, 2, 3, 4, 5];
+        let bytes = limbs.as_bytes();
+        assert_eq!(
+            U256::ref_from_bytes(&bytes[..31]),
+            Err(FromBytesError::Size)
+        );
+        assert_eq!(
+            U256::ref_from_bytes(&bytes[1..33]),
+            Err(FromBytesError::Alignment)
+        );
+        assert_eq!(
+            Uint::<255, 4>::ref_from_bytes(&bytes[..32]),
+            Ok(&Uint::from_limbs([1, 2, 3, 4]))
+        );
+        assert_eq!(
+            Uint::<2, 1>::ref_from_bytes(&bytes[32..]),
+            Err(FromBytesError::Overflow)
+        );
+
+        let (value, rest) = U256::ref_from_prefix(bytes).unwrap();
+        assert_eq!(value.as_limbs(), &limbs[..4]);
+        assert_eq!(rest, 5_u64.as_bytes());
+    }
+
+    #[test]
+    fn test_slice() {
+        let limbs = [1_u64, 2, 3, 4, 5, 6, 7, 8];
+        let values = U256::slice_from_bytes(limbs.as_bytes()).unwrap();
+        assert_eq!(values, [
+            Uint::from_limbs([1, 2, 3, 4]),
+            Uint::from_limbs([5, 6, 7, 8])
+        ]);
+        assert_eq!(values.as_bytes(), limbs.as_bytes());
+        assert_eq!(
+            U256::slice_from_bytes(limbs[..6].as_bytes()),
+            Err(FromBytesError::Size)
+        );
+        assert_eq!(
+            U64::slice_from_bytes(limbs.as_bytes()).unwrap().len(),
+            limbs.len()
+        );
+        assert_eq!(
+            Uint::<3, 1>::slice_from_bytes(limbs.as_bytes()),
+            Err(FromBytesError::Overflow)
+        );
+        assert_eq!(Uint::<0, 0>::slice_from_bytes(&[]), Ok(&[][..]));
+    }
+}
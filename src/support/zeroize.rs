@@ -1,12 +1,14 @@
 //! Support for the [`zeroize`](https://crates.io/crates/zeroize) crate.
 //!
-//! Currently only encodes to/from a big-endian byte array.
+//! Implements [`Zeroize`] for [`Uint`] and [`Bits`]. As these types are
+//! `Copy` they can not zeroize themselves on drop, use [`Secret`] for that.
 
 #![cfg(feature = "zeroize")]
 #![cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
 
 use crate::{Bits, Uint};
-use zeroize::Zeroize;
+use core::fmt::{Debug, Display, Formatter, Result};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 impl<const BITS: usize, const LIMBS: usize> Zeroize for Uint<BITS, LIMBS> {
     fn zeroize(&mut self) {
@@ -22,3 +24,96 @@ impl<const BITS: usize, const LIMBS: usize> Zeroize for Bits<BITS, LIMBS> {
         self.as_uint_mut().zeroize();
     }
 }
+
+/// Wrapper for secret values that zeroizes on drop and redacts formatting.
+///
+/// [`Debug`] and [`Display`] never print the value, so it can not leak
+/// through logging. Access it explicitly using
+/// [`expose_secret`](Self::expose_secret).
+///
+/// Note that copies of the value made before wrapping, or obtained through
+/// [`expose_secret`](Self::expose_secret), are not zeroized.
+///
+/// ```
+/// # use ruint::{aliases::U256, support::zeroize::Secret};
+/// let key = Secret::new(U256::from(42));
+/// assert_eq!(format!("{key:?}"), "Secret([REDACTED])");
+/// assert_eq!(*key.expose_secret(), U256::from(42));
+/// ```
+// Not `PartialEq` as comparisons would not be constant time.
+#[derive(Clone, Default)]
+pub struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> Secret<T> {
+    /// Wrap a secret value.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Access the secret value.
+    #[must_use]
+    pub const fn expose_secret(&self) -> &T {
+        &self.0
+    }
+
+    /// Mutably access the secret value.
+    #[must_use]
+    pub fn expose_secret_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Zeroize> Zeroize for Secret<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for Secret<T> {}
+
+impl<T: Zeroize> Debug for Secret<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+impl<T: Zeroize> Display for Secret<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_secret() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let mut secret = Secret::new(value);
+                assert_eq!(*secret.expose_secret(), value);
+                assert_eq!(format!("{secret:?} {secret}"), "Secret([REDACTED]) [REDACTED]");
+                secret.zeroize();
+                assert_eq!(*secret.expose_secret(), U::ZERO);
+            });
+        });
+    }
+}
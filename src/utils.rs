@@ -33,6 +33,15 @@ pub(crate) fn trim_end_vec<T: PartialEq>(vec: &mut Vec<T>, value: &T) {
     vec.truncate(last_idx(vec, value));
 }
 
+/// Views a pair of limb arrays as a single slice of `2 * N` limbs.
+///
+/// This works around not being able to write `[u64; 2 * N]` on stable.
+#[inline(always)]
+pub(crate) fn flatten_mut<const N: usize>(pair: &mut [[u64; N]; 2]) -> &mut [u64] {
+    // SAFETY: `[[u64; N]; 2]` has the same layout as `[u64; 2 * N]`.
+    unsafe { core::slice::from_raw_parts_mut(pair.as_mut_ptr().cast::<u64>(), 2 * N) }
+}
+
 // Branch prediction hints.
 #[cfg(feature = "nightly")]
 pub(crate) use core::intrinsics::{likely, unlikely};
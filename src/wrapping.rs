@@ -0,0 +1,370 @@
+//! [`Wrapping`] and [`Saturating`] arithmetic wrappers, mirroring
+//! [`core::num::Wrapping`] and `core::num::Saturating`.
+
+use crate::Uint;
+use core::{
+    fmt,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref,
+        DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+};
+
+/// Intentionally wrapped arithmetic on `T`.
+///
+/// All operators compute modulo $2^{\mathtt{BITS}}$, like the `wrapping_*`
+/// methods on [`Uint`]. Shifts follow [`Uint::wrapping_shl`] and
+/// [`Uint::wrapping_shr`], i.e. shifting by `BITS` or more gives zero.
+///
+/// ```
+/// # use ruint::{uint, Uint, Wrapping, aliases::*};
+/// # uint!{
+/// let a = Wrapping(U256::MAX);
+/// assert_eq!(a + Wrapping(2_U256), Wrapping(1_U256));
+/// assert_eq!(-Wrapping(1_U256), a);
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+/// Intentionally saturating arithmetic on `T`.
+///
+/// Addition, subtraction and multiplication clamp to the range of the type,
+/// like the `saturating_*` methods on [`Uint`].
+///
+/// ```
+/// # use ruint::{uint, Uint, Saturating, aliases::*};
+/// # uint!{
+/// let a = Saturating(U256::MAX);
+/// assert_eq!(a + Saturating(2_U256), a);
+/// assert_eq!(Saturating(1_U256) - Saturating(2_U256), Saturating(0_U256));
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+macro_rules! impl_wrapper {
+    ($wrapper:ident) => {
+        impl<T> Deref for $wrapper<T> {
+            type Target = T;
+
+            #[inline(always)]
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $wrapper<T> {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>>
+            for $wrapper<Uint<BITS, LIMBS>>
+        {
+            #[inline(always)]
+            fn from(value: Uint<BITS, LIMBS>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> From<$wrapper<Uint<BITS, LIMBS>>>
+            for Uint<BITS, LIMBS>
+        {
+            #[inline(always)]
+            fn from(value: $wrapper<Self>) -> Self {
+                value.0
+            }
+        }
+
+        impl_wrapper!(@fmt $wrapper, Debug, Display, Binary, Octal, LowerHex, UpperHex);
+    };
+    (@fmt $wrapper:ident, $($trait:ident),*) => {$(
+        impl<T: fmt::$trait> fmt::$trait for $wrapper<T> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_wrapper_op {
+    ($wrapper:ident, $trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $op:path) => {
+        impl<const BITS: usize, const LIMBS: usize> $trait for $wrapper<Uint<BITS, LIMBS>> {
+            type Output = Self;
+
+            #[inline(always)]
+            #[track_caller]
+            fn $fn(self, rhs: Self) -> Self {
+                Self($op(self.0, rhs.0))
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign for $wrapper<Uint<BITS, LIMBS>> {
+            #[inline(always)]
+            #[track_caller]
+            fn $fn_assign(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_wrapper_shift {
+    ($wrapper:ident, $trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $op:path) => {
+        impl<const BITS: usize, const LIMBS: usize> $trait<usize> for $wrapper<Uint<BITS, LIMBS>> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: usize) -> Self {
+                Self($op(self.0, rhs))
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign<usize>
+            for $wrapper<Uint<BITS, LIMBS>>
+        {
+            #[inline(always)]
+            fn $fn_assign(&mut self, rhs: usize) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_wrapper!(Wrapping);
+impl_wrapper_op!(
+    Wrapping,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    Uint::wrapping_add
+);
+impl_wrapper_op!(
+    Wrapping,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    Uint::wrapping_sub
+);
+impl_wrapper_op!(
+    Wrapping,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    Uint::wrapping_mul
+);
+impl_wrapper_op!(
+    Wrapping,
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    Uint::wrapping_div
+);
+impl_wrapper_op!(
+    Wrapping,
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    Uint::wrapping_rem
+);
+impl_wrapper_op!(
+    Wrapping,
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    BitAnd::bitand
+);
+impl_wrapper_op!(
+    Wrapping,
+    BitOr,
+    bitor,
+    BitOrAssign,
+    bitor_assign,
+    BitOr::bitor
+);
+impl_wrapper_op!(
+    Wrapping,
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    BitXor::bitxor
+);
+impl_wrapper_shift!(
+    Wrapping,
+    Shl,
+    shl,
+    ShlAssign,
+    shl_assign,
+    Uint::wrapping_shl
+);
+impl_wrapper_shift!(
+    Wrapping,
+    Shr,
+    shr,
+    ShrAssign,
+    shr_assign,
+    Uint::wrapping_shr
+);
+
+impl<const BITS: usize, const LIMBS: usize> Neg for Wrapping<Uint<BITS, LIMBS>> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Not for Wrapping<Uint<BITS, LIMBS>> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+impl_wrapper!(Saturating);
+impl_wrapper_op!(
+    Saturating,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    Uint::saturating_add
+);
+impl_wrapper_op!(
+    Saturating,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    Uint::saturating_sub
+);
+impl_wrapper_op!(
+    Saturating,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    Uint::saturating_mul
+);
+impl_wrapper_op!(
+    Saturating,
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    Uint::wrapping_div
+);
+impl_wrapper_op!(
+    Saturating,
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    Uint::wrapping_rem
+);
+impl_wrapper_op!(
+    Saturating,
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    BitAnd::bitand
+);
+impl_wrapper_op!(
+    Saturating,
+    BitOr,
+    bitor,
+    BitOrAssign,
+    bitor_assign,
+    BitOr::bitor
+);
+impl_wrapper_op!(
+    Saturating,
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    BitXor::bitxor
+);
+
+impl<const BITS: usize, const LIMBS: usize> Not for Saturating<Uint<BITS, LIMBS>> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_wrapping() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, shift: usize)| {
+                let shift = shift % (2 * BITS + 2);
+                let (wa, wb) = (Wrapping(a), Wrapping(b));
+                assert_eq!(wa + wb, Wrapping(a.wrapping_add(b)));
+                assert_eq!(wa - wb, Wrapping(a.wrapping_sub(b)));
+                assert_eq!(wa * wb, Wrapping(a.wrapping_mul(b)));
+                assert_eq!(-wa, Wrapping(a.wrapping_neg()));
+                assert_eq!(!wa, Wrapping(!a));
+                assert_eq!(wa & wb, Wrapping(a & b));
+                assert_eq!(wa << shift, Wrapping(a.wrapping_shl(shift)));
+                assert_eq!(wa >> shift, Wrapping(a.wrapping_shr(shift)));
+                let mut c = wa;
+                c += wb;
+                c *= wb;
+                assert_eq!(c, (wa + wb) * wb);
+                assert_eq!(<U as From<_>>::from(wa), a);
+                assert_eq!(*wa, a);
+                if b != U::ZERO {
+                    assert_eq!(wa / wb, Wrapping(a / b));
+                    assert_eq!(wa % wb, Wrapping(a % b));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_saturating() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let (sa, sb) = (Saturating(a), Saturating(b));
+                assert_eq!(sa + sb, Saturating(a.saturating_add(b)));
+                assert_eq!(sa - sb, Saturating(a.saturating_sub(b)));
+                assert_eq!(sa * sb, Saturating(a.saturating_mul(b)));
+                assert_eq!(sa | sb, Saturating(a | b));
+                let mut c = sa;
+                c -= sb;
+                assert_eq!(c, sa - sb);
+                assert_eq!(format!("{sa} {sa:?} {sa:#x}"), format!("{a} {a:?} {a:#x}"));
+            });
+        });
+    }
+}